@@ -28,6 +28,8 @@ use std::any::Any;
 use std::collections::hash_map::RandomState;
 use std::collections::HashMap;
 use std::hash::{BuildHasher, Hash};
+use std::mem::size_of_val;
+use std::sync::Arc;
 
 /// Information about an evicted cache entry.
 ///
@@ -49,6 +51,20 @@ impl<K, V> Evicted<K, V> {
     }
 }
 
+/// Runtime counters of the cache.
+///
+/// Useful to tune cache capacity: a high miss rate together with a high
+/// eviction count indicates that the working set does not fit.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CacheStats {
+    /// Number of `get` calls that found the key in the cache
+    pub hits: u64,
+    /// Number of `get` calls that did not find the key in the cache
+    pub misses: u64,
+    /// Number of entries evicted from the cache by replacement
+    pub evictions: u64,
+}
+
 /// A cache entry with reference bit for clock algorithm
 #[derive(Debug)]
 struct CacheEntry<K, V> {
@@ -313,6 +329,24 @@ impl<K: Clone, V> ClockList<K, V> {
         Some(node.entry)
     }
 
+    /// Remove the page at `slot` - O(1). If the page is under the hand,
+    /// the hand moves to the next page.
+    fn remove(&mut self, slot: usize) -> Option<CacheEntry<K, V>> {
+        if self.hand == Some(slot) {
+            return self.remove_head_page();
+        }
+        let node = self.nodes.get_mut(slot)?.take()?;
+        self.free_slots.push(slot);
+        self.size -= 1;
+        if let Some(prev_node) = self.nodes.get_mut(node.prev).and_then(Option::as_mut) {
+            prev_node.next = node.next;
+        }
+        if let Some(next_node) = self.nodes.get_mut(node.next).and_then(Option::as_mut) {
+            next_node.prev = node.prev;
+        }
+        Some(node.entry)
+    }
+
     /// Move the hand past the current head - O(1)
     fn advance_hand(&mut self) {
         if let Some(hand) = self.hand {
@@ -365,6 +399,9 @@ pub struct CarCache<K, V, S = RandomState> {
 
     /// Index to track key locations
     index: HashMap<K, Location, S>,
+
+    /// Hit/miss/eviction counters
+    stats: CacheStats,
 }
 
 impl<K: Clone, V> CarCache<K, V> {
@@ -395,6 +432,11 @@ impl<K: Clone, V, S: BuildHasher> CarCache<K, V, S> {
             b1: GhostList::new(capacity.saturating_add(1)),
             b2: GhostList::new(capacity.saturating_add(1)),
             index: HashMap::with_hasher(hasher),
+            stats: CacheStats {
+                hits: 0,
+                misses: 0,
+                evictions: 0,
+            },
         }
     }
 }
@@ -406,35 +448,36 @@ where
     /// Get value from cache
     /// Returns Some(value) if found, None if not in cache
     pub fn get(&mut self, key: &K) -> Option<&V> {
-        match self.index.get(key) {
+        let entry = match self.index.get(key) {
             Some(Location::T1(slot)) => {
                 // Line 1-2: if (x is in T1 ∪ T2) then Set the page reference bit for x to one
-                if let Some(entry) = self.t1.get_mut(*slot) {
-                    entry.ref_bit = true; // Line 2: Set the page reference bit for x to one
-                    Some(&entry.value)
-                } else {
-                    None
-                }
+                self.t1.get_mut(*slot)
             }
             Some(Location::T2(slot)) => {
                 // Line 1-2: if (x is in T1 ∪ T2) then Set the page reference bit for x to one
-                if let Some(entry) = self.t2.get_mut(*slot) {
-                    entry.ref_bit = true; // Line 2: Set the page reference bit for x to one
-                    Some(&entry.value)
-                } else {
-                    None
-                }
+                self.t2.get_mut(*slot)
             }
             _ => None, // Line 3: else /* cache miss */
+        };
+        if let Some(entry) = entry {
+            entry.ref_bit = true; // Line 2: Set the page reference bit for x to one
+            self.stats.hits += 1;
+            Some(&entry.value)
+        } else {
+            self.stats.misses += 1;
+            None
         }
     }
 
     /// Insert/update value in cache following the exact pseudocode
-    /// Returns `Option<Evicted<K, V>>` containing the evicted entry (key and value)
-    /// if an entry was evicted from the cache, or `None` if no eviction occurred.
-    pub fn put(&mut self, key: K, value: V) -> Option<Evicted<K, V>> {
+    ///
+    /// Returns a pair:
+    /// - `Option<Evicted<K, V>>` containing the evicted entry (key and value)
+    ///   if an entry was evicted from the cache, or `None` if no eviction occurred;
+    /// - keys discarded from the ghost lists B1/B2 by this insertion.
+    pub fn put(&mut self, key: K, value: V) -> (Option<Evicted<K, V>>, Vec<K>) {
         if self.c == 0 {
-            return None;
+            return (None, Vec::new());
         }
 
         // Check if it's a cache hit first
@@ -449,10 +492,11 @@ where
             // request reads or refreshes.
             entry.ref_bit = true;
             entry.value = value;
-            return None;
+            return (None, Vec::new());
         }
 
         let mut evicted = None;
+        let mut pruned = Vec::new();
         // The key's post-replace location; when the cache is not full,
         // invariant I5 guarantees B1 ∪ B2 is empty, so `None` is exact.
         let mut location = None;
@@ -476,6 +520,7 @@ where
                 // Line 7: Discard the LRU page in B1
                 if let Some(discarded_key) = self.b1.remove_lru() {
                     self.index.remove(&discarded_key);
+                    pruned.push(discarded_key);
                 }
             }
             // Line 8: elseif ((|T1| + |T2| + |B1| + |B2| = 2c) and (x is not in B1 ∪ B2)) then
@@ -485,6 +530,7 @@ where
                 // Line 9: Discard the LRU page in B2
                 if let Some(discarded_key) = self.b2.remove_lru() {
                     self.index.remove(&discarded_key);
+                    pruned.push(discarded_key);
                 }
             }
         }
@@ -533,7 +579,62 @@ where
                 debug_assert!(false, "T1/T2 hits are handled before the miss path");
             }
         }
-        evicted.map(|e| Evicted::new(e.key, e.value))
+        (evicted.map(|e| Evicted::new(e.key, e.value)), pruned)
+    }
+
+    /// Remove a key from the cache.
+    ///
+    /// Returns the value if the key was resident. A key found only in a
+    /// ghost list is forgotten and `None` is returned.
+    ///
+    /// Removing a resident page leaves the cache below capacity, so the
+    /// ghost lists are discarded to keep invariant I5 (B1 ∪ B2 is empty
+    /// while |T1| + |T2| < c).
+    pub fn remove(&mut self, key: &K) -> Option<V> {
+        let entry = match self.index.remove(key)? {
+            Location::T1(slot) => self.t1.remove(slot),
+            Location::T2(slot) => self.t2.remove(slot),
+            Location::B1(slot) => {
+                self.b1.remove(slot);
+                return None;
+            }
+            Location::B2(slot) => {
+                self.b2.remove(slot);
+                return None;
+            }
+        };
+        self.discard_ghosts();
+        entry.map(|e| e.value)
+    }
+
+    /// Check if the key is resident (in T1 or T2).
+    ///
+    /// Unlike [`Self::get`], this does not set the reference bit nor
+    /// update hit/miss counters.
+    #[must_use]
+    pub fn contains_key(&self, key: &K) -> bool {
+        matches!(self.index.get(key), Some(Location::T1(_) | Location::T2(_)))
+    }
+
+    /// Remove all pages, including ghost lists, and reset adaptation
+    /// parameter. Hit/miss/eviction counters are preserved.
+    pub fn clear(&mut self) {
+        self.p = 0;
+        self.t1 = ClockList::new(self.c);
+        self.t2 = ClockList::new(self.c);
+        self.b1 = GhostList::new(self.c.saturating_add(1));
+        self.b2 = GhostList::new(self.c.saturating_add(1));
+        self.index.clear();
+    }
+
+    /// Drop all pages of B1 and B2 together with their index entries.
+    fn discard_ghosts(&mut self) {
+        while let Some(discarded_key) = self.b1.remove_lru() {
+            self.index.remove(&discarded_key);
+        }
+        while let Some(discarded_key) = self.b2.remove_lru() {
+            self.index.remove(&discarded_key);
+        }
     }
 
     /// Move an already-indexed key to the tail of T2 and repoint its
@@ -562,6 +663,7 @@ where
             // Line 24: if (|T1| >= max(1, p)) then
             if self.t1.len() >= 1.max(self.p) {
                 if let Some(found) = self.try_replace_from_t1() {
+                    self.stats.evictions += 1;
                     return Some(found);
                 }
                 // No advance here: a T1 pass that found no victim has
@@ -571,6 +673,7 @@ where
             } else {
                 // Line 31: else
                 if let Some(found) = self.try_replace_from_t2() {
+                    self.stats.evictions += 1;
                     return Some(found);
                 }
                 // A T2 pass that found no victim only cleared the head's
@@ -672,6 +775,12 @@ impl<K: Clone, V, S> CarCache<K, V, S> {
     pub const fn adaptation_parameter(&self) -> usize {
         self.p
     }
+
+    /// Get hit/miss/eviction counters accumulated since creation
+    #[must_use]
+    pub const fn stats(&self) -> CacheStats {
+        self.stats
+    }
 }
 
 pub(crate) type TypeErasedCarCache<K> = CarCache<K, Box<dyn Any + Send + Sync>>;
@@ -686,10 +795,23 @@ where
 
     /// Put a typed value into the cache and return the evicted key if any.
     ///
-    /// Returns `Some(key)` if an entry was evicted from the cache, `None` otherwise.
-    pub(crate) fn put_typed<T: 'static + Send + Sync>(&mut self, key: K, value: T) -> Option<K> {
-        let evicted = self.put(key, Box::new(value) as Box<dyn Any + Send + Sync>);
-        evicted.map(|e| e.key)
+    /// If `size_of_val` of the value exceeds `max_size`, the value is not
+    /// cached and a value previously cached under `key` is removed.
+    ///
+    /// Returns `Some(key)` if an entry was evicted from the cache, `None`
+    /// otherwise, together with keys discarded from the ghost lists.
+    pub(crate) fn put_typed<T: 'static + Send + Sync>(
+        &mut self,
+        key: K,
+        value: Arc<T>,
+        max_size: Option<usize>,
+    ) -> (Option<K>, Vec<K>) {
+        if max_size.is_some_and(|max_size| size_of_val(value.as_ref()) > max_size) {
+            self.remove(&key);
+            return (None, Vec::new());
+        }
+        let (evicted, pruned) = self.put(key, Box::new(value) as Box<dyn Any + Send + Sync>);
+        (evicted.map(|e| e.key), pruned)
     }
 }
 
@@ -943,7 +1065,7 @@ mod tests {
         cache.put("c", 3);
         cache.get(&"a");
 
-        let evicted = cache.put("d", 4).expect("full cache evicts");
+        let evicted = cache.put("d", 4).0.expect("full cache evicts");
         assert_eq!(evicted.key, "b");
         assert_eq!(evicted.value, 2);
         assert_car_invariants(&cache);
@@ -961,7 +1083,7 @@ mod tests {
         cache.put("c", 3);
         cache.put("a", 10);
 
-        let evicted = cache.put("d", 4).expect("full cache evicts");
+        let evicted = cache.put("d", 4).0.expect("full cache evicts");
         assert_eq!(evicted.key, "b");
         assert_car_invariants(&cache);
         assert_eq!(cache.get(&"a"), Some(&10));
@@ -1007,8 +1129,8 @@ mod tests {
 
         assert_eq!(cache.capacity(), 0);
         assert!(cache.is_empty());
-        assert!(cache.put("a", 1).is_none());
-        assert!(cache.put("b", 2).is_none());
+        assert!(cache.put("a", 1).0.is_none());
+        assert!(cache.put("b", 2).0.is_none());
         assert_eq!(cache.get(&"a"), None);
         assert_eq!(cache.get(&"b"), None);
         assert_eq!(cache.len(), 0);
@@ -1288,18 +1410,18 @@ mod tests {
     fn test_put_return_values_eviction() {
         let mut cache = CarCache::new(3);
 
-        assert!(cache.put("a", 1).is_none());
-        assert!(cache.put("b", 2).is_none());
-        assert!(cache.put("c", 3).is_none());
+        assert!(cache.put("a", 1).0.is_none());
+        assert!(cache.put("b", 2).0.is_none());
+        assert!(cache.put("c", 3).0.is_none());
 
         // When eviction occurs, we get back the Evicted struct with key and value
-        let evicted = cache.put("d", 4);
+        let (evicted, _) = cache.put("d", 4);
         assert!(evicted.is_some());
         let evicted = evicted.unwrap();
         assert_eq!(evicted.key, "a");
         assert_eq!(evicted.value, 1);
 
-        let evicted = cache.put("e", 5);
+        let (evicted, _) = cache.put("e", 5);
         assert!(evicted.is_some());
         let evicted = evicted.unwrap();
         assert_eq!(evicted.key, "b");
@@ -1312,20 +1434,63 @@ mod tests {
         assert_eq!(cache.get(&"e"), Some(&5));
     }
 
+    #[test]
+    fn test_put_returns_keys_pruned_from_ghost_lists() {
+        let mut cache = CarCache::new(2);
+        let (evicted, pruned) = cache.put("a", 1);
+        assert!(evicted.is_none());
+        assert!(pruned.is_empty());
+        cache.put("b", 2);
+
+        // `a` is demoted to B1 and then discarded because |T1| + |B1| = c.
+        let (evicted, pruned) = cache.put("c", 3);
+        assert_eq!(evicted.map(|e| e.key), Some("a"));
+        assert_eq!(pruned, ["a"]);
+        assert!(!cache.index.contains_key(&"a"));
+        assert_car_invariants(&cache);
+    }
+
+    #[test]
+    fn test_put_keeps_ghosts_until_pruned() {
+        let mut cache = CarCache::new(2);
+        cache.put("a", 1);
+        cache.get(&"a");
+        cache.put("b", 2);
+        cache.get(&"b");
+
+        // Both pages recirculate to T2, `a` is demoted to B2.
+        let (evicted, pruned) = cache.put("c", 3);
+        assert_eq!(evicted.map(|e| e.key), Some("a"));
+        assert!(pruned.is_empty());
+
+        // `c` is demoted to B1.
+        let (evicted, pruned) = cache.put("d", 4);
+        assert_eq!(evicted.map(|e| e.key), Some("c"));
+        assert!(pruned.is_empty());
+
+        // `d` joins B1 which then exceeds its share: LRU `c` is pruned.
+        let (evicted, pruned) = cache.put("e", 5);
+        assert_eq!(evicted.map(|e| e.key), Some("d"));
+        assert_eq!(pruned, ["c"]);
+        assert!(!cache.index.contains_key(&"c"));
+        assert!(matches!(cache.index.get(&"a"), Some(Location::B2(_))));
+        assert_car_invariants(&cache);
+    }
+
     #[test]
     fn test_put_return_values_t1_t2_eviction() {
         let mut cache = CarCache::new(4);
 
-        assert!(cache.put("t1_a", 1).is_none());
-        assert!(cache.put("t1_b", 2).is_none());
+        assert!(cache.put("t1_a", 1).0.is_none());
+        assert!(cache.put("t1_b", 2).0.is_none());
 
         cache.get(&"t1_a");
         cache.get(&"t1_b");
 
-        assert!(cache.put("t1_c", 3).is_none());
-        assert!(cache.put("t1_d", 4).is_none());
+        assert!(cache.put("t1_c", 3).0.is_none());
+        assert!(cache.put("t1_d", 4).0.is_none());
 
-        let evicted = cache.put("new1", 10);
+        let (evicted, _) = cache.put("new1", 10);
         assert!(evicted.is_some());
         assert_eq!(evicted.unwrap().value, 3);
     }
@@ -1469,13 +1634,16 @@ mod tests {
     fn test_put_typed_works_across_types() {
         let mut cache: TypeErasedCarCache<String> = CarCache::new(2);
 
-        let evicted_key = cache.put_typed("key1".to_string(), Arc::new(TypeA { id: "1".into() }));
+        let (evicted_key, _) =
+            cache.put_typed("key1".to_string(), Arc::new(TypeA { id: "1".into() }), None);
         assert!(evicted_key.is_none());
 
-        let evicted_key = cache.put_typed("key2".to_string(), Arc::new(TypeA { id: "2".into() }));
+        let (evicted_key, _) =
+            cache.put_typed("key2".to_string(), Arc::new(TypeA { id: "2".into() }), None);
         assert!(evicted_key.is_none());
 
-        let evicted_key = cache.put_typed("key3".to_string(), Arc::new(TypeB { id: "3".into() }));
+        let (evicted_key, _) =
+            cache.put_typed("key3".to_string(), Arc::new(TypeB { id: "3".into() }), None);
 
         assert!(evicted_key.is_some(),);
 
@@ -1485,4 +1653,172 @@ mod tests {
         let key_in_cache = cache.get_typed::<Arc<TypeA>>(&evicted_key).is_some();
         assert!(!key_in_cache,);
     }
+
+    #[test]
+    fn test_put_typed_skips_values_above_max_size() {
+        let mut cache: TypeErasedCarCache<String> = CarCache::new(2);
+        let key = "key1".to_string();
+        let size = size_of_val(&TypeA { id: "1".into() });
+
+        cache.put_typed(key.clone(), Arc::new(TypeA { id: "1".into() }), Some(size));
+        assert!(cache.get_typed::<Arc<TypeA>>(&key).is_some());
+
+        // Too large value also drops the previously cached one.
+        let (evicted_key, _) = cache.put_typed(
+            key.clone(),
+            Arc::new(TypeA { id: "2".into() }),
+            Some(size - 1),
+        );
+        assert!(evicted_key.is_none());
+        assert!(!cache.contains_key(&key));
+        assert_eq!(cache.len(), 0);
+    }
+
+    #[test]
+    fn test_stats_count_hits_and_misses_in_get() {
+        let mut cache = CarCache::new(2);
+        cache.put(1, 10);
+        assert_eq!(cache.stats(), CacheStats::default());
+
+        assert!(cache.get(&1).is_some());
+        assert!(cache.get(&2).is_none());
+        assert!(cache.get(&1).is_some());
+
+        let stats = cache.stats();
+        assert_eq!(stats.hits, 2);
+        assert_eq!(stats.misses, 1);
+        assert_eq!(stats.evictions, 0);
+    }
+
+    #[test]
+    fn test_stats_count_evictions_in_replace() {
+        let mut cache = CarCache::new(2);
+        cache.put(1, 10);
+        cache.put(2, 20);
+        // Updating a resident key is not an eviction.
+        cache.put(2, 21);
+        assert_eq!(cache.stats().evictions, 0);
+
+        assert!(cache.put(3, 30).0.is_some());
+        assert!(cache.put(4, 40).0.is_some());
+
+        let stats = cache.stats();
+        assert_eq!(stats.evictions, 2);
+        assert_eq!(stats.hits, 0);
+        assert_eq!(stats.misses, 0);
+    }
+
+    #[test]
+    fn test_stats_on_type_erased_cache() {
+        let mut cache: TypeErasedCarCache<String> = CarCache::new(1);
+        cache.put_typed("key1".to_string(), Arc::new(TypeA { id: "1".into() }), None);
+        assert!(cache.get_typed::<Arc<TypeA>>(&"key1".to_string()).is_some());
+        assert!(cache.get_typed::<Arc<TypeA>>(&"key2".to_string()).is_none());
+        cache.put_typed("key2".to_string(), Arc::new(TypeB { id: "2".into() }), None);
+
+        assert_eq!(
+            cache.stats(),
+            CacheStats {
+                hits: 1,
+                misses: 1,
+                evictions: 1,
+            }
+        );
+    }
+
+    #[test]
+    fn test_remove_resident_key() {
+        let mut cache = CarCache::new(3);
+        fill_cache_with_invariant_check(&mut cache, (0..3).map(|i| (i, i * 10)));
+        cache.get(&1);
+
+        assert_eq!(cache.remove(&0), Some(0));
+        assert_car_invariants(&cache);
+        assert_eq!(cache.remove(&1), Some(10));
+        assert_car_invariants(&cache);
+        assert_eq!(cache.remove(&1), None);
+        assert_eq!(cache.len(), 1);
+        assert!(cache.get(&2).is_some());
+    }
+
+    #[test]
+    fn test_remove_discards_ghosts() {
+        let mut cache = CarCache::new(2);
+        promote_all_to_t2(&mut cache, 0..3);
+        let (_, _, b1_size, b2_size, _) = verify_directory_state(&cache);
+        assert!(b1_size + b2_size > 0);
+
+        assert_eq!(cache.remove(&2), Some(2));
+        assert_car_invariants(&cache);
+        let (_, _, b1_size, b2_size, _) = verify_directory_state(&cache);
+        assert_eq!(b1_size + b2_size, 0);
+
+        fill_cache_with_invariant_check(&mut cache, (10..20).map(|i| (i, i)));
+    }
+
+    #[test]
+    fn test_remove_ghost_key() {
+        let mut cache = CarCache::new(2);
+        cache.put(0, 0);
+        cache.get(&0);
+        fill_cache_with_invariant_check(&mut cache, (1..3).map(|i| (i, i)));
+        let ghost = (0..3)
+            .find(|k| matches!(cache.index.get(k), Some(Location::B1(_))))
+            .expect("one key demoted to B1");
+
+        assert_eq!(cache.remove(&ghost), None);
+        assert_car_invariants(&cache);
+        assert!(!cache.index.contains_key(&ghost));
+        assert_eq!(cache.len(), 2);
+    }
+
+    #[test]
+    fn test_remove_from_t2() {
+        let mut cache = CarCache::new(2);
+        promote_all_to_t2(&mut cache, 0..3);
+        let key = (0..3)
+            .find(|k| matches!(cache.index.get(k), Some(Location::T2(_))))
+            .expect("one key promoted to T2");
+
+        assert_eq!(cache.remove(&key), Some(key));
+        assert_car_invariants(&cache);
+        assert!(!cache.contains_key(&key));
+        assert_eq!(cache.len(), 1);
+    }
+
+    #[test]
+    fn test_contains_key_ignores_ghosts_and_keeps_stats() {
+        let mut cache = CarCache::new(2);
+        cache.put(0, 0);
+        cache.get(&0);
+        fill_cache_with_invariant_check(&mut cache, (1..3).map(|i| (i, i)));
+        let ghost = (0..3)
+            .find(|k| matches!(cache.index.get(k), Some(Location::B1(_))))
+            .expect("one key demoted to B1");
+        let stats = cache.stats();
+
+        for key in 0..3 {
+            assert_eq!(cache.contains_key(&key), key != ghost);
+        }
+        assert_eq!(cache.stats(), stats);
+    }
+
+    #[test]
+    fn test_clear() {
+        let mut cache = CarCache::new(3);
+        promote_all_to_t2(&mut cache, 0..6);
+        fill_cache_with_invariant_check(&mut cache, (0..6).map(|i| (i, i)));
+        let stats = cache.stats();
+
+        cache.clear();
+        assert_car_invariants(&cache);
+        assert!(cache.is_empty());
+        assert_eq!(cache.adaptation_parameter(), 0);
+        assert_eq!(verify_directory_state(&cache), (0, 0, 0, 0, 0));
+        assert!(cache.index.is_empty());
+        assert_eq!(cache.stats(), stats);
+
+        fill_cache_with_invariant_check(&mut cache, (10..20).map(|i| (i, i)));
+        assert_eq!(cache.len(), 3);
+    }
 }
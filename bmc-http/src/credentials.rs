@@ -15,6 +15,9 @@
 
 //! HTTP credentials type.
 
+use std::env;
+use std::error::Error as StdError;
+use std::ffi::OsString;
 use std::fmt;
 
 /// Credentials used to access the BMC.
@@ -56,8 +59,148 @@ impl BmcCredentials {
     pub const fn new(username: String, password: String) -> Self {
         Self::username_password(username, Some(password))
     }
+
+    /// Start building credentials from optional parts.
+    #[must_use]
+    pub fn builder() -> BmcCredentialsBuilder {
+        BmcCredentialsBuilder::default()
+    }
+
+    /// Default environment variable with username.
+    pub const USERNAME_ENV_VAR: &'static str = "BMC_USERNAME";
+
+    /// Default environment variable with password.
+    pub const PASSWORD_ENV_VAR: &'static str = "BMC_PASSWORD";
+
+    /// Create username/password credentials from `BMC_USERNAME` and
+    /// `BMC_PASSWORD` environment variables.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if any of the variables is not set or is not
+    /// valid UTF-8.
+    pub fn from_env() -> Result<Self, CredentialsFromEnvError> {
+        Self::from_env_vars(Self::USERNAME_ENV_VAR, Self::PASSWORD_ENV_VAR)
+    }
+
+    /// Create username/password credentials from the specified
+    /// environment variables.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if any of the variables is not set or is not
+    /// valid UTF-8.
+    pub fn from_env_vars(
+        username_var: &str,
+        password_var: &str,
+    ) -> Result<Self, CredentialsFromEnvError> {
+        Ok(Self::new(env_var(username_var)?, env_var(password_var)?))
+    }
+}
+
+/// Builder of [`BmcCredentials`].
+///
+/// A token, when set, takes precedence over username and password.
+/// `Debug` redacts secrets in the same way as [`BmcCredentials`].
+#[derive(Clone, Default)]
+pub struct BmcCredentialsBuilder {
+    username: Option<String>,
+    password: Option<String>,
+    token: Option<String>,
+}
+
+impl BmcCredentialsBuilder {
+    /// Set username.
+    #[must_use]
+    pub fn with_username(self, username: String) -> Self {
+        Self {
+            username: Some(username),
+            ..self
+        }
+    }
+
+    /// Set password.
+    #[must_use]
+    pub fn with_password(self, password: String) -> Self {
+        Self {
+            password: Some(password),
+            ..self
+        }
+    }
+
+    /// Set session token.
+    #[must_use]
+    pub fn with_token(self, token: String) -> Self {
+        Self {
+            token: Some(token),
+            ..self
+        }
+    }
+
+    /// Build credentials.
+    ///
+    /// Returns `None` if neither a token nor a username was set.
+    #[must_use]
+    pub fn build(self) -> Option<BmcCredentials> {
+        let Self {
+            username,
+            password,
+            token,
+        } = self;
+        token.map(BmcCredentials::token).or_else(|| {
+            username.map(|username| BmcCredentials::username_password(username, password))
+        })
+    }
+}
+
+impl fmt::Debug for BmcCredentialsBuilder {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let redacted = |v: &Option<String>| v.as_ref().map(|_| "[REDACTED]");
+        f.debug_struct("BmcCredentialsBuilder")
+            .field("username", &self.username)
+            .field("password", &redacted(&self.password))
+            .field("token", &redacted(&self.token))
+            .finish()
+    }
+}
+
+fn env_var(name: &str) -> Result<String, CredentialsFromEnvError> {
+    env::var(name).map_err(|err| match err {
+        env::VarError::NotPresent => CredentialsFromEnvError::Missing(name.into()),
+        env::VarError::NotUnicode(value) => CredentialsFromEnvError::NotUnicode {
+            name: name.into(),
+            value,
+        },
+    })
 }
 
+/// Error of reading credentials from environment variables.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CredentialsFromEnvError {
+    /// Environment variable is not set.
+    Missing(String),
+    /// Environment variable is not valid UTF-8.
+    NotUnicode {
+        /// Name of the variable.
+        name: String,
+        /// Raw value of the variable.
+        value: OsString,
+    },
+}
+
+impl fmt::Display for CredentialsFromEnvError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Missing(name) => write!(f, "environment variable {name} is not set"),
+            Self::NotUnicode { name, .. } => {
+                write!(f, "environment variable {name} is not valid UTF-8")
+            }
+        }
+    }
+}
+
+impl StdError for CredentialsFromEnvError {}
+
 impl fmt::Debug for BmcCredentials {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
@@ -87,3 +230,120 @@ impl fmt::Display for BmcCredentials {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Mutex;
+    use std::sync::PoisonError;
+
+    // Environment is process-global: serialize tests touching it.
+    static ENV_LOCK: Mutex<()> = Mutex::new(());
+
+    const SECRET: &str = "debug-secret-sentinel";
+
+    #[test]
+    fn test_debug_redacts_secrets() {
+        let credentials = [
+            BmcCredentials::new("root".into(), SECRET.into()),
+            BmcCredentials::token(SECRET.into()),
+        ];
+        for credentials in credentials {
+            assert!(!format!("{credentials:?}").contains(SECRET));
+            assert!(!format!("{credentials:#?}").contains(SECRET));
+            assert!(!credentials.to_string().contains(SECRET));
+        }
+
+        let builder = BmcCredentials::builder()
+            .with_username("root".into())
+            .with_password(SECRET.into())
+            .with_token(SECRET.into());
+        let debug = format!("{builder:?}");
+        assert!(!debug.contains(SECRET));
+        assert!(debug.contains("root"));
+    }
+
+    #[test]
+    fn test_builder_builds_credentials() {
+        assert_eq!(BmcCredentials::builder().build(), None);
+        assert_eq!(
+            BmcCredentials::builder()
+                .with_username("root".into())
+                .with_password("password".into())
+                .build(),
+            Some(BmcCredentials::new("root".into(), "password".into()))
+        );
+        assert_eq!(
+            BmcCredentials::builder()
+                .with_username("root".into())
+                .build(),
+            Some(BmcCredentials::username_password("root".into(), None))
+        );
+        assert_eq!(
+            BmcCredentials::builder()
+                .with_username("root".into())
+                .with_token("token".into())
+                .build(),
+            Some(BmcCredentials::token("token".into()))
+        );
+    }
+
+    #[test]
+    fn test_from_env_vars_reads_credentials() {
+        let _guard = ENV_LOCK.lock().unwrap_or_else(PoisonError::into_inner);
+        env::set_var("NV_REDFISH_TEST_USER", "root");
+        env::set_var("NV_REDFISH_TEST_PASS", "password");
+
+        let credentials =
+            BmcCredentials::from_env_vars("NV_REDFISH_TEST_USER", "NV_REDFISH_TEST_PASS");
+        assert_eq!(
+            credentials,
+            Ok(BmcCredentials::new("root".into(), "password".into()))
+        );
+
+        env::remove_var("NV_REDFISH_TEST_USER");
+        env::remove_var("NV_REDFISH_TEST_PASS");
+    }
+
+    #[test]
+    fn test_from_env_vars_reports_missing_variable() {
+        let _guard = ENV_LOCK.lock().unwrap_or_else(PoisonError::into_inner);
+        env::set_var("NV_REDFISH_TEST_USER", "root");
+        env::remove_var("NV_REDFISH_TEST_PASS");
+
+        let credentials =
+            BmcCredentials::from_env_vars("NV_REDFISH_TEST_USER", "NV_REDFISH_TEST_PASS");
+        assert_eq!(
+            credentials,
+            Err(CredentialsFromEnvError::Missing(
+                "NV_REDFISH_TEST_PASS".into()
+            ))
+        );
+
+        env::remove_var("NV_REDFISH_TEST_USER");
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_from_env_vars_reports_non_unicode_variable() {
+        use std::os::unix::ffi::OsStringExt as _;
+
+        let _guard = ENV_LOCK.lock().unwrap_or_else(PoisonError::into_inner);
+        let value = OsString::from_vec(vec![0x66, 0x6f, 0x80]);
+        env::set_var("NV_REDFISH_TEST_USER", &value);
+        env::set_var("NV_REDFISH_TEST_PASS", "password");
+
+        let credentials =
+            BmcCredentials::from_env_vars("NV_REDFISH_TEST_USER", "NV_REDFISH_TEST_PASS");
+        assert_eq!(
+            credentials,
+            Err(CredentialsFromEnvError::NotUnicode {
+                name: "NV_REDFISH_TEST_USER".into(),
+                value,
+            })
+        );
+
+        env::remove_var("NV_REDFISH_TEST_USER");
+        env::remove_var("NV_REDFISH_TEST_PASS");
+    }
+}
@@ -42,22 +42,36 @@
 pub mod cache;
 pub mod credentials;
 
+mod session;
+
 #[cfg(feature = "reqwest")]
 mod schema;
 
 #[cfg(feature = "reqwest")]
 pub mod reqwest;
 
+#[cfg(feature = "retry")]
+pub mod retry;
+
+use std::borrow::Cow;
 use std::collections::HashMap;
 use std::error::Error as StdError;
 use std::fmt;
 use std::future::Future;
 use std::sync::Arc;
 use std::sync::RwLock;
+use std::time::Duration;
+use std::time::Instant;
 
+use crate::cache::CacheStats;
 use crate::cache::TypeErasedCarCache;
+use crate::session::ActiveSession;
+use crate::session::SessionAuth;
+use crate::session::SessionLogin;
 
 use http::HeaderMap;
+use http::HeaderName;
+use http::HeaderValue;
 use nv_redfish_core::query::ExpandQuery;
 use nv_redfish_core::Action;
 use nv_redfish_core::Bmc;
@@ -66,15 +80,20 @@ use nv_redfish_core::EntityTypeRef;
 use nv_redfish_core::Expandable;
 use nv_redfish_core::FilterQuery;
 use nv_redfish_core::ModificationResponse;
+use nv_redfish_core::NotFoundError;
 use nv_redfish_core::ODataETag;
 use nv_redfish_core::ODataId;
+use nv_redfish_core::PageQuery;
+use nv_redfish_core::SelectQuery;
 use nv_redfish_core::SessionCreateResponse;
 use nv_redfish_core::UploadReader;
-use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use serde::{de::DeserializeOwned, de::IgnoredAny, Deserialize, Serialize};
 use url::Url;
 
 #[doc(inline)]
 pub use credentials::BmcCredentials;
+pub use credentials::BmcCredentialsBuilder;
+pub use credentials::CredentialsFromEnvError;
 
 #[cfg(feature = "update-service-deprecated")]
 #[doc(inline)]
@@ -105,6 +124,16 @@ pub trait HttpClient: Send + Sync {
     where
         T: DeserializeOwned + Send + Sync;
 
+    /// Perform an HTTP HEAD request.
+    ///
+    /// Succeeds if the server responds with a success status.
+    fn head(
+        &self,
+        url: Url,
+        credentials: &BmcCredentials,
+        custom_headers: &HeaderMap,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send;
+
     /// Perform an HTTP POST request.
     fn post<B, T>(
         &self,
@@ -192,6 +221,20 @@ pub trait HttpClient: Send + Sync {
         credentials: &BmcCredentials,
         custom_headers: &HeaderMap,
     ) -> impl Future<Output = Result<BoxTryStream<T, Self::Error>, Self::Error>> + Send;
+
+    /// Timeout applied to every request, if any.
+    ///
+    /// Clients that do not support per-request timeouts return `None`.
+    fn timeout(&self) -> Option<Duration> {
+        None
+    }
+
+    /// Apply `timeout` to every request.
+    ///
+    /// Clients that do not support per-request timeouts ignore it.
+    fn set_timeout(&mut self, timeout: Option<Duration>) {
+        let _ = timeout;
+    }
 }
 
 /// HTTP-based BMC implementation that wraps an [`HttpClient`].
@@ -215,8 +258,12 @@ pub struct HttpBmc<C: HttpClient> {
     redfish_endpoint: RedfishEndpoint,
     credentials: RwLock<Arc<BmcCredentials>>,
     cache: RwLock<TypeErasedCarCache<Url>>,
-    etags: RwLock<HashMap<Url, ODataETag>>,
-    custom_headers: HeaderMap,
+    etags: RwLock<HashMap<Url, CachedETag>>,
+    custom_headers: RwLock<Arc<HeaderMap>>,
+    session: Option<SessionAuth>,
+    max_age: Option<Duration>,
+    max_entry_size_bytes: Option<usize>,
+    clock: Arc<dyn Clock>,
 
     // Response bodies and ETags are enabled or disabled together because a
     // 304 Not Modified response contains no replacement body.
@@ -320,26 +367,92 @@ where
     /// # }
     /// ```
     pub fn with_custom_headers(
-        client: C,
+        mut client: C,
         redfish_endpoint: Url,
         credentials: BmcCredentials,
         cache_settings: CacheSettings,
         custom_headers: HeaderMap,
     ) -> Self {
+        if let Some(timeout) = cache_settings.request_timeout {
+            client.set_timeout(Some(timeout));
+        }
         Self {
             client,
             redfish_endpoint: RedfishEndpoint::from(redfish_endpoint),
             credentials: RwLock::new(Arc::new(credentials)),
             cache: RwLock::new(TypeErasedCarCache::new(cache_settings.capacity)),
             etags: RwLock::new(HashMap::new()),
-            custom_headers,
+            custom_headers: RwLock::new(Arc::new(custom_headers)),
+            session: None,
+            max_age: cache_settings.max_age,
+            max_entry_size_bytes: cache_settings.max_entry_size_bytes,
+            clock: Arc::new(SystemClock),
             cache_enabled: cache_settings.capacity > 0,
         }
     }
 
+    /// Create a new HTTP-based BMC client for a Redfish service mounted at
+    /// a non-root path.
+    ///
+    /// Same as [`HttpBmc::new`] but every request path is prefixed with
+    /// `prefix` (see [`RedfishEndpoint::with_prefix`]). For example, with
+    /// prefix `/api` the service root is requested at `/api/redfish/v1`.
+    pub fn with_base_path_prefix(
+        client: C,
+        redfish_endpoint: Url,
+        credentials: BmcCredentials,
+        cache_settings: CacheSettings,
+        prefix: &str,
+    ) -> Self {
+        let mut bmc = Self::new(client, redfish_endpoint, credentials, cache_settings);
+        bmc.redfish_endpoint = bmc.redfish_endpoint.clone().with_prefix(prefix);
+        bmc
+    }
+
+    /// Bound the duration of every request sent to the BMC.
+    ///
+    /// The timeout is applied by the [`HttpClient`]; clients that do
+    /// not support per-request timeouts ignore it.
+    #[must_use]
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.client.set_timeout(Some(timeout));
+        self
+    }
+
+    /// Replace the clock used to expire cached entries.
+    ///
+    /// Only useful together with [`CacheSettings::with_max_age`],
+    /// mostly to control time in tests.
+    #[must_use]
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// Authenticate using Redfish sessions instead of sending
+    /// credentials with every request.
+    ///
+    /// Username and password are sent only once in login request to
+    /// `/redfish/v1/SessionService/Sessions`. The login is performed
+    /// lazily on the first request and returned `X-Auth-Token` is used
+    /// for all subsequent requests. If BMC responds with 401
+    /// Unauthorized, new session is created and the request is
+    /// retried once.
+    ///
+    /// Session is not deleted on drop unless
+    /// [`HttpBmc::with_session_logout_on_drop`] is used. Use
+    /// [`HttpBmc::logout`] to delete it explicitly.
+    #[must_use]
+    pub fn with_session_auth(mut self) -> Self {
+        self.session = Some(SessionAuth::default());
+        self
+    }
+
     /// Replace the credentials used for subsequent requests.
     ///
-    /// Existing cache and ETag state is preserved.
+    /// Existing cache and ETag state is preserved. With session
+    /// authentication, the active session is dropped (it is not deleted
+    /// on the BMC) and the next request logs in with new credentials.
     ///
     /// # Panics
     ///
@@ -348,6 +461,194 @@ where
     #[allow(clippy::panic)] // See panics section.
     pub fn set_credentials(&self, credentials: BmcCredentials) {
         *self.credentials.write().expect("poisoned") = Arc::new(credentials);
+        if let Some(session) = &self.session {
+            // A login in progress holds the lock. Its session is
+            // recognized as stale by the login credentials check.
+            if let Some(mut active) = session.active.try_lock() {
+                *active = None;
+            }
+        }
+    }
+
+    /// Set custom header sent with subsequent requests.
+    ///
+    /// Replaces all existing values of the header. Existing cache and
+    /// ETag state is preserved. Requests already in flight keep the
+    /// headers they started with.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the internal headers lock is poisoned. This should not
+    /// occur in normal operation.
+    #[allow(clippy::panic)] // See panics section.
+    pub fn set_header(&self, name: HeaderName, value: HeaderValue) {
+        let mut headers = self.custom_headers.write().expect("poisoned");
+        Arc::make_mut(&mut headers).insert(name, value);
+    }
+
+    /// Remove custom header from subsequent requests.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the internal headers lock is poisoned. This should not
+    /// occur in normal operation.
+    #[allow(clippy::panic)] // See panics section.
+    pub fn remove_header(&self, name: &HeaderName) {
+        let mut headers = self.custom_headers.write().expect("poisoned");
+        Arc::make_mut(&mut headers).remove(name);
+    }
+
+    /// Drop cached bodies and `ETag`s of the resource identified by `id`.
+    ///
+    /// All cached variants of the resource are removed, including the
+    /// ones fetched with `$expand`, `$filter` or `$select` queries. The
+    /// next GET of the resource is sent without `If-None-Match`.
+    ///
+    /// Updates and deletes made through this BMC invalidate the resource
+    /// automatically; use this after changes made by other means.
+    ///
+    /// # Errors
+    ///
+    /// Returns cache error if the internal cache lock is poisoned.
+    #[allow(clippy::significant_drop_tightening)]
+    pub fn invalidate(&self, id: &ODataId) -> Result<(), C::Error> {
+        if !self.cache_enabled {
+            return Ok(());
+        }
+        let target = self.redfish_endpoint.with_odata_id(id);
+
+        let mut cache = self
+            .cache
+            .write()
+            .map_err(|e| C::Error::cache_error(e.to_string()))?;
+
+        let mut etags = self
+            .etags
+            .write()
+            .map_err(|e| C::Error::cache_error(e.to_string()))?;
+
+        etags.retain(|url, _| {
+            if url.path() == target.path() {
+                cache.remove(url);
+                false
+            } else {
+                true
+            }
+        });
+        Ok(())
+    }
+
+    /// Drop all cached bodies and `ETag`s.
+    ///
+    /// # Errors
+    ///
+    /// Returns cache error if the internal cache lock is poisoned.
+    #[allow(clippy::significant_drop_tightening)]
+    pub fn clear_cache(&self) -> Result<(), C::Error> {
+        let mut cache = self
+            .cache
+            .write()
+            .map_err(|e| C::Error::cache_error(e.to_string()))?;
+
+        let mut etags = self
+            .etags
+            .write()
+            .map_err(|e| C::Error::cache_error(e.to_string()))?;
+
+        cache.clear();
+        etags.clear();
+        Ok(())
+    }
+
+    /// Replace the response cache with a fresh one of the same capacity
+    /// and drop all `ETag`s.
+    ///
+    /// Unlike [`Self::clear_cache`], hit/miss/eviction counters are reset
+    /// as well. Use this after a BMC reset or bulk firmware update, when
+    /// no previously fetched state can be trusted.
+    ///
+    /// # Errors
+    ///
+    /// Returns cache error if the internal cache lock is poisoned.
+    #[allow(clippy::significant_drop_tightening)]
+    pub fn invalidate_all(&self) -> Result<(), C::Error> {
+        let mut cache = self
+            .cache
+            .write()
+            .map_err(|e| C::Error::cache_error(e.to_string()))?;
+
+        let mut etags = self
+            .etags
+            .write()
+            .map_err(|e| C::Error::cache_error(e.to_string()))?;
+
+        *cache = TypeErasedCarCache::new(cache.capacity());
+        etags.clear();
+        Ok(())
+    }
+
+    /// Hit/miss/eviction counters of the response cache.
+    ///
+    /// # Errors
+    ///
+    /// Returns cache error if the internal cache lock is poisoned.
+    pub fn cache_stats(&self) -> Result<CacheStats, C::Error> {
+        self.cache
+            .read()
+            .map(|cache| cache.stats())
+            .map_err(|e| C::Error::cache_error(e.to_string()))
+    }
+}
+
+#[cfg(feature = "reqwest")]
+impl<C> HttpBmc<C>
+where
+    C: HttpClient + Clone + 'static,
+    C::Error: CacheableError,
+{
+    /// Authenticate using Redfish sessions (see
+    /// [`HttpBmc::with_session_auth`]) and delete the active session
+    /// when the client is dropped.
+    ///
+    /// Logout on drop is best effort: the delete request is spawned on
+    /// the current tokio runtime and its result is ignored. If the
+    /// client is dropped outside of a tokio runtime, the session is
+    /// left to expire on the BMC.
+    #[must_use]
+    pub fn with_session_logout_on_drop(mut self) -> Self {
+        let client = self.client.clone();
+        let mut session = self.session.take().unwrap_or_default();
+        session.logout_on_drop = Some(Box::new(move |url, credentials, custom_headers| {
+            let Ok(runtime) = tokio::runtime::Handle::try_current() else {
+                return;
+            };
+            runtime.spawn(async move {
+                let _: Result<ModificationResponse<IgnoredAny>, _> = client
+                    .delete(url, credentials.as_ref(), &custom_headers)
+                    .await;
+            });
+        }));
+        self.session = Some(session);
+        self
+    }
+}
+
+impl<C: HttpClient> Drop for HttpBmc<C> {
+    fn drop(&mut self) {
+        let Some(session) = self.session.as_mut() else {
+            return;
+        };
+        let Some(logout) = session.logout_on_drop.take() else {
+            return;
+        };
+        let Some(active) = session.active.get_mut().take() else {
+            return;
+        };
+        let Ok(custom_headers) = self.custom_headers.get_mut() else {
+            return;
+        };
+        let url = self.redfish_endpoint.with_odata_id(&active.location);
+        logout(url, active.credentials, Arc::clone(custom_headers));
     }
 }
 
@@ -357,6 +658,7 @@ where
 #[derive(Debug, Clone)]
 pub struct RedfishEndpoint {
     base_url: Url,
+    path_prefix: String,
 }
 
 /// Service-provided URI reference that must be resolved as a URI reference.
@@ -387,17 +689,45 @@ impl RedfishEndpoint {
     /// Create a new `RedfishEndpoint` from a base URL
     #[must_use]
     pub const fn new(base_url: Url) -> Self {
-        Self { base_url }
+        Self {
+            base_url,
+            path_prefix: String::new(),
+        }
+    }
+
+    /// Prepend `prefix` to every path converted by this endpoint.
+    ///
+    /// Use it for services that mount Redfish at a non-root path: with
+    /// prefix `/api`, `/redfish/v1` is requested as `/api/redfish/v1`.
+    /// Paths that already start with the prefix (for example, from a
+    /// `Location` header) are kept as is. The same rule applies to
+    /// paths of resolved URI references.
+    #[must_use]
+    pub fn with_prefix(mut self, prefix: &str) -> Self {
+        self.path_prefix = prefix.trim_end_matches('/').to_string();
+        self
     }
 
     /// Convert a path to a full Redfish endpoint URL
     #[must_use]
     pub fn with_path(&self, path: &str) -> Url {
         let mut url = self.base_url.clone();
-        url.set_path(path);
+        url.set_path(&self.prefixed_path(path));
         url
     }
 
+    /// Join the path prefix and `path` unless `path` already has the prefix.
+    fn prefixed_path<'a>(&self, path: &'a str) -> Cow<'a, str> {
+        let has_prefix = path
+            .strip_prefix(self.path_prefix.as_str())
+            .is_some_and(|rest| rest.is_empty() || rest.starts_with('/'));
+        if self.path_prefix.is_empty() || has_prefix {
+            Cow::Borrowed(path)
+        } else {
+            Cow::Owned(format!("{}{path}", self.path_prefix))
+        }
+    }
+
     /// Convert an OData identifier, including its optional query, to an endpoint URL.
     ///
     /// `ODataId` is opaque and can contain a query, particularly when it comes
@@ -457,13 +787,16 @@ impl RedfishEndpoint {
     /// resolution. If the configured base URL includes a path component, that
     /// can differ from direct path replacement. Values rejected by
     /// URI-reference resolution are rejected before transport.
+    ///
+    /// The path of the resolved URL gets the path prefix the same way as
+    /// [`Self::with_path`] does.
     fn with_same_origin_uri_reference(
         &self,
         uri: UriReference<'_>,
     ) -> Result<Url, RejectedUriReferenceError> {
         let UriReference(uri) = uri;
 
-        let resolved = self
+        let mut resolved = self
             .base_url
             .join(uri)
             .map_err(|source| RejectedUriReferenceError {
@@ -484,6 +817,8 @@ impl RedfishEndpoint {
             });
         }
 
+        let path = self.prefixed_path(resolved.path()).into_owned();
+        resolved.set_path(&path);
         Ok(resolved)
     }
 
@@ -500,11 +835,19 @@ impl RedfishEndpoint {
 #[derive(Clone, Copy)]
 pub struct CacheSettings {
     capacity: usize,
+    max_age: Option<Duration>,
+    max_entry_size_bytes: Option<usize>,
+    request_timeout: Option<Duration>,
 }
 
 impl Default for CacheSettings {
     fn default() -> Self {
-        Self { capacity: 100 }
+        Self {
+            capacity: 100,
+            max_age: None,
+            max_entry_size_bytes: None,
+            request_timeout: None,
+        }
     }
 }
 
@@ -514,8 +857,68 @@ impl CacheSettings {
     /// A capacity of 0 disables caching.
     #[must_use]
     pub const fn with_capacity(capacity: usize) -> Self {
-        Self { capacity }
+        Self {
+            capacity,
+            max_age: None,
+            max_entry_size_bytes: None,
+            request_timeout: None,
+        }
     }
+
+    /// Define maximum age of cached entries.
+    ///
+    /// An entry older than `max_age` is not revalidated with its `ETag`:
+    /// the resource is fetched unconditionally instead. This protects
+    /// from BMCs that reuse `ETag`s after reboot. Without maximum age
+    /// entries never expire.
+    #[must_use]
+    pub const fn with_max_age(mut self, max_age: Duration) -> Self {
+        self.max_age = Some(max_age);
+        self
+    }
+
+    /// Define maximum size of a cached entity.
+    ///
+    /// Entities larger than `max_entry_size_bytes` are neither cached
+    /// nor revalidated with their `ETag`. The size is estimated with
+    /// [`std::mem::size_of_val`], so memory owned by the entity on the
+    /// heap is not counted.
+    #[must_use]
+    pub const fn with_max_entry_size_bytes(mut self, max_entry_size_bytes: usize) -> Self {
+        self.max_entry_size_bytes = Some(max_entry_size_bytes);
+        self
+    }
+
+    /// Define timeout of every request sent by [`HttpBmc`].
+    ///
+    /// See [`HttpBmc::with_timeout`].
+    #[must_use]
+    pub const fn with_request_timeout(mut self, timeout: Duration) -> Self {
+        self.request_timeout = Some(timeout);
+        self
+    }
+}
+
+/// Source of current time for cache expiry.
+pub trait Clock: Send + Sync {
+    /// Current instant.
+    fn now(&self) -> Instant;
+}
+
+/// [`Clock`] that uses [`Instant::now`].
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> Instant {
+        Instant::now()
+    }
+}
+
+/// `ETag` of a cached entity and the time it was stored.
+struct CachedETag {
+    etag: ODataETag,
+    stored_at: Instant,
 }
 
 impl From<Url> for RedfishEndpoint {
@@ -548,6 +951,13 @@ pub trait CacheableError {
 pub trait RequestError {
     /// Create an error from a rejected service URI reference.
     fn rejected_uri_reference(error: RejectedUriReferenceError) -> Self;
+
+    /// Returns true if the request was rejected with HTTP 401
+    /// Unauthorized. Session authentication uses it to detect expired
+    /// session token.
+    fn is_unauthorized(&self) -> bool {
+        false
+    }
 }
 
 impl<C: HttpClient> HttpBmc<C>
@@ -562,6 +972,181 @@ where
             .expect("lock poisoned")
     }
 
+    /// Snapshot of custom headers for the next request.
+    #[allow(clippy::panic)] // See set_header Panic doc.
+    fn read_custom_headers(&self) -> Arc<HeaderMap> {
+        self.custom_headers
+            .read()
+            .map(|headers| Arc::clone(&headers))
+            .expect("lock poisoned")
+    }
+
+    /// Credentials for the next request.
+    ///
+    /// With session authentication, logs in if there is no active
+    /// session yet.
+    #[allow(clippy::significant_drop_tightening)] // Lock is held during login.
+    async fn request_credentials(&self) -> Result<Arc<BmcCredentials>, C::Error> {
+        let credentials = self.read_credentials();
+        let Some(session) = &self.session else {
+            return Ok(credentials);
+        };
+        let BmcCredentials::UsernamePassword { username, password } = credentials.as_ref() else {
+            return Ok(credentials);
+        };
+        let mut active = session.active.lock().await;
+        if let Some(active) = active
+            .as_ref()
+            .filter(|active| Arc::ptr_eq(&active.login_credentials, &credentials))
+        {
+            return Ok(Arc::clone(&active.credentials));
+        }
+        let new_session = self
+            .login(username, password.as_deref(), Arc::clone(&credentials))
+            .await?;
+        let credentials = Arc::clone(&new_session.credentials);
+        *active = Some(new_session);
+        Ok(credentials)
+    }
+
+    /// Replace session which token was rejected by the BMC.
+    ///
+    /// If other request has already replaced the session, its
+    /// credentials are reused. Returns `None` if there is nothing to
+    /// log in with: configured credentials are a token.
+    #[allow(clippy::significant_drop_tightening)] // Lock is held during login.
+    async fn relogin(
+        &self,
+        rejected: &Arc<BmcCredentials>,
+    ) -> Result<Option<Arc<BmcCredentials>>, C::Error> {
+        let credentials = self.read_credentials();
+        let Some(session) = &self.session else {
+            return Ok(Some(credentials));
+        };
+        let BmcCredentials::UsernamePassword { username, password } = credentials.as_ref() else {
+            return Ok(None);
+        };
+        let mut active = session.active.lock().await;
+        if let Some(active) = active.as_ref().filter(|active| {
+            !Arc::ptr_eq(&active.credentials, rejected)
+                && Arc::ptr_eq(&active.login_credentials, &credentials)
+        }) {
+            return Ok(Some(Arc::clone(&active.credentials)));
+        }
+        *active = None;
+        let new_session = self
+            .login(username, password.as_deref(), Arc::clone(&credentials))
+            .await?;
+        let credentials = Arc::clone(&new_session.credentials);
+        *active = Some(new_session);
+        Ok(Some(credentials))
+    }
+
+    /// Create new session using username and password.
+    ///
+    /// `login_credentials` are the configured credentials `user_name`
+    /// and `password` come from.
+    async fn login(
+        &self,
+        user_name: &str,
+        password: Option<&str>,
+        login_credentials: Arc<BmcCredentials>,
+    ) -> Result<ActiveSession, C::Error> {
+        let password = password.unwrap_or_default();
+        let endpoint_url = self.redfish_endpoint.with_path(&format!(
+            "{}/SessionService/Sessions",
+            ODataId::service_root()
+        ));
+        let response = self
+            .client
+            .post_session::<_, IgnoredAny>(
+                endpoint_url,
+                &SessionLogin {
+                    user_name,
+                    password,
+                },
+                &self.read_custom_headers(),
+            )
+            .await?;
+        Ok(ActiveSession {
+            credentials: Arc::new(BmcCredentials::token(response.auth_token)),
+            location: response.location,
+            login_credentials,
+        })
+    }
+
+    /// Perform request with credentials for the current
+    /// authentication mode.
+    ///
+    /// With session authentication, request rejected as unauthorized
+    /// is retried once with new session.
+    async fn with_credentials<R, F, Fut>(&self, request: F) -> Result<R, C::Error>
+    where
+        F: Fn(Arc<BmcCredentials>) -> Fut,
+        Fut: Future<Output = Result<R, C::Error>>,
+    {
+        let credentials = self.request_credentials().await?;
+        match request(Arc::clone(&credentials)).await {
+            Err(err) if self.session.is_some() && err.is_unauthorized() => {
+                match self.relogin(&credentials).await? {
+                    Some(credentials) => request(credentials).await,
+                    None => Err(err),
+                }
+            }
+            result => result,
+        }
+    }
+
+    /// Perform request that cannot be replayed, such as an upload of a
+    /// streamed body.
+    ///
+    /// With session authentication, request rejected as unauthorized
+    /// is not retried, but new session is created so the next request
+    /// uses a fresh token.
+    async fn with_credentials_once<R, F, Fut>(&self, request: F) -> Result<R, C::Error>
+    where
+        F: FnOnce(Arc<BmcCredentials>) -> Fut,
+        Fut: Future<Output = Result<R, C::Error>>,
+    {
+        let credentials = self.request_credentials().await?;
+        let result = request(Arc::clone(&credentials)).await;
+        if let Err(err) = &result {
+            if self.session.is_some() && err.is_unauthorized() {
+                // The original error is more useful to the caller; a
+                // failed login is repeated by the next request anyway.
+                let _ = self.relogin(&credentials).await;
+            }
+        }
+        result
+    }
+
+    /// Delete the session created by session authentication.
+    ///
+    /// Does nothing if session authentication is not enabled or there
+    /// is no active session. Next request creates new session.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the session delete request fails.
+    pub async fn logout(&self) -> Result<(), C::Error> {
+        let Some(session) = &self.session else {
+            return Ok(());
+        };
+        let active = session.active.lock().await.take();
+        if let Some(active) = active {
+            let endpoint_url = self.redfish_endpoint.with_odata_id(&active.location);
+            let _: ModificationResponse<IgnoredAny> = self
+                .client
+                .delete(
+                    endpoint_url,
+                    active.credentials.as_ref(),
+                    &self.read_custom_headers(),
+                )
+                .await?;
+        }
+        Ok(())
+    }
+
     /// Perform a GET request with `ETag` caching support
     ///
     /// This handles:
@@ -585,22 +1170,35 @@ where
                 .read()
                 .map_err(|e| C::Error::cache_error(e.to_string()))?;
 
-            etags.get(&cache_key).cloned()
+            let now = self.clock.now();
+            etags
+                .get(&cache_key)
+                .filter(|cached| {
+                    self.max_age.is_none_or(|max_age| {
+                        now.saturating_duration_since(cached.stored_at) <= max_age
+                    })
+                })
+                .map(|cached| cached.etag.clone())
         } else {
             None
         };
 
-        let credentials = self.read_credentials();
-
         // Perform GET request
         match self
-            .client
-            .get::<T>(
-                endpoint_url,
-                credentials.as_ref(),
-                etag,
-                &self.custom_headers,
-            )
+            .with_credentials(|credentials| {
+                let endpoint_url = endpoint_url.clone();
+                let etag = etag.clone();
+                async move {
+                    self.client
+                        .get::<T>(
+                            endpoint_url,
+                            credentials.as_ref(),
+                            etag,
+                            &self.read_custom_headers(),
+                        )
+                        .await
+                }
+            })
             .await
         {
             Ok(response) if !self.cache_enabled => {
@@ -622,12 +1220,28 @@ where
                         .write()
                         .map_err(|e| C::Error::cache_error(e.to_string()))?;
 
-                    if let Some(evicted_url) =
-                        cache.put_typed(cache_key.clone(), Arc::clone(&entity))
-                    {
-                        etags.remove(&evicted_url);
+                    // An entity that is too large is not cached, so its
+                    // `ETag` must not be sent either: a 304 response
+                    // would have no body to return.
+                    let (evicted_url, pruned_urls) = cache.put_typed(
+                        cache_key.clone(),
+                        Arc::clone(&entity),
+                        self.max_entry_size_bytes,
+                    );
+                    for url in evicted_url.iter().chain(&pruned_urls) {
+                        etags.remove(url);
+                    }
+                    if cache.contains_key(&cache_key) {
+                        etags.insert(
+                            cache_key.clone(),
+                            CachedETag {
+                                etag: etag.clone(),
+                                stored_at: self.clock.now(),
+                            },
+                        );
+                    } else {
+                        etags.remove(&cache_key);
                     }
-                    etags.insert(cache_key.clone(), etag.clone());
                 }
                 Ok(entity)
             }
@@ -650,6 +1264,9 @@ where
     }
 }
 
+/// Header carrying the id of the last received server-sent event.
+const LAST_EVENT_ID: HeaderName = HeaderName::from_static("last-event-id");
+
 impl<C: HttpClient> Bmc for HttpBmc<C>
 where
     C::Error: CacheableError + RequestError + StdError + Send + Sync,
@@ -664,6 +1281,32 @@ where
         self.get_with_cache(endpoint_url).await
     }
 
+    async fn head(&self, id: &ODataId) -> Result<bool, Self::Error>
+    where
+        Self::Error: NotFoundError,
+    {
+        let endpoint_url = self.redfish_endpoint.with_odata_id(id);
+        let result = self
+            .with_credentials(|credentials| {
+                let endpoint_url = endpoint_url.clone();
+                async move {
+                    self.client
+                        .head(
+                            endpoint_url,
+                            credentials.as_ref(),
+                            &self.read_custom_headers(),
+                        )
+                        .await
+                }
+            })
+            .await;
+        match result {
+            Ok(()) => Ok(true),
+            Err(err) if err.is_not_found() => Ok(false),
+            Err(err) => Err(err),
+        }
+    }
+
     async fn expand<T: Expandable + 'static>(
         &self,
         id: &ODataId,
@@ -671,7 +1314,7 @@ where
     ) -> Result<Arc<T>, Self::Error> {
         let endpoint_url = self
             .redfish_endpoint
-            .with_odata_id_and_query(id, &query.to_query_string());
+            .with_odata_id_and_query(id, &query.to_encoded_query_string());
 
         self.get_with_cache(endpoint_url).await
     }
@@ -682,10 +1325,20 @@ where
         v: &V,
     ) -> Result<ModificationResponse<R>, Self::Error> {
         let endpoint_url = self.redfish_endpoint.with_odata_id(id);
-        let credentials = self.read_credentials();
-        self.client
-            .post(endpoint_url, v, credentials.as_ref(), &self.custom_headers)
-            .await
+        self.with_credentials(|credentials| {
+            let endpoint_url = endpoint_url.clone();
+            async move {
+                self.client
+                    .post(
+                        endpoint_url,
+                        v,
+                        credentials.as_ref(),
+                        &self.read_custom_headers(),
+                    )
+                    .await
+            }
+        })
+        .await
     }
 
     async fn create_session<
@@ -698,7 +1351,7 @@ where
     ) -> Result<SessionCreateResponse<R>, Self::Error> {
         let endpoint_url = self.redfish_endpoint.with_odata_id(id);
         self.client
-            .post_session(endpoint_url, v, &self.custom_headers)
+            .post_session(endpoint_url, v, &self.read_custom_headers())
             .await
     }
 
@@ -708,31 +1361,48 @@ where
         etag: Option<&ODataETag>,
         v: &V,
     ) -> Result<ModificationResponse<R>, Self::Error> {
+        self.invalidate(id)?;
         let endpoint_url = self.redfish_endpoint.with_odata_id(id);
         let etag = etag
             .cloned()
             .unwrap_or_else(|| ODataETag::from(String::from("*")));
-        let credentials = self.read_credentials();
-        self.client
-            .patch(
-                endpoint_url,
-                etag,
-                v,
-                credentials.as_ref(),
-                &self.custom_headers,
-            )
-            .await
+        self.with_credentials(|credentials| {
+            let endpoint_url = endpoint_url.clone();
+            let etag = etag.clone();
+            async move {
+                self.client
+                    .patch(
+                        endpoint_url,
+                        etag,
+                        v,
+                        credentials.as_ref(),
+                        &self.read_custom_headers(),
+                    )
+                    .await
+            }
+        })
+        .await
     }
 
     async fn delete<T: Sync + Send + for<'de> Deserialize<'de>>(
         &self,
         id: &ODataId,
     ) -> Result<ModificationResponse<T>, Self::Error> {
+        self.invalidate(id)?;
         let endpoint_url = self.redfish_endpoint.with_odata_id(id);
-        let credentials = self.read_credentials();
-        self.client
-            .delete(endpoint_url, credentials.as_ref(), &self.custom_headers)
-            .await
+        self.with_credentials(|credentials| {
+            let endpoint_url = endpoint_url.clone();
+            async move {
+                self.client
+                    .delete(
+                        endpoint_url,
+                        credentials.as_ref(),
+                        &self.read_custom_headers(),
+                    )
+                    .await
+            }
+        })
+        .await
     }
 
     async fn action<T: Send + Sync + Serialize, R: Send + Sync + for<'de> Deserialize<'de>>(
@@ -745,15 +1415,20 @@ where
             .with_same_origin_uri_reference(UriReference(action.target.as_str()))
             .map_err(C::Error::rejected_uri_reference)?;
 
-        let credentials = self.read_credentials();
-        self.client
-            .post(
-                endpoint_url,
-                params,
-                credentials.as_ref(),
-                &self.custom_headers,
-            )
-            .await
+        self.with_credentials(|credentials| {
+            let endpoint_url = endpoint_url.clone();
+            async move {
+                self.client
+                    .post(
+                        endpoint_url,
+                        params,
+                        credentials.as_ref(),
+                        &self.read_custom_headers(),
+                    )
+                    .await
+            }
+        })
+        .await
     }
 
     async fn multipart_update<U, V, R>(
@@ -771,16 +1446,17 @@ where
             .with_same_origin_uri_reference(UriReference(uri))
             .map_err(C::Error::rejected_uri_reference)?;
 
-        let credentials = self.read_credentials();
-
-        self.client
-            .post_multipart_update(
-                endpoint_url,
-                request,
-                credentials.as_ref(),
-                &self.custom_headers,
-            )
-            .await
+        self.with_credentials_once(|credentials| async move {
+            self.client
+                .post_multipart_update(
+                    endpoint_url,
+                    request,
+                    credentials.as_ref(),
+                    &self.read_custom_headers(),
+                )
+                .await
+        })
+        .await
     }
 
     #[cfg(feature = "update-service-deprecated")]
@@ -798,16 +1474,17 @@ where
             .with_same_origin_uri_reference(UriReference(uri))
             .map_err(C::Error::rejected_uri_reference)?;
 
-        let credentials = self.read_credentials();
-
-        self.client
-            .post_http_push_uri_update(
-                endpoint_url,
-                request,
-                credentials.as_ref(),
-                &self.custom_headers,
-            )
-            .await
+        self.with_credentials_once(|credentials| async move {
+            self.client
+                .post_http_push_uri_update(
+                    endpoint_url,
+                    request,
+                    credentials.as_ref(),
+                    &self.read_custom_headers(),
+                )
+                .await
+        })
+        .await
     }
 
     async fn filter<T: EntityTypeRef + for<'de> Deserialize<'de> + 'static>(
@@ -817,7 +1494,31 @@ where
     ) -> Result<Arc<T>, Self::Error> {
         let endpoint_url = self
             .redfish_endpoint
-            .with_odata_id_and_query(id, &query.to_query_string());
+            .with_odata_id_and_query(id, &query.to_encoded_query_string());
+
+        self.get_with_cache(endpoint_url).await
+    }
+
+    async fn select<T: EntityTypeRef + for<'de> Deserialize<'de> + 'static>(
+        &self,
+        id: &ODataId,
+        query: SelectQuery,
+    ) -> Result<Arc<T>, Self::Error> {
+        let endpoint_url = self
+            .redfish_endpoint
+            .with_odata_id_and_query(id, &query.to_encoded_query_string());
+
+        self.get_with_cache(endpoint_url).await
+    }
+
+    async fn page<T: EntityTypeRef + for<'de> Deserialize<'de> + 'static>(
+        &self,
+        id: &ODataId,
+        query: PageQuery,
+    ) -> Result<Arc<T>, Self::Error> {
+        let endpoint_url = self
+            .redfish_endpoint
+            .with_odata_id_and_query(id, &query.to_encoded_query_string());
 
         self.get_with_cache(endpoint_url).await
     }
@@ -831,10 +1532,48 @@ where
             .with_same_origin_uri_reference(UriReference(uri))
             .map_err(C::Error::rejected_uri_reference)?;
 
-        let credentials = self.read_credentials();
-        self.client
-            .sse(endpoint_url, credentials.as_ref(), &self.custom_headers)
-            .await
+        self.with_credentials(|credentials| {
+            let endpoint_url = endpoint_url.clone();
+            async move {
+                self.client
+                    .sse(
+                        endpoint_url,
+                        credentials.as_ref(),
+                        &self.read_custom_headers(),
+                    )
+                    .await
+            }
+        })
+        .await
+    }
+
+    async fn stream_resume<T: Send + Sized + for<'de> Deserialize<'de>>(
+        &self,
+        uri: &str,
+        last_event_id: Option<&str>,
+    ) -> Result<BoxTryStream<T, Self::Error>, Self::Error> {
+        let endpoint_url = self
+            .redfish_endpoint
+            .with_same_origin_uri_reference(UriReference(uri))
+            .map_err(C::Error::rejected_uri_reference)?;
+
+        let mut headers = HeaderMap::clone(&self.read_custom_headers());
+        // Ids that are not valid header values cannot be resumed from;
+        // the stream is re-opened from the current position instead.
+        if let Some(value) = last_event_id.and_then(|id| HeaderValue::from_str(id).ok()) {
+            headers.insert(LAST_EVENT_ID, value);
+        }
+
+        self.with_credentials(|credentials| {
+            let endpoint_url = endpoint_url.clone();
+            let headers = &headers;
+            async move {
+                self.client
+                    .sse(endpoint_url, credentials.as_ref(), headers)
+                    .await
+            }
+        })
+        .await
     }
 }
 
@@ -910,6 +1649,89 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn path_prefix_is_prepended_to_paths() -> Result<(), Box<dyn Error>> {
+        let endpoint = RedfishEndpoint::new(Url::parse("https://bmc.example")?).with_prefix("/api");
+
+        assert_eq!(
+            endpoint.with_path("/redfish/v1").as_str(),
+            "https://bmc.example/api/redfish/v1"
+        );
+        assert_eq!(
+            endpoint
+                .with_path_and_query("/redfish/v1/Systems", "$expand=.")
+                .as_str(),
+            "https://bmc.example/api/redfish/v1/Systems?$expand=."
+        );
+
+        let id = ODataId::from("/redfish/v1/TaskService/Tasks/42?token=abc".to_string());
+        let resolved = endpoint.with_odata_id(&id);
+        assert_eq!(resolved.path(), "/api/redfish/v1/TaskService/Tasks/42");
+        assert_eq!(resolved.query(), Some("token=abc"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn path_prefix_is_not_doubled() -> Result<(), Box<dyn Error>> {
+        let endpoint = RedfishEndpoint::new(Url::parse("https://bmc.example")?).with_prefix("/api");
+
+        let id = ODataId::from("/api/redfish/v1/SessionService/Sessions/1".to_string());
+        assert_eq!(
+            endpoint.with_odata_id(&id).path(),
+            "/api/redfish/v1/SessionService/Sessions/1"
+        );
+        assert_eq!(endpoint.with_path("/api").path(), "/api");
+        assert_eq!(
+            endpoint.with_path("/apis/redfish/v1").path(),
+            "/api/apis/redfish/v1"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn path_prefix_is_applied_to_uri_references() -> Result<(), Box<dyn Error>> {
+        let endpoint = RedfishEndpoint::new(Url::parse("https://bmc.example")?).with_prefix("/api");
+
+        for (uri, expected) in [
+            (
+                "/redfish/v1/Systems/1/Actions/ComputerSystem.Reset",
+                "https://bmc.example/api/redfish/v1/Systems/1/Actions/ComputerSystem.Reset",
+            ),
+            (
+                "/api/redfish/v1/Systems/1/Actions/ComputerSystem.Reset",
+                "https://bmc.example/api/redfish/v1/Systems/1/Actions/ComputerSystem.Reset",
+            ),
+            (
+                "https://bmc.example/redfish/v1/EventService/SSE?$filter=x",
+                "https://bmc.example/api/redfish/v1/EventService/SSE?$filter=x",
+            ),
+            (
+                "redfish/v1/UpdateService/upload",
+                "https://bmc.example/api/redfish/v1/UpdateService/upload",
+            ),
+        ] {
+            let resolved = endpoint.with_same_origin_uri_reference(UriReference(uri))?;
+            assert_eq!(resolved.as_str(), expected);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn path_prefix_trailing_slash_is_ignored() -> Result<(), Box<dyn Error>> {
+        let endpoint =
+            RedfishEndpoint::new(Url::parse("https://bmc.example")?).with_prefix("/api/");
+
+        assert_eq!(
+            endpoint.with_path("/redfish/v1").as_str(),
+            "https://bmc.example/api/redfish/v1"
+        );
+
+        Ok(())
+    }
+
     #[test]
     fn uri_reference_relative_path_follows_base_path() -> Result<(), Box<dyn Error>> {
         let endpoint = RedfishEndpoint::new(Url::parse("https://bmc.example/proxy/")?);
@@ -15,13 +15,16 @@
 
 //! Implementation of [`HttpClient`] trait using reqwest crate.
 
+use std::convert::TryFrom as _;
 use std::error::Error as StdErr;
 use std::fmt;
 use std::sync::Arc;
 use std::time::Duration;
 
+#[cfg(feature = "retry")]
+use crate::retry::RetryableError;
 use crate::schema::redfish::message::Message;
-use crate::schema::redfish::redfish_error::RedfishError;
+use crate::schema::redfish::redfish_error::RedfishError as RedfishErrorSchema;
 use crate::BmcCredentials;
 use crate::CacheableError;
 use crate::HttpClient;
@@ -38,6 +41,7 @@ use nv_redfish_core::AsyncTask;
 use nv_redfish_core::BoxTryStream;
 use nv_redfish_core::DataStream;
 use nv_redfish_core::ModificationResponse;
+use nv_redfish_core::NotFoundError;
 use nv_redfish_core::ODataETag;
 use nv_redfish_core::ODataId;
 use nv_redfish_core::OemMultipartPart;
@@ -48,6 +52,7 @@ use nv_redfish_core::UploadStream;
 use reqwest::multipart::Form;
 use reqwest::multipart::Part;
 use reqwest::redirect::Policy as RedirectPolicy;
+use reqwest::Certificate;
 use reqwest::Client as ReqwestClient;
 use reqwest::Error as ReqwestError;
 use serde::de::DeserializeOwned;
@@ -62,6 +67,8 @@ use url::Url;
 pub enum BmcError {
     /// Direct mapping of underlying reqwest error.
     ReqwestError(reqwest::Error),
+    /// Request or connection timeout expired.
+    Timeout(reqwest::Error),
     /// JSON to model deserialize error with path tracking.
     JsonError(serde_path_to_error::Error<serde_json::Error>),
     /// Unexpected HTTP response.
@@ -72,6 +79,8 @@ pub enum BmcError {
         status: reqwest::StatusCode,
         /// Text in the response.
         text: String,
+        /// Redfish error payload parsed from a JSON response body.
+        redfish_error: Option<Box<RedfishError>>,
     },
     /// SSE stream error.
     SseStreamError(sse_stream::Error),
@@ -85,11 +94,98 @@ pub enum BmcError {
     EncodeError(serde_json::Error),
     /// Request rejected before transport.
     InvalidRequest(String),
+    /// Request failed after all attempts allowed by [`RetryPolicy`].
+    RetriesExhausted {
+        /// Number of attempts made, including the first one.
+        attempts: u32,
+        /// Error of the last attempt.
+        source: Box<Self>,
+    },
+}
+
+/// One entry of `@Message.ExtendedInfo` in a Redfish error response.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RedfishExtendedInfo {
+    /// Human-readable message.
+    pub message: String,
+    /// Message identifier in `Registry.Version.MessageKey` form.
+    pub message_id: String,
+    /// Message severity, if reported by the BMC.
+    pub severity: Option<String>,
+}
+
+/// Redfish error response body (`{"error": {...}}`).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RedfishError {
+    /// Error code, typically a `MessageId`.
+    pub code: String,
+    /// Human-readable error message.
+    pub message: String,
+    /// Extended information about the error.
+    pub extended_info: Vec<RedfishExtendedInfo>,
+}
+
+impl RedfishError {
+    /// Parse a Redfish error response body. Returns `None` if the body
+    /// is not a Redfish error: a JSON object with an `error` member
+    /// that has `code` and `message`.
+    #[must_use]
+    pub fn parse(body: &str) -> Option<Self> {
+        // `@Message.ExtendedInfo` is an annotation, so it is not a
+        // property of the generated error schema.
+        #[derive(serde::Deserialize)]
+        struct ExtendedInfoEnvelope {
+            error: ExtendedInfo,
+        }
+
+        #[derive(serde::Deserialize)]
+        struct ExtendedInfo {
+            #[serde(rename = "@Message.ExtendedInfo", default)]
+            extended_info: Vec<Message>,
+        }
+
+        let value: serde_json::Value = serde_json::from_str(body).ok()?;
+        let response = <RedfishErrorSchema as serde::Deserialize>::deserialize(&value).ok()?;
+        let extended_info = <ExtendedInfoEnvelope as serde::Deserialize>::deserialize(&value)
+            .map(|envelope| envelope.error.extended_info)
+            .unwrap_or_default();
+        Some(Self {
+            code: response.error.code,
+            message: response.error.message,
+            extended_info: extended_info
+                .into_iter()
+                .map(|info| RedfishExtendedInfo {
+                    message: info.message.unwrap_or_default(),
+                    message_id: info.message_id,
+                    severity: info
+                        .message_severity
+                        .map(|severity| severity.to_string())
+                        .or(info.severity),
+                })
+                .collect(),
+        })
+    }
+}
+
+impl BmcError {
+    /// Redfish error payload returned by the BMC, if any.
+    #[must_use]
+    pub fn redfish_error(&self) -> Option<&RedfishError> {
+        match self {
+            Self::InvalidResponse { redfish_error, .. } => redfish_error.as_deref(),
+            Self::RetriesExhausted { source, .. } => source.redfish_error(),
+            _ => None,
+        }
+    }
 }
 
 impl From<reqwest::Error> for BmcError {
     fn from(value: reqwest::Error) -> Self {
-        Self::ReqwestError(value)
+        if value.is_timeout() {
+            Self::Timeout(value)
+        } else {
+            Self::ReqwestError(value)
+        }
     }
 }
 
@@ -114,13 +210,48 @@ impl RequestError for BmcError {
     fn rejected_uri_reference(error: RejectedUriReferenceError) -> Self {
         Self::InvalidRequest(error.reason)
     }
+
+    fn is_unauthorized(&self) -> bool {
+        match self {
+            Self::InvalidResponse { status, .. } => status == &reqwest::StatusCode::UNAUTHORIZED,
+            Self::RetriesExhausted { source, .. } => source.is_unauthorized(),
+            _ => false,
+        }
+    }
+}
+
+impl NotFoundError for BmcError {
+    fn is_not_found(&self) -> bool {
+        match self {
+            Self::InvalidResponse { status, .. } => status == &reqwest::StatusCode::NOT_FOUND,
+            Self::RetriesExhausted { source, .. } => source.is_not_found(),
+            _ => false,
+        }
+    }
+}
+
+#[cfg(feature = "retry")]
+impl RetryableError for BmcError {
+    fn is_transient(&self) -> bool {
+        match self {
+            Self::ReqwestError(e) => e.is_connect() || e.is_timeout(),
+            Self::Timeout(_) => true,
+            Self::InvalidResponse { status, .. } => {
+                status.is_server_error() || status == &reqwest::StatusCode::TOO_MANY_REQUESTS
+            }
+            _ => false,
+        }
+    }
 }
 
 impl fmt::Display for BmcError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             Self::ReqwestError(e) => write!(f, "HTTP client error: {e:?}"),
-            Self::InvalidResponse { url, status, text } => {
+            Self::Timeout(e) => write!(f, "HTTP request timed out: {e:?}"),
+            Self::InvalidResponse {
+                url, status, text, ..
+            } => {
                 write!(
                     f,
                     "Invalid HTTP response - url: {url} status: {status} text: {text}"
@@ -139,6 +270,9 @@ impl fmt::Display for BmcError {
             Self::DecodeError(e) => write!(f, "JSON Decode error: {e}"),
             Self::EncodeError(e) => write!(f, "JSON Encode error: {e}"),
             Self::InvalidRequest(e) => write!(f, "Invalid request: {e}"),
+            Self::RetriesExhausted { attempts, source } => {
+                write!(f, "Request failed after {attempts} attempts: {source}")
+            }
         }
     }
 }
@@ -146,10 +280,11 @@ impl fmt::Display for BmcError {
 impl StdErr for BmcError {
     fn source(&self) -> Option<&(dyn StdErr + 'static)> {
         match self {
-            Self::ReqwestError(e) => Some(e),
+            Self::ReqwestError(e) | Self::Timeout(e) => Some(e),
             Self::JsonError(e) => Some(e.inner()),
             Self::SseStreamError(e) => Some(e),
             Self::DecodeError(e) | Self::EncodeError(e) => Some(e),
+            Self::RetriesExhausted { source, .. } => Some(source.as_ref()),
             _ => None,
         }
     }
@@ -162,13 +297,21 @@ impl StdErr for BmcError {
 type RetryClassifier =
     dyn Fn(&reqwest::Request, &reqwest::Response) -> bool + Send + Sync + 'static;
 
+/// Classifier deciding whether a transport error should be retried.
+type TransportRetryClassifier =
+    dyn Fn(&reqwest::Request, &reqwest::Error) -> bool + Send + Sync + 'static;
+
 /// Retry policy with a configurable delay between attempts.
 ///
 /// While retries remain, the classifier is called for every received HTTP
 /// response, regardless of the request method, and decides whether to retry.
-/// Transport and connection errors are returned immediately. Requests with
-/// non-clonable (streaming) bodies, such as multipart uploads, are sent exactly
-/// once and never retried.
+/// Transport and connection errors are returned immediately unless
+/// [`Self::on_transport_error`] is configured. Requests with non-clonable
+/// (streaming) bodies, such as multipart uploads, are sent exactly once and
+/// never retried.
+///
+/// If the last allowed attempt still has to be retried, the error is
+/// returned as [`BmcError::RetriesExhausted`] with the number of attempts.
 ///
 /// # Examples
 ///
@@ -189,14 +332,36 @@ type RetryClassifier =
 ///
 /// let params = ClientParams::new().retry(policy);
 /// ```
+///
+/// Retry transient failures of `GET` and `PATCH` requests with
+/// exponential backoff starting at 200ms and up to 100ms of jitter:
+///
+/// ```rust
+/// use nv_redfish_bmc_http::reqwest::{ClientParams, RetryPolicy};
+/// use std::time::Duration;
+///
+/// let policy = RetryPolicy::transient(&[http::Method::GET, http::Method::PATCH])
+///     .max_retries(4)
+///     .delay(Duration::from_millis(200))
+///     .exponential_backoff()
+///     .jitter(Duration::from_millis(100));
+///
+/// let params = ClientParams::new().retry(policy);
+/// ```
 #[derive(Clone)]
 pub struct RetryPolicy {
     /// Number of extra attempts after the first one.
     max_retries: u32,
-    /// Fixed sleep between attempts; `None` retries immediately.
+    /// Sleep between attempts; `None` retries immediately.
     delay: Option<Duration>,
+    /// Double the delay after every attempt.
+    exponential_backoff: bool,
+    /// Upper bound of random delay added to every sleep.
+    jitter: Option<Duration>,
     /// Decides whether a response should be retried.
     classifier: Arc<RetryClassifier>,
+    /// Decides whether a transport error should be retried.
+    transport_classifier: Option<Arc<TransportRetryClassifier>>,
 }
 
 impl RetryPolicy {
@@ -212,10 +377,35 @@ impl RetryPolicy {
         Self {
             max_retries: 0,
             delay: None,
+            exponential_backoff: false,
+            jitter: None,
             classifier: Arc::new(classifier),
+            transport_classifier: None,
         }
     }
 
+    /// Creates a policy that retries transient failures of requests
+    /// with one of the `methods`.
+    ///
+    /// Server errors (5xx), `429 Too Many Requests` and connection or
+    /// timeout errors are retried. Other client errors (4xx) are never
+    /// retried. Only idempotent methods should be listed: `GET` is the
+    /// usual choice, `PATCH` and `DELETE` are opt-in.
+    #[must_use]
+    pub fn transient(methods: &[http::Method]) -> Self {
+        let response_methods = methods.to_vec();
+        let transport_methods = methods.to_vec();
+        Self::new(move |request, response| {
+            let status = response.status();
+            response_methods.contains(request.method())
+                && (status.is_server_error() || status == http::StatusCode::TOO_MANY_REQUESTS)
+        })
+        .on_transport_error(move |request, error| {
+            transport_methods.contains(request.method())
+                && (error.is_connect() || error.is_timeout())
+        })
+    }
+
     /// Maximum number of extra attempts after the initial request.
     #[must_use]
     pub const fn max_retries(mut self, max_retries: u32) -> Self {
@@ -224,11 +414,60 @@ impl RetryPolicy {
     }
 
     /// Fixed delay to sleep between attempts.
+    ///
+    /// With [`Self::exponential_backoff`] it is the delay before the
+    /// first retry.
     #[must_use]
     pub const fn delay(mut self, delay: Duration) -> Self {
         self.delay = Some(delay);
         self
     }
+
+    /// Double the delay after every attempt.
+    #[must_use]
+    pub const fn exponential_backoff(mut self) -> Self {
+        self.exponential_backoff = true;
+        self
+    }
+
+    /// Add random delay up to `jitter` to every sleep between attempts.
+    #[must_use]
+    pub const fn jitter(mut self, jitter: Duration) -> Self {
+        self.jitter = Some(jitter);
+        self
+    }
+
+    /// Retry transport errors accepted by `classifier`.
+    #[must_use]
+    pub fn on_transport_error<F>(mut self, classifier: F) -> Self
+    where
+        F: Fn(&reqwest::Request, &reqwest::Error) -> bool + Send + Sync + 'static,
+    {
+        self.transport_classifier = Some(Arc::new(classifier));
+        self
+    }
+
+    /// Delay before `retry` (starting from 1), without jitter.
+    fn backoff_delay(&self, retry: u32) -> Duration {
+        let delay = self.delay.unwrap_or_default();
+        if self.exponential_backoff {
+            let factor = 1_u32
+                .checked_shl(retry.saturating_sub(1))
+                .unwrap_or(u32::MAX);
+            delay.saturating_mul(factor)
+        } else {
+            delay
+        }
+    }
+
+    /// Delay before `retry` (starting from 1).
+    fn retry_delay(&self, retry: u32) -> Duration {
+        let jitter = self.jitter.map_or(Duration::ZERO, |jitter| {
+            let max = u64::try_from(jitter.as_nanos()).unwrap_or(u64::MAX).max(1);
+            Duration::from_nanos(fastrand::u64(..max))
+        });
+        self.backoff_delay(retry).saturating_add(jitter)
+    }
 }
 
 impl fmt::Debug for RetryPolicy {
@@ -236,7 +475,13 @@ impl fmt::Debug for RetryPolicy {
         f.debug_struct("RetryPolicy")
             .field("max_retries", &self.max_retries)
             .field("delay", &self.delay)
+            .field("exponential_backoff", &self.exponential_backoff)
+            .field("jitter", &self.jitter)
             .field("classifier", &"<closure>")
+            .field(
+                "transport_classifier",
+                &self.transport_classifier.as_ref().map(|_| "<closure>"),
+            )
             .finish()
     }
 }
@@ -258,6 +503,27 @@ impl fmt::Debug for RetryPolicy {
 ///     .user_agent("MyApp/1.0")
 ///     .accept_invalid_certs(true);
 /// ```
+///
+/// # Connection pooling
+///
+/// One [`Client`] is usually shared across many BMCs. The defaults keep
+/// at most one idle connection per host for 90 seconds, which bounds the
+/// number of open sockets when iterating over hundreds of hosts. Raising
+/// [`Self::pool_max_idle_per_host`] lets concurrent requests to the same
+/// BMC reuse TLS sessions instead of reconnecting, at the cost of more
+/// sockets held open across the fleet; many BMCs also limit the number of
+/// concurrent sessions they accept. A shorter [`Self::idle_timeout`]
+/// releases connections sooner when hosts are visited only once per sweep.
+///
+/// ```rust
+/// use nv_redfish_bmc_http::reqwest::ClientParams;
+/// use std::time::Duration;
+///
+/// let params = ClientParams::new()
+///     .pool_max_idle_per_host(4)
+///     .idle_timeout(Duration::from_secs(30))
+///     .connection_verbose(true);
+/// ```
 #[derive(Debug, Clone)]
 pub struct ClientParams {
     /// HTTP request timeout
@@ -280,12 +546,19 @@ pub struct ClientParams {
     pub pool_idle_timeout: Option<Duration>,
     /// Maximum idle connections per host
     pub pool_max_idle_per_host: Option<usize>,
+    /// Log connection reads and writes at `TRACE` level
+    pub connection_verbose: bool,
     /// List of default headers, added to every request
     pub default_headers: Option<HeaderMap>,
     /// Forces use of rust TLS, enabled by default
     pub use_rust_tls: bool,
     /// Retry policy for received responses, `None` disables retries
     pub retry: Option<RetryPolicy>,
+    /// DER-encoded certificates trusted instead of the built-in roots.
+    ///
+    /// When not empty, only servers presenting these certificates (or
+    /// certificates issued by them) are accepted.
+    pub pinned_certificates: Vec<Vec<u8>>,
 }
 
 impl Default for ClientParams {
@@ -299,9 +572,11 @@ impl Default for ClientParams {
             tcp_keepalive: Some(Duration::from_secs(60)),
             pool_idle_timeout: Some(Duration::from_secs(90)),
             pool_max_idle_per_host: Some(1),
+            connection_verbose: false,
             default_headers: None,
             use_rust_tls: true,
             retry: None,
+            pinned_certificates: Vec::new(),
         }
     }
 }
@@ -369,6 +644,13 @@ impl ClientParams {
         self
     }
 
+    /// See: [`reqwest::ClientBuilder::connection_verbose`].
+    #[must_use]
+    pub const fn connection_verbose(mut self, verbose: bool) -> Self {
+        self.connection_verbose = verbose;
+        self
+    }
+
     /// Clears timeout for this client.
     #[must_use]
     pub const fn no_timeout(mut self) -> Self {
@@ -389,6 +671,40 @@ impl ClientParams {
         self.retry = Some(retry);
         self
     }
+
+    /// Trust only the DER-encoded certificate `der` (in addition to
+    /// other pinned certificates).
+    ///
+    /// Pinning is the safer alternative to
+    /// [`Self::accept_invalid_certs`] for BMCs with self-signed
+    /// certificates.
+    ///
+    /// ```rust,no_run
+    /// use nv_redfish_bmc_http::reqwest::Client;
+    /// use nv_redfish_bmc_http::reqwest::ClientParams;
+    ///
+    /// # fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let der = std::fs::read("/etc/bmc/bmc-cert.der")?;
+    /// let client = Client::with_params(ClientParams::new().pinned_certificate(der))?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn pinned_certificate(mut self, der: Vec<u8>) -> Self {
+        self.pinned_certificates.push(der);
+        self
+    }
+
+    /// Trust only the DER-encoded `certs` (in addition to other pinned
+    /// certificates). See [`Self::pinned_certificate`].
+    #[must_use]
+    pub fn pinned_certificates<I>(mut self, certs: I) -> Self
+    where
+        I: IntoIterator<Item = Vec<u8>>,
+    {
+        self.pinned_certificates.extend(certs);
+        self
+    }
 }
 
 /// HTTP client implementation using the reqwest library.
@@ -397,9 +713,11 @@ impl ClientParams {
 /// reqwest HTTP client library. It supports all standard HTTP features including
 /// TLS, authentication, and connection pooling.
 #[derive(Clone)]
+#[allow(clippy::struct_field_names)] // `client` is the wrapped reqwest client.
 pub struct Client {
     client: ReqwestClient,
     retry: Option<RetryPolicy>,
+    request_timeout: Option<Duration>,
 }
 
 impl Client {
@@ -442,6 +760,13 @@ impl Client {
             builder = builder.danger_accept_invalid_certs(true);
         }
 
+        if !params.pinned_certificates.is_empty() {
+            builder = builder.tls_built_in_root_certs(false);
+            for der in &params.pinned_certificates {
+                builder = builder.add_root_certificate(Certificate::from_der(der)?);
+            }
+        }
+
         // Reqwest's standard policies enforce redirect limits but still follow cross-origin
         // targets, where Redfish-specific and custom authentication headers can be forwarded.
         // Wrap the selected standard policy so its limit and error behavior remain unchanged
@@ -464,6 +789,10 @@ impl Client {
             builder = builder.pool_max_idle_per_host(max_idle);
         }
 
+        if params.connection_verbose {
+            builder = builder.connection_verbose(true);
+        }
+
         if let Some(default_headers) = params.default_headers {
             builder = builder.default_headers(default_headers);
         }
@@ -471,6 +800,7 @@ impl Client {
         Ok(Self {
             client: builder.build()?,
             retry: params.retry,
+            request_timeout: None,
         })
     }
 
@@ -488,6 +818,33 @@ impl Client {
         Self {
             client,
             retry: None,
+            request_timeout: None,
+        }
+    }
+
+    /// Returns a client that applies `timeout` to every request
+    /// instead of [`ClientParams::timeout`]. Requests with their own
+    /// timeout (such as `HttpPushUri` uploads) keep it.
+    ///
+    /// The returned client shares connection pool with this one, so it
+    /// is cheap to create for a single call:
+    ///
+    /// ```rust,no_run
+    /// use std::time::Duration;
+    ///
+    /// use nv_redfish_bmc_http::reqwest::Client;
+    ///
+    /// # fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = Client::new()?;
+    /// let probe = client.with_request_timeout(Duration::from_secs(2));
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn with_request_timeout(&self, timeout: Duration) -> Self {
+        Self {
+            request_timeout: Some(timeout),
+            ..self.clone()
         }
     }
 }
@@ -495,33 +852,73 @@ impl Client {
 impl Client {
     /// Sends the request, retrying according to the configured [`RetryPolicy`].
     ///
-    /// Transport errors are returned immediately. Requests with streaming
-    /// bodies cannot be cloned and are sent exactly once.
-    async fn send(&self, request: reqwest::Request) -> Result<reqwest::Response, BmcError> {
+    /// Transport errors are returned immediately unless the policy
+    /// retries them. Requests with streaming bodies cannot be cloned and
+    /// are sent exactly once.
+    async fn send(&self, mut request: reqwest::Request) -> Result<reqwest::Response, BmcError> {
+        if request.timeout().is_none() {
+            *request.timeout_mut() = self.request_timeout;
+        }
+
         let Some(policy) = &self.retry else {
             return Ok(self.client.execute(request).await?);
         };
 
-        let mut attempt: u32 = 0;
+        let mut attempts: u32 = 0;
         let mut current = request;
         loop {
-            let is_last = attempt >= policy.max_retries;
             // try_clone() returns None for streaming bodies, which therefore
             // get a single attempt.
-            let next = if is_last { None } else { current.try_clone() };
-            let response = self.client.execute(current).await?;
-            match next {
-                // The clone is identical to the request just sent, so the
-                // classifier sees what went over the wire.
-                Some(next_request) if (policy.classifier)(&next_request, &response) => {
-                    if let Some(delay) = policy.delay {
-                        sleep(delay).await;
-                    }
-                    current = next_request;
-                    attempt += 1;
+            let Some(next_request) = current.try_clone() else {
+                return Ok(self.client.execute(current).await?);
+            };
+            attempts += 1;
+            // The clone is identical to the request just sent, so the
+            // classifiers see what went over the wire. Retryable outcome
+            // is kept to be reported if no attempts remain.
+            let retryable = match self.client.execute(current).await {
+                Ok(response)
+                    if policy.max_retries == 0
+                        || !(policy.classifier)(&next_request, &response) =>
+                {
+                    return Ok(response)
+                }
+                Ok(response) => Ok(response),
+                Err(error)
+                    if policy
+                        .transport_classifier
+                        .as_ref()
+                        .is_some_and(|classifier| classifier(&next_request, &error)) =>
+                {
+                    Err(error)
                 }
-                _ => return Ok(response),
+                Err(error) => return Err(error.into()),
+            };
+            if attempts <= policy.max_retries {
+                sleep(policy.retry_delay(attempts)).await;
+                current = next_request;
+                continue;
             }
+            // A custom classifier may retry any status. Only failed
+            // statuses become errors; others are returned as is.
+            let error = match retryable {
+                Ok(response)
+                    if response.status().is_success()
+                        || response.status() == reqwest::StatusCode::NOT_MODIFIED =>
+                {
+                    return Ok(response)
+                }
+                Ok(response) => error_response(response).await,
+                Err(error) => error.into(),
+            };
+            return Err(if attempts == 1 {
+                error
+            } else {
+                BmcError::RetriesExhausted {
+                    attempts,
+                    source: Box::new(error),
+                }
+            });
         }
     }
 
@@ -530,18 +927,14 @@ impl Client {
         T: DeserializeOwned,
     {
         if !response.status().is_success() {
-            return Err(BmcError::InvalidResponse {
-                url: response.url().clone(),
-                status: response.status(),
-                text: response.text().await.unwrap_or_else(|_| "<no data>".into()),
-            });
+            return Err(error_response(response).await);
         }
 
         let headers = response.headers().clone();
 
         let etag_header = etag_from_headers(&headers);
 
-        let mut value: serde_json::Value = response.json().await.map_err(BmcError::ReqwestError)?;
+        let mut value: serde_json::Value = response.json().await.map_err(BmcError::from)?;
 
         if let Some(etag) = etag_header {
             inject_etag(&etag, &mut value);
@@ -561,11 +954,7 @@ impl Client {
         let url = response.url().clone();
         let headers = response.headers().clone();
         if !status.is_success() {
-            return Err(BmcError::InvalidResponse {
-                url,
-                status,
-                text: response.text().await.unwrap_or_else(|_| "<no data>".into()),
-            });
+            return Err(error_response(response).await);
         }
 
         let etag = etag_from_headers(&headers);
@@ -584,6 +973,7 @@ impl Client {
                         url,
                         status,
                         text: String::from("202 Accepted without Location header"),
+                        redfish_error: None,
                     });
                 };
 
@@ -593,7 +983,7 @@ impl Client {
                 }))
             }
             reqwest::StatusCode::OK | reqwest::StatusCode::CREATED => {
-                let bytes = response.bytes().await.map_err(BmcError::ReqwestError)?;
+                let bytes = response.bytes().await.map_err(BmcError::from)?;
                 if !bytes.is_empty() {
                     let value: serde_json::Value =
                         serde_json::from_slice(&bytes).map_err(BmcError::DecodeError)?;
@@ -640,6 +1030,7 @@ impl Client {
                 url,
                 status,
                 text: format!("Unexpected successful status code: {status}"),
+                redfish_error: None,
             }),
         }
     }
@@ -655,11 +1046,7 @@ impl Client {
         let url = response.url().clone();
         let headers = response.headers().clone();
         if !status.is_success() {
-            return Err(BmcError::InvalidResponse {
-                url,
-                status,
-                text: response.text().await.unwrap_or_else(|_| "<no data>".into()),
-            });
+            return Err(error_response(response).await);
         }
 
         let Some(auth_token) = auth_token_from_headers(&headers) else {
@@ -667,6 +1054,7 @@ impl Client {
                 url,
                 status,
                 text: String::from("session creation response missing X-Auth-Token header"),
+                redfish_error: None,
             });
         };
 
@@ -677,18 +1065,20 @@ impl Client {
                 url,
                 status,
                 text: String::from("session creation response missing Location header"),
+                redfish_error: None,
             });
         };
 
         match status {
             reqwest::StatusCode::OK | reqwest::StatusCode::CREATED => {
                 let etag = etag_from_headers(&headers);
-                let bytes = response.bytes().await.map_err(BmcError::ReqwestError)?;
+                let bytes = response.bytes().await.map_err(BmcError::from)?;
                 if bytes.is_empty() {
                     return Err(BmcError::InvalidResponse {
                         url,
                         status,
                         text: String::from("session creation response missing entity body"),
+                        redfish_error: None,
                     });
                 }
 
@@ -710,16 +1100,19 @@ impl Client {
                 url,
                 status,
                 text: String::from("session creation returned 202 Accepted without session entity"),
+                redfish_error: None,
             }),
             reqwest::StatusCode::NO_CONTENT => Err(BmcError::InvalidResponse {
                 url,
                 status,
                 text: String::from("session creation returned 204 No Content"),
+                redfish_error: None,
             }),
             _ => Err(BmcError::InvalidResponse {
                 url,
                 status,
                 text: format!("Unexpected successful status code for session creation: {status}"),
+                redfish_error: None,
             }),
         }
     }
@@ -757,6 +1150,7 @@ fn location_from_headers(
         url: response_url.clone(),
         status,
         text: text.to_string(),
+        redfish_error: None,
     };
 
     let Some(value) = headers.get(header::LOCATION) else {
@@ -836,6 +1230,30 @@ fn inject_etag(etag: &ODataETag, body: &mut serde_json::Value) {
     }
 }
 
+/// Build [`BmcError::InvalidResponse`] from an unsuccessful response,
+/// parsing the Redfish error payload when the body is JSON.
+async fn error_response(response: reqwest::Response) -> BmcError {
+    let url = response.url().clone();
+    let status = response.status();
+    let is_json = response
+        .headers()
+        .get(header::CONTENT_TYPE)
+        .and_then(|v| v.to_str().ok())
+        .and_then(|v| v.split(';').next())
+        .is_some_and(|v| v.trim().eq_ignore_ascii_case("application/json"));
+    let text = response.text().await.unwrap_or_else(|_| "<no data>".into());
+    let redfish_error = is_json
+        .then(|| RedfishError::parse(&text))
+        .flatten()
+        .map(Box::new);
+    BmcError::InvalidResponse {
+        url,
+        status,
+        text,
+        redfish_error,
+    }
+}
+
 /// DSP0266 7.11, Table 10 allows actions without response bodies to return
 /// an error-shaped success body. Only that body should become Empty.
 #[inline]
@@ -851,7 +1269,7 @@ fn is_redfish_success_response(value: &serde_json::Value) -> bool {
         return true;
     }
 
-    let Ok(response) = <RedfishError as serde::Deserialize>::deserialize(value) else {
+    let Ok(response) = <RedfishErrorSchema as serde::Deserialize>::deserialize(value) else {
         return false;
     };
 
@@ -897,6 +1315,23 @@ impl HttpClient for Client {
         self.handle_response(response).await
     }
 
+    async fn head(
+        &self,
+        url: Url,
+        credentials: &BmcCredentials,
+        custom_headers: &HeaderMap,
+    ) -> Result<(), Self::Error> {
+        let request = auth_headers(self.client.request(reqwest::Method::HEAD, url), credentials)
+            .headers(custom_headers.clone());
+
+        let response = self.send(request.build()?).await?;
+        if response.status().is_success() {
+            Ok(())
+        } else {
+            Err(error_response(response).await)
+        }
+    }
+
     async fn post<B, T>(
         &self,
         url: Url,
@@ -1075,11 +1510,7 @@ impl HttpClient for Client {
         let response = self.send(request.build()?).await?;
 
         if !response.status().is_success() {
-            return Err(BmcError::InvalidResponse {
-                url: response.url().clone(),
-                status: response.status(),
-                text: response.text().await.unwrap_or_else(|_| "<no data>".into()),
-            });
+            return Err(error_response(response).await);
         }
 
         let stream = sse_stream::SseStream::from_bytes_stream(response.bytes_stream()).filter_map(
@@ -1098,6 +1529,14 @@ impl HttpClient for Client {
 
         Ok(Box::pin(stream))
     }
+
+    fn timeout(&self) -> Option<Duration> {
+        self.request_timeout
+    }
+
+    fn set_timeout(&mut self, timeout: Option<Duration>) {
+        self.request_timeout = timeout;
+    }
 }
 
 fn build_update_parameters_part<V>(update_parameters: &V) -> Result<Part, BmcError>
@@ -1106,7 +1545,7 @@ where
 {
     Part::bytes(serde_json::to_vec(update_parameters).map_err(BmcError::EncodeError)?)
         .mime_str("application/json")
-        .map_err(BmcError::ReqwestError)
+        .map_err(BmcError::from)
 }
 
 fn build_stream_part<U>(stream: DataStream<U>, content_type: &'static str) -> Result<Part, BmcError>
@@ -1117,9 +1556,21 @@ where
         name,
         reader,
         content_length,
+        progress,
     } = stream;
 
-    let body = reqwest::Body::wrap_stream(ReaderStream::new(reader.compat()));
+    let chunks = ReaderStream::new(reader.compat());
+    let body = if let Some(progress) = progress {
+        let mut read = 0_u64;
+        reqwest::Body::wrap_stream(chunks.inspect(move |chunk| {
+            if let Ok(chunk) = chunk {
+                read += chunk.len() as u64;
+                progress(read);
+            }
+        }))
+    } else {
+        reqwest::Body::wrap_stream(chunks)
+    };
     let part = match content_length {
         Some(length) => Part::stream_with_length(body, length),
         None => Part::stream(body),
@@ -1127,7 +1578,7 @@ where
 
     part.file_name(name)
         .mime_str(content_type)
-        .map_err(BmcError::ReqwestError)
+        .map_err(BmcError::from)
 }
 
 fn build_oem_part(part: OemMultipartPart) -> Result<(String, Part), BmcError> {
@@ -1218,6 +1669,7 @@ mod tests {
             url: "http://example.com/redfish/v1".parse().unwrap(),
             status: mock_response.status(),
             text: "".into(),
+            redfish_error: None,
         };
         assert!(error.is_cached());
 
@@ -1228,6 +1680,51 @@ mod tests {
         assert!(matches!(created_miss, BmcError::CacheMiss));
     }
 
+    #[test]
+    fn test_timeout_is_reported_and_set() -> Result<(), Box<dyn StdError>> {
+        let mut client = Client::new()?;
+        assert_eq!(client.timeout(), None);
+
+        let timeout = Duration::from_secs(3);
+        assert_eq!(
+            client.with_request_timeout(timeout).timeout(),
+            Some(timeout)
+        );
+
+        client.set_timeout(Some(timeout));
+        assert_eq!(client.timeout(), Some(timeout));
+        client.set_timeout(None);
+        assert_eq!(client.timeout(), None);
+        Ok(())
+    }
+
+    #[test]
+    fn test_pinned_certificates_are_collected() {
+        let params = ClientParams::new()
+            .pinned_certificate(vec![1])
+            .pinned_certificates(vec![vec![2], vec![3]]);
+        assert_eq!(params.pinned_certificates, [vec![1], vec![2], vec![3]]);
+    }
+
+    #[test]
+    fn test_connection_pool_params_build_client() -> Result<(), Box<dyn StdError>> {
+        let params = ClientParams::new()
+            .pool_max_idle_per_host(16)
+            .idle_timeout(Duration::from_secs(30))
+            .connection_verbose(true);
+        assert_eq!(params.pool_max_idle_per_host, Some(16));
+        assert_eq!(params.pool_idle_timeout, Some(Duration::from_secs(30)));
+        assert!(params.connection_verbose);
+        Client::with_params(params)?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_invalid_pinned_certificate_fails_client_build() {
+        let params = ClientParams::new().pinned_certificate(b"not a certificate".to_vec());
+        assert!(matches!(Client::with_params(params), Err(err) if err.is_builder()));
+    }
+
     #[tokio::test]
     async fn cross_origin_redirect_is_rejected_before_forwarding_credentials(
     ) -> Result<(), Box<dyn StdError>> {
@@ -1558,6 +2055,253 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn test_retried_success_is_returned_when_retries_run_out() -> Result<(), Box<dyn StdError>>
+    {
+        let mock_server = MockServer::start().await;
+        let resource_path = "/redfish/v1";
+
+        Mock::given(method("GET"))
+            .and(path(resource_path))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(serde_json::json!({ "@odata.id": resource_path })),
+            )
+            .expect(2)
+            .mount(&mock_server)
+            .await;
+
+        // Custom classifier that retries every response, including 2xx.
+        let policy = RetryPolicy::new(|_request, _response| true).max_retries(1);
+        let client = Client::with_params(ClientParams::new().retry(policy))?;
+        let credentials = BmcCredentials::new("root".to_string(), "password".to_string());
+
+        let response: serde_json::Value = client
+            .get(
+                Url::parse(&format!("{}{resource_path}", mock_server.uri()))?,
+                &credentials,
+                None,
+                &HeaderMap::new(),
+            )
+            .await?;
+
+        assert_eq!(response["@odata.id"], resource_path);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_exponential_backoff_doubles_delay() {
+        let policy = RetryPolicy::transient(&[http::Method::GET])
+            .delay(Duration::from_millis(100))
+            .exponential_backoff();
+        assert_eq!(policy.backoff_delay(1), Duration::from_millis(100));
+        assert_eq!(policy.backoff_delay(2), Duration::from_millis(200));
+        assert_eq!(policy.backoff_delay(3), Duration::from_millis(400));
+        assert!(policy.backoff_delay(64) > policy.backoff_delay(3));
+    }
+
+    #[test]
+    fn test_jitter_is_bounded() {
+        let policy = RetryPolicy::transient(&[http::Method::GET])
+            .delay(Duration::from_millis(100))
+            .jitter(Duration::from_millis(10));
+        let delays = (0..1000).map(|_| policy.retry_delay(1)).collect::<Vec<_>>();
+        for delay in &delays {
+            assert!(*delay >= Duration::from_millis(100));
+            assert!(*delay < Duration::from_millis(110));
+        }
+        assert!(delays.iter().any(|delay| *delay != delays[0]));
+    }
+
+    #[tokio::test]
+    async fn test_transient_get_fails_twice_then_succeeds() -> Result<(), Box<dyn StdError>> {
+        let mock_server = MockServer::start().await;
+        let resource_path = "/redfish/v1";
+        mount_unavailable_then_ok(&mock_server, resource_path, 2).await;
+
+        let policy = RetryPolicy::transient(&[http::Method::GET])
+            .max_retries(3)
+            .delay(Duration::from_millis(1))
+            .exponential_backoff();
+        let client = Client::with_params(ClientParams::new().retry(policy))?;
+        let credentials = BmcCredentials::new("root".to_string(), "password".to_string());
+
+        let response: serde_json::Value = client
+            .get(
+                Url::parse(&format!("{}{resource_path}", mock_server.uri()))?,
+                &credentials,
+                None,
+                &HeaderMap::new(),
+            )
+            .await?;
+
+        assert_eq!(response["@odata.id"], resource_path);
+        mock_server.verify().await;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_transient_policy_retries_429_but_not_other_4xx() -> Result<(), Box<dyn StdError>>
+    {
+        let mock_server = MockServer::start().await;
+        let throttled_path = "/redfish/v1/Throttled";
+        let missing_path = "/redfish/v1/Missing";
+
+        Mock::given(method("GET"))
+            .and(path(throttled_path))
+            .respond_with(ResponseTemplate::new(429))
+            .up_to_n_times(1)
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path(throttled_path))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(serde_json::json!({ "@odata.id": throttled_path })),
+            )
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path(missing_path))
+            .respond_with(ResponseTemplate::new(404))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let policy = RetryPolicy::transient(&[http::Method::GET]).max_retries(3);
+        let client = Client::with_params(ClientParams::new().retry(policy))?;
+        let credentials = BmcCredentials::new("root".to_string(), "password".to_string());
+
+        let response: serde_json::Value = client
+            .get(
+                Url::parse(&format!("{}{throttled_path}", mock_server.uri()))?,
+                &credentials,
+                None,
+                &HeaderMap::new(),
+            )
+            .await?;
+        assert_eq!(response["@odata.id"], throttled_path);
+
+        let response = client
+            .get::<serde_json::Value>(
+                Url::parse(&format!("{}{missing_path}", mock_server.uri()))?,
+                &credentials,
+                None,
+                &HeaderMap::new(),
+            )
+            .await;
+        assert!(matches!(
+            response,
+            Err(BmcError::InvalidResponse { status, .. })
+                if status == reqwest::StatusCode::NOT_FOUND
+        ));
+        mock_server.verify().await;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_transient_policy_retries_patch_only_when_opted_in(
+    ) -> Result<(), Box<dyn StdError>> {
+        let mock_server = MockServer::start().await;
+        let resource_path = "/redfish/v1/Systems/1";
+
+        Mock::given(method("PATCH"))
+            .and(path(resource_path))
+            .respond_with(ResponseTemplate::new(503))
+            .up_to_n_times(2)
+            .expect(2)
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("PATCH"))
+            .and(path(resource_path))
+            .respond_with(ResponseTemplate::new(204))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let url = Url::parse(&format!("{}{resource_path}", mock_server.uri()))?;
+        let credentials = BmcCredentials::new("root".to_string(), "password".to_string());
+        let body = serde_json::json!({ "AssetTag": "tag" });
+
+        let get_only = Client::with_params(
+            ClientParams::new().retry(RetryPolicy::transient(&[http::Method::GET]).max_retries(3)),
+        )?;
+        let response = get_only
+            .patch::<_, serde_json::Value>(
+                url.clone(),
+                ODataETag::from(String::from("*")),
+                &body,
+                &credentials,
+                &HeaderMap::new(),
+            )
+            .await;
+        assert!(matches!(
+            response,
+            Err(BmcError::InvalidResponse { status, .. })
+                if status == reqwest::StatusCode::SERVICE_UNAVAILABLE
+        ));
+
+        let with_patch = Client::with_params(ClientParams::new().retry(
+            RetryPolicy::transient(&[http::Method::GET, http::Method::PATCH]).max_retries(3),
+        ))?;
+        let response = with_patch
+            .patch::<_, serde_json::Value>(
+                url,
+                ODataETag::from(String::from("*")),
+                &body,
+                &credentials,
+                &HeaderMap::new(),
+            )
+            .await?;
+        assert!(matches!(response, ModificationResponse::Empty));
+        mock_server.verify().await;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_exhausted_retries_report_attempts() -> Result<(), Box<dyn StdError>> {
+        let mock_server = MockServer::start().await;
+        let resource_path = "/redfish/v1";
+
+        Mock::given(method("GET"))
+            .and(path(resource_path))
+            .respond_with(ResponseTemplate::new(500))
+            .expect(3)
+            .mount(&mock_server)
+            .await;
+
+        let policy = RetryPolicy::transient(&[http::Method::GET]).max_retries(2);
+        let client = Client::with_params(ClientParams::new().retry(policy))?;
+        let credentials = BmcCredentials::new("root".to_string(), "password".to_string());
+
+        let response = client
+            .get::<serde_json::Value>(
+                Url::parse(&format!("{}{resource_path}", mock_server.uri()))?,
+                &credentials,
+                None,
+                &HeaderMap::new(),
+            )
+            .await;
+        let Err(BmcError::RetriesExhausted { attempts, source }) = response else {
+            return Err("expected exhausted retries error".into());
+        };
+        assert_eq!(attempts, 3);
+        assert!(matches!(
+            *source,
+            BmcError::InvalidResponse { status, .. }
+                if status == reqwest::StatusCode::INTERNAL_SERVER_ERROR
+        ));
+        mock_server.verify().await;
+
+        Ok(())
+    }
+
     #[tokio::test]
     async fn test_multipart_form_fails_oem_validation() -> Result<(), Box<dyn StdError>> {
         let mock_server = MockServer::start().await;
@@ -1695,6 +2439,119 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn redfish_error_parses_extended_info() {
+        let body = r#"{
+            "error": {
+                "code": "Base.1.8.GeneralError",
+                "message": "A general error has occurred.",
+                "@Message.ExtendedInfo": [
+                    {
+                        "MessageId": "Base.1.8.PropertyValueNotInList",
+                        "Message": "The value Foo for AssetTag is not in the list.",
+                        "MessageSeverity": "Warning"
+                    },
+                    {
+                        "MessageId": "Base.1.8.ResourceAtUriUnauthorized",
+                        "Message": "Unauthorized.",
+                        "Severity": "Critical"
+                    }
+                ]
+            }
+        }"#;
+
+        let error = RedfishError::parse(body).expect("redfish error");
+        assert_eq!(error.code, "Base.1.8.GeneralError");
+        assert_eq!(error.message, "A general error has occurred.");
+        assert_eq!(
+            error.extended_info,
+            vec![
+                RedfishExtendedInfo {
+                    message: "The value Foo for AssetTag is not in the list.".into(),
+                    message_id: "Base.1.8.PropertyValueNotInList".into(),
+                    severity: Some("Warning".into()),
+                },
+                RedfishExtendedInfo {
+                    message: "Unauthorized.".into(),
+                    message_id: "Base.1.8.ResourceAtUriUnauthorized".into(),
+                    severity: Some("Critical".into()),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn redfish_error_without_extended_info() {
+        let error = RedfishError::parse(
+            r#"{"error":{"code":"Base.1.8.GeneralError","message":"General error."}}"#,
+        )
+        .expect("redfish error");
+        assert_eq!(error.code, "Base.1.8.GeneralError");
+        assert_eq!(error.message, "General error.");
+        assert!(error.extended_info.is_empty());
+    }
+
+    #[test]
+    fn redfish_error_rejects_non_error_bodies() {
+        assert_eq!(RedfishError::parse(r#"{"Id":"1"}"#), None);
+        assert_eq!(
+            RedfishError::parse(r#"{"error":{"code":"Base.1.8.GeneralError"}}"#),
+            None
+        );
+        assert_eq!(RedfishError::parse("not json"), None);
+        assert_eq!(RedfishError::parse(""), None);
+    }
+
+    #[tokio::test]
+    async fn invalid_response_carries_parsed_redfish_error() -> Result<(), Box<dyn StdError>> {
+        let mock_server = MockServer::start().await;
+        let body = r#"{"error":{"code":"Base.1.8.GeneralError","message":"Bad request.","@Message.ExtendedInfo":[{"MessageId":"Base.1.8.PropertyMissing","Message":"The property UserName is missing.","MessageSeverity":"Warning"}]}}"#;
+        Mock::given(method("GET"))
+            .and(path("/redfish/v1/Systems/1"))
+            .respond_with(
+                ResponseTemplate::new(400).set_body_raw(body, "application/json; charset=utf-8"),
+            )
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/redfish/v1/Systems/2"))
+            .respond_with(ResponseTemplate::new(400).set_body_raw(body, "text/plain"))
+            .mount(&mock_server)
+            .await;
+
+        let client = Client::new()?;
+        let credentials = BmcCredentials::new("root".to_string(), "password".to_string());
+
+        let error = client
+            .get::<serde_json::Value>(
+                Url::parse(&format!("{}/redfish/v1/Systems/1", mock_server.uri()))?,
+                &credentials,
+                None,
+                &HeaderMap::new(),
+            )
+            .await
+            .expect_err("request must fail");
+        let redfish_error = error.redfish_error().expect("parsed redfish error");
+        assert_eq!(redfish_error.code, "Base.1.8.GeneralError");
+        assert_eq!(
+            redfish_error.extended_info[0].message_id,
+            "Base.1.8.PropertyMissing"
+        );
+
+        let error = client
+            .get::<serde_json::Value>(
+                Url::parse(&format!("{}/redfish/v1/Systems/2", mock_server.uri()))?,
+                &credentials,
+                None,
+                &HeaderMap::new(),
+            )
+            .await
+            .expect_err("request must fail");
+        assert!(error.redfish_error().is_none());
+
+        Ok(())
+    }
+
     fn multipart_body_contains(request: &Request, file_name: &str, file_body: &str) -> bool {
         let Some(content_type) = request
             .headers
@@ -0,0 +1,328 @@
+// SPDX-FileCopyrightText: Copyright (c) 2026 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Retry layer for any [`HttpClient`].
+//!
+//! [`RetryClient`] wraps an HTTP client and repeats requests that failed
+//! with a transient error (see [`RetryableError`]) using exponential
+//! backoff. Only idempotent requests (`GET`, `PATCH` guarded by ETag,
+//! `DELETE` and opening of SSE streams) are retried; `POST` requests
+//! and uploads are passed to the inner client as is.
+//!
+//! Available with the opt-in `retry` feature.
+//!
+//! # Examples
+//!
+//! ```rust,no_run
+//! use std::time::Duration;
+//!
+//! use nv_redfish_bmc_http::reqwest::Client;
+//! use nv_redfish_bmc_http::retry::RetryClient;
+//! use nv_redfish_bmc_http::retry::RetryClientConfig;
+//!
+//! # fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! let client = RetryClient::new(
+//!     Client::new()?,
+//!     RetryClientConfig {
+//!         max_retries: 5,
+//!         initial_delay: Duration::from_millis(200),
+//!         ..RetryClientConfig::default()
+//!     },
+//! );
+//! # Ok(())
+//! # }
+//! ```
+
+use std::convert::TryFrom as _;
+use std::future::Future;
+use std::time::Duration;
+
+use http::HeaderMap;
+use nv_redfish_core::BoxTryStream;
+use nv_redfish_core::ModificationResponse;
+use nv_redfish_core::ODataETag;
+use nv_redfish_core::SessionCreateResponse;
+use nv_redfish_core::UploadReader;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use tokio::time::sleep;
+use url::Url;
+
+use crate::BmcCredentials;
+use crate::CacheableError;
+use crate::HttpClient;
+use crate::MultipartUpdateRequest;
+
+#[cfg(feature = "update-service-deprecated")]
+use crate::HttpPushUriUpdateRequest;
+
+/// Trait for errors that can be caused by a transient failure.
+pub trait RetryableError {
+    /// Returns true if the same request may succeed when repeated
+    /// (for example, `503 Service Unavailable` or connection reset).
+    fn is_transient(&self) -> bool;
+}
+
+/// Configuration of [`RetryClient`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RetryClientConfig {
+    /// Maximum number of extra attempts after the initial request.
+    pub max_retries: u32,
+    /// Delay before the first retry.
+    pub initial_delay: Duration,
+    /// Factor applied to the delay after each retry.
+    pub multiplier: f64,
+    /// Upper bound of the delay between attempts.
+    pub max_delay: Duration,
+}
+
+impl Default for RetryClientConfig {
+    fn default() -> Self {
+        Self {
+            max_retries: 3,
+            initial_delay: Duration::from_millis(100),
+            multiplier: 2.0,
+            max_delay: Duration::from_secs(10),
+        }
+    }
+}
+
+impl RetryClientConfig {
+    /// Delay before `retry` (starting from 1).
+    fn delay(&self, retry: u32) -> Duration {
+        let exponent = i32::try_from(retry.saturating_sub(1)).unwrap_or(i32::MAX);
+        Duration::try_from_secs_f64(
+            self.initial_delay.as_secs_f64() * self.multiplier.powi(exponent),
+        )
+        .map_or(self.max_delay, |delay| delay.min(self.max_delay))
+    }
+}
+
+/// HTTP client that retries transient failures of the inner client.
+pub struct RetryClient<C> {
+    client: C,
+    config: RetryClientConfig,
+}
+
+impl<C> RetryClient<C> {
+    /// Wrap `client` with the retry `config`.
+    pub const fn new(client: C, config: RetryClientConfig) -> Self {
+        Self { client, config }
+    }
+
+    /// Inner HTTP client.
+    pub const fn inner(&self) -> &C {
+        &self.client
+    }
+
+    /// Retry configuration.
+    pub const fn config(&self) -> &RetryClientConfig {
+        &self.config
+    }
+}
+
+impl<C: HttpClient> RetryClient<C>
+where
+    C::Error: CacheableError + RetryableError,
+{
+    async fn retry<T, F, Fut>(&self, mut request: F) -> Result<T, C::Error>
+    where
+        F: FnMut() -> Fut + Send,
+        Fut: Future<Output = Result<T, C::Error>> + Send,
+    {
+        let mut retries = 0;
+        loop {
+            match request().await {
+                // Not Modified is an answer to be served from cache,
+                // never a failure to be repeated.
+                Err(err)
+                    if retries < self.config.max_retries
+                        && !err.is_cached()
+                        && err.is_transient() =>
+                {
+                    retries += 1;
+                    sleep(self.config.delay(retries)).await;
+                }
+                result => return result,
+            }
+        }
+    }
+}
+
+impl<C: HttpClient> HttpClient for RetryClient<C>
+where
+    C::Error: CacheableError + RetryableError,
+{
+    type Error = C::Error;
+
+    async fn get<T>(
+        &self,
+        url: Url,
+        credentials: &BmcCredentials,
+        etag: Option<ODataETag>,
+        custom_headers: &HeaderMap,
+    ) -> Result<T, Self::Error>
+    where
+        T: DeserializeOwned + Send + Sync,
+    {
+        self.retry(|| {
+            self.client
+                .get(url.clone(), credentials, etag.clone(), custom_headers)
+        })
+        .await
+    }
+
+    async fn head(
+        &self,
+        url: Url,
+        credentials: &BmcCredentials,
+        custom_headers: &HeaderMap,
+    ) -> Result<(), Self::Error> {
+        self.retry(|| self.client.head(url.clone(), credentials, custom_headers))
+            .await
+    }
+
+    async fn post<B, T>(
+        &self,
+        url: Url,
+        body: &B,
+        credentials: &BmcCredentials,
+        custom_headers: &HeaderMap,
+    ) -> Result<ModificationResponse<T>, Self::Error>
+    where
+        B: Serialize + Send + Sync,
+        T: DeserializeOwned + Send + Sync,
+    {
+        self.client
+            .post(url, body, credentials, custom_headers)
+            .await
+    }
+
+    async fn post_session<B, T>(
+        &self,
+        url: Url,
+        body: &B,
+        custom_headers: &HeaderMap,
+    ) -> Result<SessionCreateResponse<T>, Self::Error>
+    where
+        B: Serialize + Send + Sync,
+        T: DeserializeOwned + Send + Sync,
+    {
+        self.client.post_session(url, body, custom_headers).await
+    }
+
+    async fn post_multipart_update<U, V, T>(
+        &self,
+        url: Url,
+        request: MultipartUpdateRequest<'_, U, V>,
+        credentials: &BmcCredentials,
+        custom_headers: &HeaderMap,
+    ) -> Result<ModificationResponse<T>, Self::Error>
+    where
+        U: UploadReader,
+        T: DeserializeOwned + Send + Sync,
+        V: Serialize + Send + Sync,
+    {
+        self.client
+            .post_multipart_update(url, request, credentials, custom_headers)
+            .await
+    }
+
+    #[cfg(feature = "update-service-deprecated")]
+    async fn post_http_push_uri_update<U, T>(
+        &self,
+        url: Url,
+        request: HttpPushUriUpdateRequest<U>,
+        credentials: &BmcCredentials,
+        custom_headers: &HeaderMap,
+    ) -> Result<ModificationResponse<T>, Self::Error>
+    where
+        U: UploadReader,
+        T: DeserializeOwned + Send + Sync,
+    {
+        self.client
+            .post_http_push_uri_update(url, request, credentials, custom_headers)
+            .await
+    }
+
+    async fn patch<B, T>(
+        &self,
+        url: Url,
+        etag: ODataETag,
+        body: &B,
+        credentials: &BmcCredentials,
+        custom_headers: &HeaderMap,
+    ) -> Result<ModificationResponse<T>, Self::Error>
+    where
+        B: Serialize + Send + Sync,
+        T: DeserializeOwned + Send + Sync,
+    {
+        self.retry(|| {
+            self.client
+                .patch(url.clone(), etag.clone(), body, credentials, custom_headers)
+        })
+        .await
+    }
+
+    async fn delete<T>(
+        &self,
+        url: Url,
+        credentials: &BmcCredentials,
+        custom_headers: &HeaderMap,
+    ) -> Result<ModificationResponse<T>, Self::Error>
+    where
+        T: DeserializeOwned + Send + Sync,
+    {
+        self.retry(|| self.client.delete(url.clone(), credentials, custom_headers))
+            .await
+    }
+
+    async fn sse<T: Sized + for<'de> Deserialize<'de> + Send>(
+        &self,
+        url: Url,
+        credentials: &BmcCredentials,
+        custom_headers: &HeaderMap,
+    ) -> Result<BoxTryStream<T, Self::Error>, Self::Error> {
+        self.retry(|| self.client.sse(url.clone(), credentials, custom_headers))
+            .await
+    }
+
+    fn timeout(&self) -> Option<Duration> {
+        self.client.timeout()
+    }
+
+    fn set_timeout(&mut self, timeout: Option<Duration>) {
+        self.client.set_timeout(timeout);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_delay_grows_by_multiplier_up_to_max() {
+        let config = RetryClientConfig {
+            max_retries: 10,
+            initial_delay: Duration::from_millis(100),
+            multiplier: 3.0,
+            max_delay: Duration::from_secs(1),
+        };
+        assert_eq!(config.delay(1), Duration::from_millis(100));
+        assert_eq!(config.delay(2), Duration::from_millis(300));
+        assert_eq!(config.delay(3), Duration::from_millis(900));
+        assert_eq!(config.delay(4), Duration::from_secs(1));
+        assert_eq!(config.delay(u32::MAX), Duration::from_secs(1));
+    }
+}
@@ -0,0 +1,59 @@
+// SPDX-FileCopyrightText: Copyright (c) 2026 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Redfish `SessionService` based authentication state.
+
+use std::sync::Arc;
+
+use futures_util::lock::Mutex;
+use http::HeaderMap;
+use nv_redfish_core::ODataId;
+use serde::Serialize;
+use url::Url;
+
+use crate::BmcCredentials;
+
+/// Session created by login to `SessionService`.
+pub struct ActiveSession {
+    /// Token credentials of the session.
+    pub credentials: Arc<BmcCredentials>,
+    /// Session resource URI used to log out.
+    pub location: ODataId,
+    /// Username and password credentials the session was created with.
+    pub login_credentials: Arc<BmcCredentials>,
+}
+
+/// Deletes session with the given URL and token credentials when the
+/// client is dropped.
+pub type LogoutOnDrop = Box<dyn FnOnce(Url, Arc<BmcCredentials>, Arc<HeaderMap>) + Send + Sync>;
+
+/// Session authentication state.
+///
+/// The lock is held while login is in progress, so concurrent
+/// requests wait for the same session instead of creating new ones.
+#[derive(Default)]
+pub struct SessionAuth {
+    pub active: Mutex<Option<ActiveSession>>,
+    pub logout_on_drop: Option<LogoutOnDrop>,
+}
+
+/// Body of the session login request.
+#[derive(Serialize)]
+pub struct SessionLogin<'a> {
+    #[serde(rename = "UserName")]
+    pub user_name: &'a str,
+    #[serde(rename = "Password")]
+    pub password: &'a str,
+}
@@ -17,16 +17,23 @@ mod common;
 
 #[cfg(feature = "reqwest")]
 mod cache_integration_tests {
-    use std::{error::Error, sync::Arc};
+    use std::{
+        error::Error,
+        mem::size_of,
+        sync::{Arc, Mutex},
+        time::{Duration, Instant},
+    };
 
     use crate::common::test_utils::*;
 
     use nv_redfish_bmc_http::{
+        cache::CacheStats,
         reqwest::{BmcError, Client},
-        CacheSettings, HttpBmc,
+        CacheSettings, Clock, HttpBmc,
     };
     use nv_redfish_core::query::{ExpandQuery, FilterQuery};
     use nv_redfish_core::Bmc;
+    use nv_redfish_core::ModificationResponse;
     use url::Url;
     use wiremock::{
         matchers::{header, method, path, query_param},
@@ -88,6 +95,238 @@ mod cache_integration_tests {
             .await;
     }
 
+    async fn mount_cached_get_mock(
+        mock_server: &MockServer,
+        resource_path: &str,
+        resource: &TestResource,
+        etag_value: &str,
+    ) {
+        Mock::given(method("GET"))
+            .and(path(resource_path))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(resource)
+                    .insert_header("etag", etag_value),
+            )
+            .expect(2)
+            .mount(mock_server)
+            .await;
+    }
+
+    async fn assert_gets_are_unconditional(mock_server: &MockServer) {
+        let Some(received_requests) = mock_server.received_requests().await else {
+            panic!("request recording should be enabled");
+        };
+
+        let gets = received_requests
+            .iter()
+            .filter(|request| request.method.as_str() == "GET")
+            .collect::<Vec<_>>();
+        assert_eq!(gets.len(), 2);
+        assert!(gets
+            .iter()
+            .all(|request| !request.headers.contains_key("if-none-match")));
+    }
+
+    #[tokio::test]
+    async fn update_invalidates_cached_resource() -> Result<(), Box<dyn Error>> {
+        let mock_server = MockServer::start().await;
+        let resource_path = paths::SYSTEMS_1;
+        let etag_value = "before-update";
+        let test_resource =
+            create_test_resource(resource_path, Some(etag_value), names::TEST_SYSTEM, 1);
+
+        mount_cached_get_mock(&mock_server, resource_path, &test_resource, etag_value).await;
+
+        Mock::given(method("PATCH"))
+            .and(path(resource_path))
+            .respond_with(ResponseTemplate::new(204))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let bmc = create_test_bmc(&mock_server);
+        let resource_id = create_odata_id(resource_path);
+
+        bmc.get::<TestResource>(&resource_id).await?;
+
+        let update = UpdateRequest {
+            name: None,
+            value: Some(2),
+        };
+        let response = bmc
+            .update::<UpdateRequest, TestResource>(&resource_id, None, &update)
+            .await?;
+        assert!(matches!(response, ModificationResponse::Empty));
+
+        bmc.get::<TestResource>(&resource_id).await?;
+
+        mock_server.verify().await;
+        assert_gets_are_unconditional(&mock_server).await;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn delete_invalidates_cached_resource() -> Result<(), Box<dyn Error>> {
+        let mock_server = MockServer::start().await;
+        let resource_path = paths::CHASSIS_1;
+        let etag_value = "before-delete";
+        let test_resource =
+            create_test_resource(resource_path, Some(etag_value), names::TEST_CHASSIS, 1);
+
+        mount_cached_get_mock(&mock_server, resource_path, &test_resource, etag_value).await;
+
+        Mock::given(method("DELETE"))
+            .and(path(resource_path))
+            .respond_with(ResponseTemplate::new(204))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let bmc = create_test_bmc(&mock_server);
+        let resource_id = create_odata_id(resource_path);
+
+        bmc.get::<TestResource>(&resource_id).await?;
+        let response = bmc.delete::<TestResource>(&resource_id).await?;
+        assert!(matches!(response, ModificationResponse::Empty));
+        bmc.get::<TestResource>(&resource_id).await?;
+
+        mock_server.verify().await;
+        assert_gets_are_unconditional(&mock_server).await;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn get_after_invalidate_is_sent_to_network() -> Result<(), Box<dyn Error>> {
+        let mock_server = MockServer::start().await;
+        let resource_path = paths::SYSTEMS_1;
+        let etag_value = "before-invalidate";
+        let test_resource =
+            create_test_resource(resource_path, Some(etag_value), names::TEST_SYSTEM, 1);
+
+        mount_cached_get_mock(&mock_server, resource_path, &test_resource, etag_value).await;
+
+        let bmc = create_test_bmc(&mock_server);
+        let resource_id = create_odata_id(resource_path);
+
+        bmc.get::<TestResource>(&resource_id).await?;
+        bmc.invalidate(&resource_id)?;
+        bmc.get::<TestResource>(&resource_id).await?;
+
+        mock_server.verify().await;
+        assert_gets_are_unconditional(&mock_server).await;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn get_after_clear_cache_is_sent_to_network() -> Result<(), Box<dyn Error>> {
+        let mock_server = MockServer::start().await;
+        let resource_path = paths::CHASSIS_1;
+        let etag_value = "before-clear";
+        let test_resource =
+            create_test_resource(resource_path, Some(etag_value), names::TEST_CHASSIS, 1);
+
+        mount_cached_get_mock(&mock_server, resource_path, &test_resource, etag_value).await;
+
+        let bmc = create_test_bmc(&mock_server);
+        let resource_id = create_odata_id(resource_path);
+
+        bmc.get::<TestResource>(&resource_id).await?;
+        bmc.clear_cache()?;
+        bmc.get::<TestResource>(&resource_id).await?;
+
+        mock_server.verify().await;
+        assert_gets_are_unconditional(&mock_server).await;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn get_after_invalidate_all_is_sent_to_network() -> Result<(), Box<dyn Error>> {
+        let mock_server = MockServer::start().await;
+        let resource_path = paths::SYSTEMS_1;
+        let etag_value = "before-invalidate-all";
+        let test_resource =
+            create_test_resource(resource_path, Some(etag_value), names::TEST_SYSTEM, 1);
+
+        mount_cached_get_mock(&mock_server, resource_path, &test_resource, etag_value).await;
+
+        let bmc = create_test_bmc(&mock_server);
+        let resource_id = create_odata_id(resource_path);
+
+        bmc.get::<TestResource>(&resource_id).await?;
+        bmc.invalidate_all()?;
+        assert_eq!(bmc.cache_stats()?, CacheStats::default());
+        bmc.get::<TestResource>(&resource_id).await?;
+
+        mock_server.verify().await;
+        assert_gets_are_unconditional(&mock_server).await;
+
+        Ok(())
+    }
+
+    struct ManualClock(Mutex<Instant>);
+
+    impl ManualClock {
+        fn new() -> Arc<Self> {
+            Arc::new(Self(Mutex::new(Instant::now())))
+        }
+
+        fn advance(&self, duration: Duration) {
+            *self.0.lock().unwrap() += duration;
+        }
+    }
+
+    impl Clock for ManualClock {
+        fn now(&self) -> Instant {
+            *self.0.lock().unwrap()
+        }
+    }
+
+    #[tokio::test]
+    async fn entry_older_than_max_age_is_fetched_unconditionally() -> Result<(), Box<dyn Error>> {
+        let mock_server = MockServer::start().await;
+        let resource_path = paths::SYSTEMS_1;
+        let etag_value = "reused-etag";
+        let test_resource =
+            create_test_resource(resource_path, Some(etag_value), names::TEST_SYSTEM, 1);
+
+        Mock::given(method("GET"))
+            .and(path(resource_path))
+            .and(header("if-none-match", etag_value))
+            .respond_with(ResponseTemplate::new(304))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+        mount_cached_get_mock(&mock_server, resource_path, &test_resource, etag_value).await;
+
+        let clock = ManualClock::new();
+        let bmc = HttpBmc::new(
+            Client::new()?,
+            Url::parse(&mock_server.uri())?,
+            create_test_credentials(),
+            CacheSettings::default().with_max_age(Duration::from_secs(60)),
+        )
+        .with_clock(clock.clone());
+        let resource_id = create_odata_id(resource_path);
+
+        bmc.get::<TestResource>(&resource_id).await?;
+
+        // Fresh entry is revalidated with its ETag.
+        clock.advance(Duration::from_secs(30));
+        bmc.get::<TestResource>(&resource_id).await?;
+
+        // 304 does not extend the age of the entry.
+        clock.advance(Duration::from_secs(31));
+        bmc.get::<TestResource>(&resource_id).await?;
+
+        mock_server.verify().await;
+        Ok(())
+    }
+
     #[tokio::test]
     async fn test_initial_request_caches_resource() {
         let mock_server = MockServer::start().await;
@@ -424,6 +663,107 @@ mod cache_integration_tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn entries_above_max_size_are_not_cached() -> Result<(), Box<dyn Error>> {
+        let mock_server = MockServer::start().await;
+        let resource_path = paths::SYSTEMS_1;
+        let etag_value = "large-entry-etag";
+        let test_resource =
+            create_test_resource(resource_path, Some(etag_value), names::TEST_SYSTEM, 42);
+
+        Mock::given(method("GET"))
+            .and(path(resource_path))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(&test_resource)
+                    .insert_header("etag", etag_value),
+            )
+            .expect(2)
+            .mount(&mock_server)
+            .await;
+
+        let bmc = HttpBmc::new(
+            Client::new()?,
+            Url::parse(&mock_server.uri())?,
+            create_test_credentials(),
+            CacheSettings::default().with_max_entry_size_bytes(size_of::<TestResource>() - 1),
+        );
+
+        let resource_id = create_odata_id(resource_path);
+        let first_result = bmc.get::<TestResource>(&resource_id).await?;
+        let second_result = bmc.get::<TestResource>(&resource_id).await?;
+
+        mock_server.verify().await;
+
+        let Some(received_requests) = mock_server.received_requests().await else {
+            panic!("request recording should be enabled");
+        };
+        assert!(!Arc::ptr_eq(&first_result, &second_result));
+        assert!(received_requests
+            .iter()
+            .all(|request| !request.headers.contains_key("if-none-match")));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn cache_stats_count_hits_and_misses() -> Result<(), Box<dyn Error>> {
+        let mock_server = MockServer::start().await;
+        let etag_value = "stats-etag";
+        let test_resource =
+            create_test_resource(paths::SYSTEMS_1, Some(etag_value), names::TEST_SYSTEM, 1);
+
+        Mock::given(method("GET"))
+            .and(path(paths::SYSTEMS_1))
+            .and(header("if-none-match", etag_value))
+            .respond_with(ResponseTemplate::new(304))
+            .expect(2)
+            .mount(&mock_server)
+            .await;
+
+        Mock::given(method("GET"))
+            .and(path(paths::SYSTEMS_1))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(&test_resource)
+                    .insert_header("etag", etag_value),
+            )
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        Mock::given(method("GET"))
+            .and(path(paths::NONEXISTENT))
+            .respond_with(ResponseTemplate::new(304))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let bmc = create_test_bmc(&mock_server);
+        assert_eq!(bmc.cache_stats()?, CacheStats::default());
+
+        let resource_id = create_odata_id(paths::SYSTEMS_1);
+        bmc.get::<TestResource>(&resource_id).await?;
+        bmc.get::<TestResource>(&resource_id).await?;
+        bmc.get::<TestResource>(&resource_id).await?;
+        assert!(bmc
+            .get::<TestResource>(&create_odata_id(paths::NONEXISTENT))
+            .await
+            .is_err());
+
+        assert_eq!(
+            bmc.cache_stats()?,
+            CacheStats {
+                hits: 2,
+                misses: 1,
+                evictions: 0,
+            }
+        );
+
+        mock_server.verify().await;
+        Ok(())
+    }
+
     #[tokio::test]
     async fn test_etag_cache_from_header() {
         let mock_server = MockServer::start().await;
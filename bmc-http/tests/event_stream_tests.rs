@@ -94,6 +94,42 @@ mod tests {
         assert!(stream.next().await.is_none());
     }
 
+    #[tokio::test]
+    async fn test_event_stream_resume_sends_last_event_id() {
+        let mock_server = MockServer::start().await;
+        let sse_body = concat!(
+            "event: Alert\n",
+            "data: {\"event_id\":\"43\",\"severity\":\"OK\"}\n\n",
+        );
+
+        Mock::given(method("GET"))
+            .and(path(SSE_URI))
+            .and(header("accept", "text/event-stream"))
+            .and(header("last-event-id", "42"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .insert_header("content-type", "text/event-stream")
+                    .set_body_string(sse_body),
+            )
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let bmc = create_test_bmc(&mock_server);
+        let mut stream = bmc
+            .stream_resume::<StreamPayload>(SSE_URI, Some("42"))
+            .await
+            .expect("must open stream");
+
+        let first = stream
+            .next()
+            .await
+            .expect("first event expected")
+            .expect("first event parse");
+        assert_eq!(first.event_id, "43");
+        assert!(stream.next().await.is_none());
+    }
+
     #[tokio::test]
     async fn test_event_stream_json_decodes_payload() {
         let mock_server = MockServer::start().await;
@@ -0,0 +1,168 @@
+// SPDX-FileCopyrightText: Copyright (c) 2026 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod common;
+
+#[cfg(all(feature = "reqwest", feature = "retry"))]
+mod retry_client_tests {
+    use std::error::Error;
+    use std::time::Duration;
+
+    use nv_redfish_bmc_http::reqwest::BmcError;
+    use nv_redfish_bmc_http::reqwest::Client;
+    use nv_redfish_bmc_http::retry::RetryClient;
+    use nv_redfish_bmc_http::retry::RetryClientConfig;
+    use nv_redfish_bmc_http::CacheSettings;
+    use nv_redfish_bmc_http::HttpBmc;
+    use nv_redfish_core::Bmc;
+    use serde_json::json;
+    use url::Url;
+    use wiremock::{
+        matchers::{header, method, path},
+        Mock, MockServer, ResponseTemplate,
+    };
+
+    use crate::common::test_utils::*;
+
+    fn create_retry_bmc(
+        mock_server: &MockServer,
+        max_retries: u32,
+    ) -> Result<HttpBmc<RetryClient<Client>>, Box<dyn Error>> {
+        let client = RetryClient::new(
+            Client::new()?,
+            RetryClientConfig {
+                max_retries,
+                initial_delay: Duration::from_millis(1),
+                ..RetryClientConfig::default()
+            },
+        );
+        Ok(HttpBmc::new(
+            client,
+            Url::parse(&mock_server.uri())?,
+            create_test_credentials(),
+            CacheSettings::default(),
+        ))
+    }
+
+    #[tokio::test]
+    async fn get_fails_twice_then_succeeds() -> Result<(), Box<dyn Error>> {
+        let mock_server = MockServer::start().await;
+        let resource = create_test_resource(paths::SYSTEMS_1, None, names::TEST_SYSTEM, 1);
+
+        Mock::given(method("GET"))
+            .and(path(paths::SYSTEMS_1))
+            .respond_with(ResponseTemplate::new(503))
+            .up_to_n_times(2)
+            .expect(2)
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path(paths::SYSTEMS_1))
+            .respond_with(ResponseTemplate::new(200).set_body_json(&resource))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let bmc = create_retry_bmc(&mock_server, 3)?;
+        let result = bmc
+            .get::<TestResource>(&create_odata_id(paths::SYSTEMS_1))
+            .await?;
+        assert_eq!(*result, resource);
+
+        mock_server.verify().await;
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn get_gives_up_after_max_retries() -> Result<(), Box<dyn Error>> {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path(paths::SYSTEMS_1))
+            .respond_with(ResponseTemplate::new(503))
+            .expect(3)
+            .mount(&mock_server)
+            .await;
+
+        let bmc = create_retry_bmc(&mock_server, 2)?;
+        let result = bmc
+            .get::<TestResource>(&create_odata_id(paths::SYSTEMS_1))
+            .await;
+        assert!(matches!(
+            result,
+            Err(BmcError::InvalidResponse { status, .. })
+                if status == reqwest::StatusCode::SERVICE_UNAVAILABLE
+        ));
+
+        mock_server.verify().await;
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn not_modified_is_not_retried() -> Result<(), Box<dyn Error>> {
+        let mock_server = MockServer::start().await;
+        let resource =
+            create_test_resource(paths::SYSTEMS_1, Some("etag-1"), names::TEST_SYSTEM, 1);
+
+        Mock::given(method("GET"))
+            .and(path(paths::SYSTEMS_1))
+            .and(header("if-none-match", "etag-1"))
+            .respond_with(ResponseTemplate::new(304))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path(paths::SYSTEMS_1))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .insert_header("etag", "etag-1")
+                    .set_body_json(&resource),
+            )
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let bmc = create_retry_bmc(&mock_server, 3)?;
+        let id = create_odata_id(paths::SYSTEMS_1);
+        let first = bmc.get::<TestResource>(&id).await?;
+        let second = bmc.get::<TestResource>(&id).await?;
+        assert_eq!(*first, resource);
+        assert_eq!(*second, resource);
+
+        mock_server.verify().await;
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn client_errors_are_not_retried() -> Result<(), Box<dyn Error>> {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path(paths::SYSTEMS_1))
+            .respond_with(ResponseTemplate::new(404).set_body_json(json!({})))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let bmc = create_retry_bmc(&mock_server, 3)?;
+        let result = bmc
+            .get::<TestResource>(&create_odata_id(paths::SYSTEMS_1))
+            .await;
+        assert!(result.is_err());
+
+        mock_server.verify().await;
+        Ok(())
+    }
+}
@@ -0,0 +1,367 @@
+// SPDX-FileCopyrightText: Copyright (c) 2026 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod common;
+
+#[cfg(feature = "reqwest")]
+mod session_auth_tests {
+    use std::error::Error;
+
+    use nv_redfish_bmc_http::reqwest::Client;
+    use nv_redfish_bmc_http::BmcCredentials;
+    use nv_redfish_bmc_http::CacheSettings;
+    use nv_redfish_bmc_http::HttpBmc;
+    use nv_redfish_core::Bmc;
+    use serde_json::json;
+    use url::Url;
+    use wiremock::{
+        matchers::{body_json, header, method, path},
+        Mock, MockServer, ResponseTemplate,
+    };
+
+    use crate::common::test_utils::*;
+
+    const SESSIONS_PATH: &str = "/redfish/v1/SessionService/Sessions";
+    const SESSION_PATH: &str = "/redfish/v1/SessionService/Sessions/1";
+
+    fn create_session_bmc(mock_server: &MockServer) -> Result<HttpBmc<Client>, Box<dyn Error>> {
+        Ok(HttpBmc::new(
+            Client::new()?,
+            Url::parse(&mock_server.uri())?,
+            create_test_credentials(),
+            CacheSettings::default(),
+        )
+        .with_session_auth())
+    }
+
+    async fn mount_login(mock_server: &MockServer, token: &str, times: u64) {
+        Mock::given(method("POST"))
+            .and(path(SESSIONS_PATH))
+            .and(body_json(json!({
+                "UserName": "root",
+                "Password": "password"
+            })))
+            .respond_with(
+                ResponseTemplate::new(201)
+                    .insert_header("X-Auth-Token", token)
+                    .insert_header("Location", SESSION_PATH)
+                    .set_body_json(json!({
+                        "@odata.id": SESSION_PATH,
+                        "Id": "1",
+                        "Name": "User Session",
+                        "UserName": "root"
+                    })),
+            )
+            .up_to_n_times(times)
+            .expect(times)
+            .mount(mock_server)
+            .await;
+    }
+
+    #[tokio::test]
+    async fn login_is_lazy_and_token_is_reused() -> Result<(), Box<dyn Error>> {
+        let mock_server = MockServer::start().await;
+        let resource = create_test_resource(paths::SYSTEMS_1, None, names::TEST_SYSTEM, 1);
+
+        mount_login(&mock_server, "session-token", 1).await;
+
+        Mock::given(method("GET"))
+            .and(path(paths::SYSTEMS_1))
+            .and(header("X-Auth-Token", "session-token"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(&resource))
+            .expect(2)
+            .mount(&mock_server)
+            .await;
+
+        let bmc = create_session_bmc(&mock_server)?;
+        assert!(mock_server
+            .received_requests()
+            .await
+            .unwrap_or_default()
+            .is_empty());
+
+        let id = create_odata_id(paths::SYSTEMS_1);
+        let first = bmc.get::<TestResource>(&id).await?;
+        let second = bmc.get::<TestResource>(&id).await?;
+        assert_eq!(*first, resource);
+        assert_eq!(*second, resource);
+
+        let requests = mock_server.received_requests().await.unwrap_or_default();
+        assert!(requests
+            .iter()
+            .all(|request| !request.headers.contains_key("authorization")));
+
+        mock_server.verify().await;
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn expired_token_is_renewed_once() -> Result<(), Box<dyn Error>> {
+        let mock_server = MockServer::start().await;
+        let resource = create_test_resource(paths::SYSTEMS_1, None, names::TEST_SYSTEM, 1);
+
+        mount_login(&mock_server, "expired-token", 1).await;
+        mount_login(&mock_server, "fresh-token", 1).await;
+
+        Mock::given(method("GET"))
+            .and(path(paths::SYSTEMS_1))
+            .and(header("X-Auth-Token", "expired-token"))
+            .respond_with(ResponseTemplate::new(401))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        Mock::given(method("GET"))
+            .and(path(paths::SYSTEMS_1))
+            .and(header("X-Auth-Token", "fresh-token"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(&resource))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let bmc = create_session_bmc(&mock_server)?;
+        let result = bmc
+            .get::<TestResource>(&create_odata_id(paths::SYSTEMS_1))
+            .await?;
+        assert_eq!(*result, resource);
+
+        mock_server.verify().await;
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn token_credentials_are_not_used_for_login() -> Result<(), Box<dyn Error>> {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path(SESSIONS_PATH))
+            .respond_with(ResponseTemplate::new(201))
+            .expect(0)
+            .mount(&mock_server)
+            .await;
+
+        Mock::given(method("GET"))
+            .and(path(paths::SYSTEMS_1))
+            .and(header("X-Auth-Token", "user-token"))
+            .respond_with(ResponseTemplate::new(401))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let bmc = HttpBmc::new(
+            Client::new()?,
+            Url::parse(&mock_server.uri())?,
+            BmcCredentials::token("user-token".into()),
+            CacheSettings::default(),
+        )
+        .with_session_auth();
+        let result = bmc
+            .get::<TestResource>(&create_odata_id(paths::SYSTEMS_1))
+            .await;
+        assert!(result.is_err());
+
+        mock_server.verify().await;
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn set_credentials_drops_active_session() -> Result<(), Box<dyn Error>> {
+        let mock_server = MockServer::start().await;
+        let resource = create_test_resource(paths::SYSTEMS_1, None, names::TEST_SYSTEM, 1);
+
+        mount_login(&mock_server, "session-token", 1).await;
+
+        Mock::given(method("POST"))
+            .and(path(SESSIONS_PATH))
+            .and(body_json(json!({
+                "UserName": "admin",
+                "Password": "rotated"
+            })))
+            .respond_with(
+                ResponseTemplate::new(201)
+                    .insert_header("X-Auth-Token", "rotated-token")
+                    .insert_header("Location", "/redfish/v1/SessionService/Sessions/2")
+                    .set_body_json(json!({
+                        "@odata.id": "/redfish/v1/SessionService/Sessions/2",
+                        "Id": "2",
+                        "Name": "User Session",
+                        "UserName": "admin"
+                    })),
+            )
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        Mock::given(method("GET"))
+            .and(path(paths::SYSTEMS_1))
+            .and(header("X-Auth-Token", "session-token"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(&resource))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        Mock::given(method("GET"))
+            .and(path(paths::SYSTEMS_1))
+            .and(header("X-Auth-Token", "rotated-token"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(&resource))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let bmc = create_session_bmc(&mock_server)?;
+        let id = create_odata_id(paths::SYSTEMS_1);
+        bmc.get::<TestResource>(&id).await?;
+        bmc.clear_cache()?;
+
+        bmc.set_credentials(BmcCredentials::username_password(
+            "admin".into(),
+            Some("rotated".into()),
+        ));
+        bmc.get::<TestResource>(&id).await?;
+
+        mock_server.verify().await;
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn prefixed_mount_logout_deletes_session_once_prefixed() -> Result<(), Box<dyn Error>> {
+        let mock_server = MockServer::start().await;
+        let session_path = format!("/api{SESSION_PATH}");
+        let resource = create_test_resource(paths::SYSTEMS_1, None, names::TEST_SYSTEM, 1);
+
+        Mock::given(method("POST"))
+            .and(path(format!("/api{SESSIONS_PATH}")))
+            .respond_with(
+                ResponseTemplate::new(201)
+                    .insert_header("X-Auth-Token", "session-token")
+                    .insert_header("Location", session_path.as_str())
+                    .set_body_json(json!({
+                        "@odata.id": &session_path,
+                        "Id": "1",
+                        "Name": "User Session",
+                        "UserName": "root"
+                    })),
+            )
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        Mock::given(method("GET"))
+            .and(path(format!("/api{}", paths::SYSTEMS_1)))
+            .and(header("X-Auth-Token", "session-token"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(&resource))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        Mock::given(method("DELETE"))
+            .and(path(session_path.as_str()))
+            .and(header("X-Auth-Token", "session-token"))
+            .respond_with(ResponseTemplate::new(204))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let bmc = HttpBmc::with_base_path_prefix(
+            Client::new()?,
+            Url::parse(&mock_server.uri())?,
+            create_test_credentials(),
+            CacheSettings::default(),
+            "/api",
+        )
+        .with_session_auth();
+        bmc.get::<TestResource>(&create_odata_id(paths::SYSTEMS_1))
+            .await?;
+        bmc.logout().await?;
+
+        mock_server.verify().await;
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn logout_deletes_session() -> Result<(), Box<dyn Error>> {
+        let mock_server = MockServer::start().await;
+        let resource = create_test_resource(paths::SYSTEMS_1, None, names::TEST_SYSTEM, 1);
+
+        mount_login(&mock_server, "session-token", 1).await;
+
+        Mock::given(method("GET"))
+            .and(path(paths::SYSTEMS_1))
+            .and(header("X-Auth-Token", "session-token"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(&resource))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        Mock::given(method("DELETE"))
+            .and(path(SESSION_PATH))
+            .and(header("X-Auth-Token", "session-token"))
+            .respond_with(ResponseTemplate::new(204))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let bmc = create_session_bmc(&mock_server)?;
+        bmc.get::<TestResource>(&create_odata_id(paths::SYSTEMS_1))
+            .await?;
+        bmc.logout().await?;
+        // Nothing to delete anymore.
+        bmc.logout().await?;
+
+        mock_server.verify().await;
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn drop_deletes_session_when_logout_on_drop() -> Result<(), Box<dyn Error>> {
+        let mock_server = MockServer::start().await;
+        let resource = create_test_resource(paths::SYSTEMS_1, None, names::TEST_SYSTEM, 1);
+
+        mount_login(&mock_server, "session-token", 1).await;
+
+        Mock::given(method("GET"))
+            .and(path(paths::SYSTEMS_1))
+            .and(header("X-Auth-Token", "session-token"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(&resource))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        Mock::given(method("DELETE"))
+            .and(path(SESSION_PATH))
+            .and(header("X-Auth-Token", "session-token"))
+            .respond_with(ResponseTemplate::new(204))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let bmc = create_session_bmc(&mock_server)?.with_session_logout_on_drop();
+        bmc.get::<TestResource>(&create_odata_id(paths::SYSTEMS_1))
+            .await?;
+        drop(bmc);
+
+        // Logout is spawned on the runtime; wait for it to reach the server.
+        for _ in 0..100 {
+            let requests = mock_server.received_requests().await.unwrap_or_default();
+            if requests.iter().any(|r| r.method.as_str() == "DELETE") {
+                break;
+            }
+            tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+        }
+
+        mock_server.verify().await;
+        Ok(())
+    }
+}
@@ -15,51 +15,109 @@
 
 //! Expectations for Bmc Mock.
 
+use std::fmt::Debug;
 use std::fmt::Display;
+use std::fmt::Formatter;
+use std::fmt::Result as FmtResult;
 
 use nv_redfish_core::action::ActionTarget;
 use nv_redfish_core::AsyncTask;
+use nv_redfish_core::FilterQuery;
 use nv_redfish_core::ODataId;
+use nv_redfish_core::PageQuery;
+use nv_redfish_core::SelectQuery;
 
 use serde_json::from_str;
 use serde_json::Value as JsonValue;
 
 pub type Response<E> = Result<JsonValue, E>;
 
+/// Predicate on JSON body of a request.
+pub type BodyPredicate = Box<dyn Fn(&JsonValue) -> bool + Send + Sync>;
+
+/// Body of request expected by BMC.
+pub enum ExpectedBody {
+    /// Body is equal to the value.
+    Exact(JsonValue),
+    /// Body is accepted by the predicate.
+    Predicate(BodyPredicate),
+}
+
+impl ExpectedBody {
+    /// Check whether request `body` is expected.
+    pub fn matches(&self, body: &JsonValue) -> bool {
+        match self {
+            Self::Exact(v) => v == body,
+            Self::Predicate(f) => f(body),
+        }
+    }
+
+    fn exact(request: &impl Display) -> Self {
+        Self::Exact(from_str(&request.to_string()).expect("invalid json"))
+    }
+}
+
+impl Debug for ExpectedBody {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Self::Exact(v) => write!(f, "{v}"),
+            Self::Predicate(_) => f.write_str("<predicate>"),
+        }
+    }
+}
+
 /// Request expected by BMC.
 #[derive(Debug)]
 pub enum ExpectedRequest {
     /// Expected Get.
     Get { id: ODataId },
 
+    /// Expected Get of the entity that does not exist.
+    GetNotFound { id: ODataId },
+
+    /// Expected Head of the entity that exists.
+    Head { id: ODataId },
+
+    /// Expected Head of the entity that does not exist.
+    HeadNotFound { id: ODataId },
+
     /// Expected Expand.
     Expand { id: ODataId },
 
+    /// Expected Select with serialized `$select` query.
+    Select { id: ODataId, query: String },
+
+    /// Expected Page with serialized `$top`/`$skip` query.
+    Page { id: ODataId, query: String },
+
+    /// Expected Filter with serialized `$filter` query.
+    Filter { id: ODataId, query: String },
+
     /// Expected Update.
-    Update { id: ODataId, request: JsonValue },
+    Update { id: ODataId, request: ExpectedBody },
 
     /// Expected asynchronous update.
     UpdateTask {
         id: ODataId,
-        request: JsonValue,
+        request: ExpectedBody,
         task: AsyncTask,
     },
 
     /// Expected update with no response body.
-    UpdateEmpty { id: ODataId, request: JsonValue },
+    UpdateEmpty { id: ODataId, request: ExpectedBody },
 
     /// Expected Create.
-    Create { id: ODataId, request: JsonValue },
+    Create { id: ODataId, request: ExpectedBody },
 
     /// Expected asynchronous create.
     CreateTask {
         id: ODataId,
-        request: JsonValue,
+        request: ExpectedBody,
         task: AsyncTask,
     },
 
     /// Expected create with no response body.
-    CreateEmpty { id: ODataId, request: JsonValue },
+    CreateEmpty { id: ODataId, request: ExpectedBody },
 
     /// Expected Redfish session creation.
     CreateSession {
@@ -72,7 +130,14 @@ pub enum ExpectedRequest {
     /// Expected ActionTarget
     Action {
         target: ActionTarget,
-        request: JsonValue,
+        request: ExpectedBody,
+    },
+
+    /// Expected asynchronous action.
+    ActionTask {
+        target: ActionTarget,
+        request: ExpectedBody,
+        task: AsyncTask,
     },
 
     /// Expected multipart update.
@@ -95,6 +160,12 @@ pub enum ExpectedRequest {
 
     /// Expected Stream.
     Stream { uri: String },
+
+    /// Expected resumed Stream.
+    StreamResume {
+        uri: String,
+        last_event_id: Option<String>,
+    },
 }
 
 /// Expectation for the tests.
@@ -113,6 +184,30 @@ impl<E> Expect<E> {
             response: Ok(from_str(&response.to_string()).expect("invalid json")),
         }
     }
+    pub fn get_not_found(uri: impl Display) -> Self {
+        Expect {
+            request: ExpectedRequest::GetNotFound {
+                id: uri.to_string().into(),
+            },
+            response: Ok(JsonValue::Null),
+        }
+    }
+    pub fn head(uri: impl Display) -> Self {
+        Expect {
+            request: ExpectedRequest::Head {
+                id: uri.to_string().into(),
+            },
+            response: Ok(JsonValue::Null),
+        }
+    }
+    pub fn head_not_found(uri: impl Display) -> Self {
+        Expect {
+            request: ExpectedRequest::HeadNotFound {
+                id: uri.to_string().into(),
+            },
+            response: Ok(JsonValue::Null),
+        }
+    }
     pub fn expand(uri: impl Display, response: impl Display) -> Self {
         Expect {
             request: ExpectedRequest::Expand {
@@ -121,11 +216,53 @@ impl<E> Expect<E> {
             response: Ok(from_str(&response.to_string()).expect("invalid json")),
         }
     }
+    pub fn select(uri: impl Display, query: &SelectQuery, response: impl Display) -> Self {
+        Expect {
+            request: ExpectedRequest::Select {
+                id: uri.to_string().into(),
+                query: query.to_query_string(),
+            },
+            response: Ok(from_str(&response.to_string()).expect("invalid json")),
+        }
+    }
+    pub fn page(uri: impl Display, query: &PageQuery, response: impl Display) -> Self {
+        Expect {
+            request: ExpectedRequest::Page {
+                id: uri.to_string().into(),
+                query: query.to_query_string(),
+            },
+            response: Ok(from_str(&response.to_string()).expect("invalid json")),
+        }
+    }
+    pub fn filter(uri: impl Display, query: &FilterQuery, response: impl Display) -> Self {
+        Expect {
+            request: ExpectedRequest::Filter {
+                id: uri.to_string().into(),
+                query: query.to_query_string(),
+            },
+            response: Ok(from_str(&response.to_string()).expect("invalid json")),
+        }
+    }
     pub fn update(uri: impl Display, request: impl Display, response: impl Display) -> Self {
         Expect {
             request: ExpectedRequest::Update {
                 id: uri.to_string().into(),
-                request: from_str(&request.to_string()).expect("invalid json"),
+                request: ExpectedBody::exact(&request),
+            },
+            response: Ok(from_str(&response.to_string()).expect("invalid json")),
+        }
+    }
+
+    /// Expect update with body accepted by `predicate`.
+    pub fn update_matching(
+        uri: impl Display,
+        predicate: impl Fn(&JsonValue) -> bool + Send + Sync + 'static,
+        response: impl Display,
+    ) -> Self {
+        Expect {
+            request: ExpectedRequest::Update {
+                id: uri.to_string().into(),
+                request: ExpectedBody::Predicate(Box::new(predicate)),
             },
             response: Ok(from_str(&response.to_string()).expect("invalid json")),
         }
@@ -135,7 +272,7 @@ impl<E> Expect<E> {
         Expect {
             request: ExpectedRequest::UpdateTask {
                 id: uri.to_string().into(),
-                request: from_str(&request.to_string()).expect("invalid json"),
+                request: ExpectedBody::exact(&request),
                 task,
             },
             response: Ok(JsonValue::Null),
@@ -146,7 +283,7 @@ impl<E> Expect<E> {
         Expect {
             request: ExpectedRequest::UpdateEmpty {
                 id: uri.to_string().into(),
-                request: from_str(&request.to_string()).expect("invalid json"),
+                request: ExpectedBody::exact(&request),
             },
             response: Ok(JsonValue::Null),
         }
@@ -156,7 +293,22 @@ impl<E> Expect<E> {
         Expect {
             request: ExpectedRequest::Create {
                 id: uri.to_string().into(),
-                request: from_str(&request.to_string()).expect("invalid json"),
+                request: ExpectedBody::exact(&request),
+            },
+            response: Ok(from_str(&response.to_string()).expect("invalid json")),
+        }
+    }
+
+    /// Expect create with body accepted by `predicate`.
+    pub fn create_matching(
+        uri: impl Display,
+        predicate: impl Fn(&JsonValue) -> bool + Send + Sync + 'static,
+        response: impl Display,
+    ) -> Self {
+        Expect {
+            request: ExpectedRequest::Create {
+                id: uri.to_string().into(),
+                request: ExpectedBody::Predicate(Box::new(predicate)),
             },
             response: Ok(from_str(&response.to_string()).expect("invalid json")),
         }
@@ -166,7 +318,7 @@ impl<E> Expect<E> {
         Expect {
             request: ExpectedRequest::CreateTask {
                 id: uri.to_string().into(),
-                request: from_str(&request.to_string()).expect("invalid json"),
+                request: ExpectedBody::exact(&request),
                 task,
             },
             response: Ok(JsonValue::Null),
@@ -177,7 +329,7 @@ impl<E> Expect<E> {
         Expect {
             request: ExpectedRequest::CreateEmpty {
                 id: uri.to_string().into(),
-                request: from_str(&request.to_string()).expect("invalid json"),
+                request: ExpectedBody::exact(&request),
             },
             response: Ok(JsonValue::Null),
         }
@@ -204,7 +356,33 @@ impl<E> Expect<E> {
         Expect {
             request: ExpectedRequest::Action {
                 target: ActionTarget::new(uri.to_string()),
-                request: from_str(&request.to_string()).expect("invalid json"),
+                request: ExpectedBody::exact(&request),
+            },
+            response: Ok(from_str(&response.to_string()).expect("invalid json")),
+        }
+    }
+
+    pub fn action_task(uri: impl Display, request: impl Display, task: AsyncTask) -> Self {
+        Expect {
+            request: ExpectedRequest::ActionTask {
+                target: ActionTarget::new(uri.to_string()),
+                request: ExpectedBody::exact(&request),
+                task,
+            },
+            response: Ok(JsonValue::Null),
+        }
+    }
+
+    /// Expect action with parameters accepted by `predicate`.
+    pub fn action_matching(
+        uri: impl Display,
+        predicate: impl Fn(&JsonValue) -> bool + Send + Sync + 'static,
+        response: impl Display,
+    ) -> Self {
+        Expect {
+            request: ExpectedRequest::Action {
+                target: ActionTarget::new(uri.to_string()),
+                request: ExpectedBody::Predicate(Box::new(predicate)),
             },
             response: Ok(from_str(&response.to_string()).expect("invalid json")),
         }
@@ -286,4 +464,18 @@ impl<E> Expect<E> {
             response: Ok(from_str(&response.to_string()).expect("invalid json")),
         }
     }
+
+    pub fn stream_resume(
+        uri: impl Display,
+        last_event_id: Option<&str>,
+        response: impl Display,
+    ) -> Self {
+        Expect {
+            request: ExpectedRequest::StreamResume {
+                uri: uri.to_string(),
+                last_event_id: last_event_id.map(ToString::to_string),
+            },
+            response: Ok(from_str(&response.to_string()).expect("invalid json")),
+        }
+    }
 }
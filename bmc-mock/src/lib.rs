@@ -17,6 +17,7 @@ pub mod expect;
 
 #[doc(inline)]
 pub use expect::Expect;
+pub use expect::ExpectedBody;
 pub use expect::ExpectedRequest;
 
 use std::collections::VecDeque;
@@ -24,9 +25,15 @@ use std::error::Error as StdError;
 use std::fmt::Display;
 use std::fmt::Formatter;
 use std::fmt::Result as FmtResult;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
 use std::sync::Arc;
 use std::sync::Mutex;
 use std::sync::PoisonError;
+use std::task::Context;
+use std::task::Poll;
 
 use nv_redfish_core::action::ActionTarget;
 use nv_redfish_core::query::ExpandQuery;
@@ -34,12 +41,16 @@ use nv_redfish_core::ActionError;
 use nv_redfish_core::Bmc as NvRedfishBmc;
 use nv_redfish_core::EntityTypeRef;
 use nv_redfish_core::Expandable;
+use nv_redfish_core::FilterQuery;
 #[cfg(feature = "update-service-deprecated")]
 use nv_redfish_core::HttpPushUriUpdateRequest;
 use nv_redfish_core::ModificationResponse;
 use nv_redfish_core::MultipartUpdateRequest;
+use nv_redfish_core::NotFoundError;
 use nv_redfish_core::ODataETag;
 use nv_redfish_core::ODataId;
+use nv_redfish_core::PageQuery;
+use nv_redfish_core::SelectQuery;
 use nv_redfish_core::SessionCreateResponse;
 use nv_redfish_core::UploadReader;
 use serde::Serialize;
@@ -53,9 +64,15 @@ pub enum Error {
     ErrorResponse(Box<dyn StdError + Send + Sync>),
     MutexLock(String),
     NothingIsExpected,
+    NotFound(ODataId),
+    NotConsumed(Vec<ExpectedRequest>),
     BadResponseJson(JsonError),
     UnexpectedGet(ODataId, ExpectedRequest),
+    UnexpectedHead(ODataId, ExpectedRequest),
     UnexpectedExpand(ODataId, ExpectedRequest),
+    UnexpectedSelect(ODataId, String, ExpectedRequest),
+    UnexpectedPage(ODataId, String, ExpectedRequest),
+    UnexpectedFilter(ODataId, String, ExpectedRequest),
     UnexpectedUpdate(ODataId, String, ExpectedRequest),
     UnexpectedCreate(ODataId, String, ExpectedRequest),
     UnexpectedCreateSession(ODataId, String, ExpectedRequest),
@@ -73,16 +90,41 @@ impl Display for Error {
             Self::ErrorResponse(err) => write!(f, "response: {err}"),
             Self::NotSupported => write!(f, "not supported"),
             Self::MutexLock(err) => write!(f, "lock error: {err}"),
+            Self::NotFound(id) => write!(f, "not found: {id}"),
             Self::NothingIsExpected => {
                 write!(f, "nothing is expected to happen but something happened")
             }
+            Self::NotConsumed(requests) => {
+                write!(f, "expected requests were not consumed: {requests:?}")
+            }
             Self::BadResponseJson(err) => write!(f, "bad json response: {err}"),
             Self::UnexpectedGet(id, expected) => {
                 write!(f, "unexpected get: {id}; expected: {expected:?}")
             }
+            Self::UnexpectedHead(id, expected) => {
+                write!(f, "unexpected head: {id}; expected: {expected:?}")
+            }
             Self::UnexpectedExpand(id, expected) => {
                 write!(f, "unexpected expand: {id}; expected: {expected:?}")
             }
+            Self::UnexpectedSelect(id, query, expected) => {
+                write!(
+                    f,
+                    "unexpected select: {id}; query: {query} expected: {expected:?}"
+                )
+            }
+            Self::UnexpectedPage(id, query, expected) => {
+                write!(
+                    f,
+                    "unexpected page: {id}; query: {query} expected: {expected:?}"
+                )
+            }
+            Self::UnexpectedFilter(id, query, expected) => {
+                write!(
+                    f,
+                    "unexpected filter: {id}; query: {query} expected: {expected:?}"
+                )
+            }
             Self::UnexpectedUpdate(id, json, expected) => {
                 write!(
                     f,
@@ -132,6 +174,12 @@ impl Display for Error {
 
 impl StdError for Error {}
 
+impl NotFoundError for Error {
+    fn is_not_found(&self) -> bool {
+        matches!(self, Self::NotFound(_))
+    }
+}
+
 impl Error {
     pub fn mutex_lock<T>(err: PoisonError<T>) -> Self {
         Self::MutexLock(err.to_string())
@@ -140,12 +188,51 @@ impl Error {
 
 pub struct Bmc<E> {
     expect: Mutex<VecDeque<Expect<E>>>,
+    in_flight: AtomicUsize,
+    max_in_flight: AtomicUsize,
 }
 
 impl<E> Default for Bmc<E> {
     fn default() -> Self {
         Self {
             expect: Mutex::default(),
+            in_flight: AtomicUsize::default(),
+            max_in_flight: AtomicUsize::default(),
+        }
+    }
+}
+
+/// Tracks number of get requests that are processed concurrently.
+struct InFlight<'a>(&'a AtomicUsize);
+
+impl<'a> InFlight<'a> {
+    fn enter(in_flight: &'a AtomicUsize, max_in_flight: &AtomicUsize) -> Self {
+        let current = in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+        max_in_flight.fetch_max(current, Ordering::SeqCst);
+        Self(in_flight)
+    }
+}
+
+impl Drop for InFlight<'_> {
+    fn drop(&mut self) {
+        self.0.fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
+/// Gives other concurrent requests a chance to start.
+#[derive(Default)]
+struct YieldNow(bool);
+
+impl Future for YieldNow {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        if self.0 {
+            Poll::Ready(())
+        } else {
+            self.0 = true;
+            cx.waker().wake_by_ref();
+            Poll::Pending
         }
     }
 }
@@ -156,6 +243,31 @@ impl<E> Bmc<E> {
         expect.push_back(exp);
     }
 
+    pub fn expect_sequence(&self, exps: impl IntoIterator<Item = Expect<E>>) {
+        let expect: &mut VecDeque<Expect<E>> = &mut self.expect.lock().expect("not poisoned");
+        expect.extend(exps);
+    }
+
+    #[allow(clippy::result_large_err)] // same error as returned by Bmc methods
+    pub fn assert_all_consumed(&self) -> Result<(), Error> {
+        let mut expect = self.expect.lock().map_err(Error::mutex_lock)?;
+        if expect.is_empty() {
+            Ok(())
+        } else {
+            Err(Error::NotConsumed(
+                expect.drain(..).map(|exp| exp.request).collect(),
+            ))
+        }
+    }
+
+    pub fn expectations_remaining(&self) -> usize {
+        self.expect.lock().expect("not poisoned").len()
+    }
+
+    pub fn max_in_flight(&self) -> usize {
+        self.max_in_flight.load(Ordering::SeqCst)
+    }
+
     pub fn debug_expect(&self) {
         let expect: &VecDeque<Expect<E>> = &self.expect.lock().expect("not poisoned");
         println!("Expectations (total: {})", expect.len());
@@ -198,6 +310,8 @@ where
         &self,
         in_id: &ODataId,
     ) -> Result<Arc<T>, Self::Error> {
+        let _in_flight = InFlight::enter(&self.in_flight, &self.max_in_flight);
+        YieldNow::default().await;
         let expect = self
             .expect
             .lock()
@@ -213,10 +327,36 @@ where
                 let result: T = from_value(response).map_err(Error::BadResponseJson)?;
                 Ok(Arc::new(result))
             }
+            Expect {
+                request: ExpectedRequest::GetNotFound { id },
+                ..
+            } if id == *in_id => Err(Error::NotFound(id)),
             _ => Err(Error::UnexpectedGet(in_id.clone(), expect.request)),
         }
     }
 
+    async fn head(&self, in_id: &ODataId) -> Result<bool, Self::Error> {
+        let expect = self
+            .expect
+            .lock()
+            .map_err(Error::mutex_lock)?
+            .pop_front()
+            .ok_or(Error::NothingIsExpected)?;
+        match expect {
+            Expect {
+                request: ExpectedRequest::Head { id },
+                response,
+            } if id == *in_id => response
+                .map(|_| true)
+                .map_err(|err| Error::ErrorResponse(Box::new(err))),
+            Expect {
+                request: ExpectedRequest::HeadNotFound { id },
+                ..
+            } if id == *in_id => Ok(false),
+            _ => Err(Error::UnexpectedHead(in_id.clone(), expect.request)),
+        }
+    }
+
     async fn update<
         V: Sync + Send + Serialize,
         R: Sync + Send + Sized + for<'de> serde::Deserialize<'de>,
@@ -239,7 +379,7 @@ where
             Expect {
                 request: ExpectedRequest::Update { id, request },
                 response,
-            } if id == *in_id && request == in_request => {
+            } if id == *in_id && request.matches(&in_request) => {
                 let response = response.map_err(|err| Error::ErrorResponse(Box::new(err)))?;
                 let result: R = from_value(response).map_err(Error::BadResponseJson)?;
                 Ok(ModificationResponse::Entity(result))
@@ -247,11 +387,13 @@ where
             Expect {
                 request: ExpectedRequest::UpdateTask { id, request, task },
                 ..
-            } if id == *in_id && request == in_request => Ok(ModificationResponse::Task(task)),
+            } if id == *in_id && request.matches(&in_request) => {
+                Ok(ModificationResponse::Task(task))
+            }
             Expect {
                 request: ExpectedRequest::UpdateEmpty { id, request },
                 ..
-            } if id == *in_id && request == in_request => Ok(ModificationResponse::Empty),
+            } if id == *in_id && request.matches(&in_request) => Ok(ModificationResponse::Empty),
             _ => Err(Error::UnexpectedUpdate(
                 in_id.clone(),
                 in_request.to_string(),
@@ -281,7 +423,7 @@ where
             Expect {
                 request: ExpectedRequest::Create { id, request },
                 response,
-            } if id == *in_id && request == in_request => {
+            } if id == *in_id && request.matches(&in_request) => {
                 let response = response.map_err(|err| Error::ErrorResponse(Box::new(err)))?;
                 let result: R = from_value(response).map_err(Error::BadResponseJson)?;
                 Ok(ModificationResponse::Entity(result))
@@ -289,11 +431,13 @@ where
             Expect {
                 request: ExpectedRequest::CreateTask { id, request, task },
                 ..
-            } if id == *in_id && request == in_request => Ok(ModificationResponse::Task(task)),
+            } if id == *in_id && request.matches(&in_request) => {
+                Ok(ModificationResponse::Task(task))
+            }
             Expect {
                 request: ExpectedRequest::CreateEmpty { id, request },
                 ..
-            } if id == *in_id && request == in_request => Ok(ModificationResponse::Empty),
+            } if id == *in_id && request.matches(&in_request) => Ok(ModificationResponse::Empty),
             _ => Err(Error::UnexpectedCreate(
                 in_id.clone(),
                 in_request.to_string(),
@@ -386,11 +530,22 @@ where
             Expect {
                 request: ExpectedRequest::Action { target, request },
                 response,
-            } if target == action.target && request == in_request => {
+            } if target == action.target && request.matches(&in_request) => {
                 let response = response.map_err(|err| Error::ErrorResponse(Box::new(err)))?;
                 let result: R = from_value(response).map_err(Error::BadResponseJson)?;
                 Ok(ModificationResponse::Entity(result))
             }
+            Expect {
+                request:
+                    ExpectedRequest::ActionTask {
+                        target,
+                        request,
+                        task,
+                    },
+                ..
+            } if target == action.target && request.matches(&in_request) => {
+                Ok(ModificationResponse::Task(task))
+            }
             _ => Err(Error::UnexpectedAction(
                 action.target.clone(),
                 in_request.to_string(),
@@ -492,10 +647,89 @@ where
 
     async fn filter<T: EntityTypeRef + for<'de> serde::Deserialize<'de>>(
         &self,
-        _id: &ODataId,
-        _query: nv_redfish_core::FilterQuery,
+        in_id: &ODataId,
+        query: FilterQuery,
+    ) -> Result<Arc<T>, Self::Error> {
+        let expect = self
+            .expect
+            .lock()
+            .map_err(Error::mutex_lock)?
+            .pop_front()
+            .ok_or(Error::NothingIsExpected)?;
+        let in_query = query.to_query_string();
+        match expect {
+            Expect {
+                request: ExpectedRequest::Filter { id, query },
+                response,
+            } if id == *in_id && query == in_query => {
+                let response = response.map_err(|err| Error::ErrorResponse(Box::new(err)))?;
+                let result: T = from_value(response).map_err(Error::BadResponseJson)?;
+                Ok(Arc::new(result))
+            }
+            _ => Err(Error::UnexpectedFilter(
+                in_id.clone(),
+                in_query,
+                expect.request,
+            )),
+        }
+    }
+
+    async fn select<T: EntityTypeRef + for<'de> serde::Deserialize<'de>>(
+        &self,
+        in_id: &ODataId,
+        query: SelectQuery,
     ) -> Result<Arc<T>, Self::Error> {
-        todo!("unimplemented")
+        let expect = self
+            .expect
+            .lock()
+            .map_err(Error::mutex_lock)?
+            .pop_front()
+            .ok_or(Error::NothingIsExpected)?;
+        let in_query = query.to_query_string();
+        match expect {
+            Expect {
+                request: ExpectedRequest::Select { id, query },
+                response,
+            } if id == *in_id && query == in_query => {
+                let response = response.map_err(|err| Error::ErrorResponse(Box::new(err)))?;
+                let result: T = from_value(response).map_err(Error::BadResponseJson)?;
+                Ok(Arc::new(result))
+            }
+            _ => Err(Error::UnexpectedSelect(
+                in_id.clone(),
+                in_query,
+                expect.request,
+            )),
+        }
+    }
+
+    async fn page<T: EntityTypeRef + for<'de> serde::Deserialize<'de>>(
+        &self,
+        in_id: &ODataId,
+        query: PageQuery,
+    ) -> Result<Arc<T>, Self::Error> {
+        let expect = self
+            .expect
+            .lock()
+            .map_err(Error::mutex_lock)?
+            .pop_front()
+            .ok_or(Error::NothingIsExpected)?;
+        let in_query = query.to_query_string();
+        match expect {
+            Expect {
+                request: ExpectedRequest::Page { id, query },
+                response,
+            } if id == *in_id && query == in_query => {
+                let response = response.map_err(|err| Error::ErrorResponse(Box::new(err)))?;
+                let result: T = from_value(response).map_err(Error::BadResponseJson)?;
+                Ok(Arc::new(result))
+            }
+            _ => Err(Error::UnexpectedPage(
+                in_id.clone(),
+                in_query,
+                expect.request,
+            )),
+        }
     }
 
     async fn stream<T: Sized + for<'de> serde::Deserialize<'de> + Send + 'static>(
@@ -522,6 +756,32 @@ where
             _ => Err(Error::UnexpectedStream(in_uri.to_string(), expect.request)),
         }
     }
+
+    async fn stream_resume<T: Sized + for<'de> serde::Deserialize<'de> + Send + 'static>(
+        &self,
+        in_uri: &str,
+        in_last_event_id: Option<&str>,
+    ) -> Result<nv_redfish_core::BoxTryStream<T, Self::Error>, Self::Error> {
+        let expect = self
+            .expect
+            .lock()
+            .map_err(Error::mutex_lock)?
+            .pop_front()
+            .ok_or(Error::NothingIsExpected)?;
+        match expect {
+            Expect {
+                request: ExpectedRequest::StreamResume { uri, last_event_id },
+                response,
+            } if uri == *in_uri && last_event_id.as_deref() == in_last_event_id => {
+                let response = response.map_err(|err| Error::ErrorResponse(Box::new(err)))?;
+                let result: Vec<T> = from_value(response).map_err(Error::BadResponseJson)?;
+                Ok(Box::pin(futures_util::stream::iter(
+                    result.into_iter().map(Ok),
+                )))
+            }
+            _ => Err(Error::UnexpectedStream(in_uri.to_string(), expect.request)),
+        }
+    }
 }
 
 impl ActionError for Error {
@@ -22,9 +22,10 @@
 //! - `T`: request parameters payload type (sent as the POST body when running the action)
 //! - `R`: response type returned by the BMC for that action
 //!
-//! Only the `target` field is deserialized. Any additional metadata
-//! (such as `...@Redfish.AllowableValues`) is ignored by this type
-//! and may be used by higher layers.
+//! Besides the `target` field, the parameter constraints advertised with
+//! `<Parameter>@Redfish.AllowableValues` are collected and available via
+//! [`Action::allowable_values`]. Any other metadata is ignored by this
+//! type.
 //!
 //! Example: how an action appears in a Redfish resource and which part maps to [`Action`]
 //!
@@ -44,7 +45,8 @@
 //! ```
 //!
 //! The [`Action<T, R>`] value corresponds to the inner object of
-//! `"#ComputerSystem.Reset"` and deserializes the `target` field only.
+//! `"#ComputerSystem.Reset"`: it deserializes the `target` field and the
+//! allowable values of `ResetType`.
 //!
 
 use crate::Bmc;
@@ -54,9 +56,16 @@ use core::fmt::Display;
 use core::fmt::Formatter;
 use core::fmt::Result as FmtResult;
 use serde::Deserialize;
+use serde::Deserializer;
 use serde::Serialize;
+use serde_json::Value as JsonValue;
+use std::collections::HashMap;
 use std::marker::PhantomData;
 
+/// Suffix of the annotation that lists allowable values of an action
+/// parameter.
+const ALLOWABLE_VALUES_SUFFIX: &str = "@Redfish.AllowableValues";
+
 /// URI reference for the `target` field of an action.
 ///
 /// The [`Bmc`] implementation resolves this value when the action is run and
@@ -95,7 +104,9 @@ pub struct Action<T, R> {
     /// URI reference used to trigger the action.
     #[serde(rename = "target")]
     pub target: ActionTarget,
-    // TODO: we can retrieve constraints on attributes here.
+    /// Allowable values of parameters keyed by parameter name.
+    #[serde(flatten, deserialize_with = "de_allowable_values")]
+    allowable_values: HashMap<String, Vec<String>>,
     /// Establishes a dependency on the `T` (parameters) type.
     #[serde(skip_deserializing)]
     _marker: PhantomData<T>,
@@ -108,10 +119,39 @@ impl<T, R> Debug for Action<T, R> {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
         f.debug_struct("Action")
             .field("target", &self.target)
+            .field("allowable_values", &self.allowable_values)
             .finish()
     }
 }
 
+impl<T, R> Action<T, R> {
+    /// Values allowed by the service for `parameter`, as advertised by
+    /// `<parameter>@Redfish.AllowableValues`. Returns `None` when the
+    /// service does not constrain the parameter.
+    #[must_use]
+    pub fn allowable_values(&self, parameter: &str) -> Option<&[String]> {
+        self.allowable_values.get(parameter).map(Vec::as_slice)
+    }
+}
+
+fn de_allowable_values<'de, D>(de: D) -> Result<HashMap<String, Vec<String>>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    Ok(HashMap::<String, JsonValue>::deserialize(de)?
+        .into_iter()
+        .filter_map(|(k, v)| {
+            let parameter = k.strip_suffix(ALLOWABLE_VALUES_SUFFIX)?;
+            let values = v
+                .as_array()?
+                .iter()
+                .filter_map(|v| v.as_str().map(ToOwned::to_owned))
+                .collect();
+            Some((parameter.to_owned(), values))
+        })
+        .collect())
+}
+
 /// Action error trait. Needed in generated code when an action function
 /// is called for an action that wasn't specified by the server.
 pub trait ActionError {
@@ -142,6 +182,8 @@ impl<T: Send + Sync + Serialize, R: Send + Sync + Sized + for<'de> Deserialize<'
 mod tests {
     use super::Action;
     use super::ActionTarget;
+    use serde_json::json;
+    use std::collections::HashMap;
     use std::marker::PhantomData;
 
     struct NotDebug;
@@ -150,13 +192,34 @@ mod tests {
     fn debug_does_not_require_parameter_or_result_debug() {
         let action: Action<NotDebug, NotDebug> = Action {
             target: ActionTarget::new("/redfish/v1/Actions/Test".into()),
+            allowable_values: HashMap::new(),
             _marker: PhantomData,
             _marker_retval: PhantomData,
         };
 
         assert_eq!(
             format!("{action:?}"),
-            "Action { target: ActionTarget(\"/redfish/v1/Actions/Test\") }"
+            "Action { target: ActionTarget(\"/redfish/v1/Actions/Test\"), allowable_values: {} }"
+        );
+    }
+
+    #[test]
+    fn allowable_values_are_collected_per_parameter() {
+        let action: Action<(), ()> = serde_json::from_value(json!({
+            "target": "/redfish/v1/Systems/1/Actions/ComputerSystem.Reset",
+            "ResetType@Redfish.AllowableValues": ["On", "ForceOff"],
+            "@Redfish.ActionInfo": "/redfish/v1/Systems/1/ResetActionInfo",
+        }))
+        .expect("valid action");
+
+        assert_eq!(
+            action.target.as_str(),
+            "/redfish/v1/Systems/1/Actions/ComputerSystem.Reset"
+        );
+        assert_eq!(
+            action.allowable_values("ResetType"),
+            Some(["On".to_string(), "ForceOff".to_string()].as_slice())
         );
+        assert_eq!(action.allowable_values("Other"), None);
     }
 }
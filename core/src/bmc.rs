@@ -33,7 +33,12 @@
 //!
 //! Operation semantics:
 //! - `get` fetches the entity at the given `@odata.id`.
+//! - `get_many` fetches entities at all given `@odata.id`s concurrently.
+//! - `head` checks whether the entity at the given `@odata.id` exists.
 //! - `expand` fetches the entity with the provided `$expand` query.
+//! - `filter` fetches the entity with the provided `$filter` query.
+//! - `select` fetches the entity with the provided `$select` query.
+//! - `page` fetches a page of the collection with `$top`/`$skip` query.
 //! - `create` typically performs a POST to a collection identified by `id` and
 //!   returns the server-provided representation (`R`).
 //! - `update` typically performs a PATCH on an entity identified by `id` and
@@ -49,6 +54,7 @@
 //! - Errors should implement `std::error::Error` and be safely transferable
 //!   across threads.
 
+use futures_util::future::join_all;
 use serde::Deserialize;
 use serde::Serialize;
 
@@ -63,6 +69,8 @@ use crate::HttpPushUriUpdateRequest;
 use crate::ModificationResponse;
 use crate::ODataETag;
 use crate::ODataId;
+use crate::PageQuery;
+use crate::SelectQuery;
 use crate::SessionCreateResponse;
 use std::error::Error as StdError;
 use std::future::Future;
@@ -94,6 +102,56 @@ pub trait Bmc: Send + Sync {
         id: &ODataId,
     ) -> impl Future<Output = Result<Arc<T>, Self::Error>> + Send;
 
+    /// Get data of multiple objects concurrently.
+    ///
+    /// Results are returned in the same order as `ids`. Failure to
+    /// get one object does not affect the others.
+    ///
+    /// The default implementation issues all [`Bmc::get`] requests at
+    /// once; implementations may override it to limit concurrency.
+    fn get_many<T: EntityTypeRef + for<'de> Deserialize<'de> + Send + Sync + 'static>(
+        &self,
+        ids: &[ODataId],
+    ) -> impl Future<Output = Vec<Result<Arc<T>, Self::Error>>> + Send {
+        join_all(ids.iter().map(|id| self.get::<T>(id)))
+    }
+
+    /// Check whether the object exists without using its data.
+    ///
+    /// Returns `false` if the BMC reports that the object does not exist.
+    ///
+    /// The default implementation fetches the full object with
+    /// [`Bmc::get`]; implementations may override it to send HTTP
+    /// `HEAD` instead.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use nv_redfish_core::Bmc;
+    /// use nv_redfish_core::NotFoundError;
+    /// use nv_redfish_core::ODataId;
+    ///
+    /// async fn has_bios<B: Bmc>(bmc: &B) -> Result<bool, B::Error>
+    /// where
+    ///     B::Error: NotFoundError,
+    /// {
+    ///     let id = ODataId::from(String::from("/redfish/v1/Systems/1/Bios"));
+    ///     bmc.head(&id).await
+    /// }
+    /// ```
+    fn head(&self, id: &ODataId) -> impl Future<Output = Result<bool, Self::Error>> + Send
+    where
+        Self::Error: NotFoundError,
+    {
+        async move {
+            match self.get::<AnyEntity>(id).await {
+                Ok(_) => Ok(true),
+                Err(err) if err.is_not_found() => Ok(false),
+                Err(err) => Err(err),
+            }
+        }
+    }
+
     /// Get and filters data of the object (navigation property or entity).
     ///
     /// `T` is structure that is used for return type.
@@ -103,6 +161,24 @@ pub trait Bmc: Send + Sync {
         query: FilterQuery,
     ) -> impl Future<Output = Result<Arc<T>, Self::Error>> + Send;
 
+    /// Get data of the object limited to the selected properties.
+    ///
+    /// `T` is structure that is used for return type.
+    fn select<T: EntityTypeRef + for<'de> Deserialize<'de> + 'static>(
+        &self,
+        id: &ODataId,
+        query: SelectQuery,
+    ) -> impl Future<Output = Result<Arc<T>, Self::Error>> + Send;
+
+    /// Get a single page of the collection.
+    ///
+    /// `T` is structure that is used for return type.
+    fn page<T: EntityTypeRef + for<'de> Deserialize<'de> + 'static>(
+        &self,
+        id: &ODataId,
+        query: PageQuery,
+    ) -> impl Future<Output = Result<Arc<T>, Self::Error>> + Send;
+
     /// Creates element of the collection.
     ///
     /// `V` is structure that is used for create.
@@ -204,4 +280,48 @@ pub trait Bmc: Send + Sync {
         &self,
         uri: &str,
     ) -> impl Future<Output = Result<BoxTryStream<T, Self::Error>, Self::Error>> + Send;
+
+    /// Re-open the stream for the URI after it was dropped.
+    ///
+    /// `last_event_id` is the id of the last event received from the
+    /// previous stream. Server-sent event transports send it as
+    /// `Last-Event-ID` so the service can replay missed events.
+    ///
+    /// The default implementation ignores `last_event_id` and opens a
+    /// new stream with [`Bmc::stream`].
+    fn stream_resume<T: Sized + for<'de> Deserialize<'de> + Send + 'static>(
+        &self,
+        uri: &str,
+        last_event_id: Option<&str>,
+    ) -> impl Future<Output = Result<BoxTryStream<T, Self::Error>, Self::Error>> + Send {
+        let _ = last_event_id;
+        self.stream(uri)
+    }
+}
+
+/// Entity of any type, used by the default [`Bmc::head`].
+#[derive(Deserialize)]
+struct AnyEntity {
+    #[serde(rename = "@odata.id")]
+    odata_id: ODataId,
+}
+
+impl EntityTypeRef for AnyEntity {
+    fn odata_id(&self) -> &ODataId {
+        &self.odata_id
+    }
+
+    fn etag(&self) -> Option<&ODataETag> {
+        None
+    }
+}
+
+/// Errors that can report that the requested resource does not exist.
+///
+/// Used by [`crate::NavProperty::try_get`] to tell absent resources
+/// apart from other failures.
+pub trait NotFoundError {
+    /// Returns true if the error reports that the resource does not
+    /// exist (HTTP 404 Not Found).
+    fn is_not_found(&self) -> bool;
 }
@@ -20,6 +20,15 @@
 //! uses canonical RFC 3339 formatting; `+00:00` is rendered as `Z` while
 //! non‑UTC offsets are preserved.
 //!
+//! Parsing accepts RFC 3339 (`date-time` production, i.e. the ISO 8601
+//! extended format with a mandatory offset). With the `lenient_datetime`
+//! feature, `FromStr` additionally accepts the ISO 8601 basic format
+//! without offset (`YYYYMMDDThhmmss`, emitted by some BMC firmware) and
+//! assumes UTC for it.
+//!
+//! With the `chrono` feature, values convert to and from
+//! `chrono::DateTime<FixedOffset>` preserving the original offset.
+//!
 //! References:
 //! - OASIS OData 4.01 CSDL, Primitive Types: Edm.DateTimeOffset — `https://docs.oasis-open.org/odata/`
 //! - DMTF Redfish Specification DSP0266 — `https://www.dmtf.org/standards/redfish`
@@ -47,6 +56,10 @@
 //! ```
 //!
 
+#[cfg(feature = "chrono")]
+use chrono::DateTime;
+#[cfg(feature = "chrono")]
+use chrono::FixedOffset;
 use core::str::FromStr;
 use serde::{Deserialize, Serialize};
 use std::convert::TryFrom;
@@ -59,6 +72,10 @@ use std::time::Duration;
 use std::time::SystemTime;
 use time::format_description::well_known::Rfc3339;
 use time::OffsetDateTime;
+#[cfg(feature = "lenient_datetime")]
+use time::PrimitiveDateTime;
+#[cfg(feature = "chrono")]
+use time::UtcOffset;
 
 #[derive(Debug, PartialEq, Eq)]
 #[non_exhaustive]
@@ -68,6 +85,14 @@ pub enum Error {
     /// representable as a system time. In particular, on Windows any
     /// time point before 1601 is not representable.
     OutOfSystemTimeRange,
+    /// Conversion to `chrono::DateTime` failed because the value or its
+    /// offset is not representable by `chrono`.
+    #[cfg(feature = "chrono")]
+    OutOfChronoRange,
+    /// Conversion from `chrono::DateTime` failed because the value or its
+    /// offset is not representable by [`EdmDateTimeOffset`].
+    #[cfg(feature = "chrono")]
+    OutOfEdmRange,
 }
 
 impl Display for Error {
@@ -76,6 +101,14 @@ impl Display for Error {
             Self::OutOfSystemTimeRange => {
                 f.write_str("value is outside the representable system time range")
             }
+            #[cfg(feature = "chrono")]
+            Self::OutOfChronoRange => {
+                f.write_str("value is outside the representable chrono range")
+            }
+            #[cfg(feature = "chrono")]
+            Self::OutOfEdmRange => {
+                f.write_str("value is outside the representable Edm.DateTimeOffset range")
+            }
         }
     }
 }
@@ -99,6 +132,47 @@ impl From<EdmDateTimeOffset> for OffsetDateTime {
     }
 }
 
+impl EdmDateTimeOffset {
+    /// Number of whole seconds since the Unix epoch.
+    #[must_use]
+    pub const fn unix_timestamp(&self) -> i64 {
+        self.0.unix_timestamp()
+    }
+
+    /// Number of nanoseconds since the Unix epoch.
+    #[must_use]
+    pub const fn unix_timestamp_nanos(&self) -> i128 {
+        self.0.unix_timestamp_nanos()
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl TryFrom<EdmDateTimeOffset> for DateTime<FixedOffset> {
+    type Error = Error;
+    fn try_from(w: EdmDateTimeOffset) -> Result<Self, Self::Error> {
+        let offset =
+            FixedOffset::east_opt(w.0.offset().whole_seconds()).ok_or(Error::OutOfChronoRange)?;
+        DateTime::from_timestamp(w.0.unix_timestamp(), w.0.nanosecond())
+            .map(|dt| dt.with_timezone(&offset))
+            .ok_or(Error::OutOfChronoRange)
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl TryFrom<DateTime<FixedOffset>> for EdmDateTimeOffset {
+    type Error = Error;
+    fn try_from(dt: DateTime<FixedOffset>) -> Result<Self, Self::Error> {
+        let offset = UtcOffset::from_whole_seconds(dt.offset().local_minus_utc())
+            .map_err(|_| Error::OutOfEdmRange)?;
+        OffsetDateTime::from_unix_timestamp(dt.timestamp())
+            .and_then(|v| v.replace_nanosecond(dt.timestamp_subsec_nanos()))
+            .ok()
+            .and_then(|v| v.checked_to_offset(offset))
+            .map(Self)
+            .ok_or(Error::OutOfEdmRange)
+    }
+}
+
 impl TryFrom<EdmDateTimeOffset> for SystemTime {
     type Error = Error;
     fn try_from(w: EdmDateTimeOffset) -> Result<Self, Self::Error> {
@@ -136,11 +210,24 @@ impl FromStr for EdmDateTimeOffset {
     type Err = time::error::Parse;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let dt = OffsetDateTime::parse(s, &Rfc3339)?;
-        Ok(Self(dt))
+        let dt = OffsetDateTime::parse(s, &Rfc3339);
+        #[cfg(feature = "lenient_datetime")]
+        let dt = dt.or_else(|err| parse_basic_utc(s).map_err(|_| err));
+        Ok(Self(dt?))
     }
 }
 
+/// Parse ISO 8601 basic format without offset (`20250616T194738`) as UTC.
+#[cfg(feature = "lenient_datetime")]
+#[allow(clippy::absolute_paths)]
+fn parse_basic_utc(s: &str) -> Result<OffsetDateTime, time::error::Parse> {
+    PrimitiveDateTime::parse(
+        s,
+        time::macros::format_description!("[year][month][day]T[hour][minute][second]"),
+    )
+    .map(PrimitiveDateTime::assume_utc)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -263,6 +350,77 @@ mod tests {
         assert_eq!(w_minus.to_string(), s_minus);
     }
 
+    #[cfg(feature = "lenient_datetime")]
+    #[test]
+    fn parses_basic_format_without_offset_as_utc() {
+        let w: EdmDateTimeOffset = "20250616T194738".parse().unwrap();
+        let dt: OffsetDateTime = w.into();
+        assert_eq!(dt.offset(), UtcOffset::UTC);
+        assert_eq!(w.to_string(), "2025-06-16T19:47:38Z");
+
+        let plus00: EdmDateTimeOffset = "2025-06-16T19:47:38+00:00".parse().unwrap();
+        assert_eq!(OffsetDateTime::from(plus00), dt);
+        assert!("20250616T19473".parse::<EdmDateTimeOffset>().is_err());
+    }
+
+    #[cfg(not(feature = "lenient_datetime"))]
+    #[test]
+    fn rejects_basic_format_without_offset() {
+        assert!("20250616T194738".parse::<EdmDateTimeOffset>().is_err());
+    }
+
+    #[test]
+    fn exposes_unix_timestamp() {
+        let w: EdmDateTimeOffset = "2021-03-04T10:36:07.25+05:30".parse().unwrap();
+        assert_eq!(w.unix_timestamp(), 1_614_834_367);
+        assert_eq!(w.unix_timestamp_nanos(), 1_614_834_367_250_000_000);
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn converts_to_and_from_chrono_preserving_offset() {
+        use chrono::DateTime;
+        use chrono::FixedOffset;
+
+        for (s, offset_secs) in [
+            ("2021-03-04T10:36:07+05:30", 5 * 3600 + 30 * 60),
+            ("2021-03-04T00:06:07.5-05:00", -5 * 3600),
+            ("2021-03-04T05:06:07Z", 0),
+            ("2021-03-04T00:00:00Z", 0),
+        ] {
+            let w: EdmDateTimeOffset = s.parse().unwrap();
+            let dt: DateTime<FixedOffset> = w.try_into().unwrap();
+            assert_eq!(dt, DateTime::parse_from_rfc3339(s).unwrap());
+            assert_eq!(dt.offset().local_minus_utc(), offset_secs);
+            assert_eq!(dt.timestamp(), w.unix_timestamp());
+
+            let back: EdmDateTimeOffset = dt.try_into().unwrap();
+            assert_eq!(back.to_string(), s);
+        }
+    }
+
+    #[test]
+    fn rejects_time_only_release_date() {
+        // Some BMCs report `00:00:00Z` as firmware `ReleaseDate`.
+        assert!("00:00:00Z".parse::<EdmDateTimeOffset>().is_err());
+        assert!("0000-00-00T00:00:00Z".parse::<EdmDateTimeOffset>().is_err());
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn rejects_chrono_values_outside_edm_range() {
+        use chrono::DateTime;
+        use chrono::FixedOffset;
+        use chrono::TimeDelta;
+
+        let far: DateTime<FixedOffset> =
+            DateTime::parse_from_rfc3339("9999-12-31T23:59:59Z").unwrap() + TimeDelta::days(1);
+        assert_eq!(
+            EdmDateTimeOffset::try_from(far).unwrap_err(),
+            Error::OutOfEdmRange
+        );
+    }
+
     #[test]
     fn rejects_leap_second() {
         assert!("2021-03-04T23:59:60Z".parse::<EdmDateTimeOffset>().is_err());
@@ -88,6 +88,87 @@ impl EdmDuration {
         self.0
     }
 
+    /// Parse full ISO 8601 duration.
+    ///
+    /// Unlike `FromStr`, that accepts Edm.Duration only, this parser
+    /// also accepts years and months approximated as 365 and 30 days.
+    /// Useful for services that emit ISO 8601 durations where
+    /// Edm.Duration is expected.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the string is not a valid ISO 8601 duration
+    /// or its value cannot be represented.
+    pub fn parse_lenient(v: &str) -> Result<Self, Error> {
+        Self::parse(
+            v,
+            &[
+                ('Y', 3600 * 24 * 365),
+                ('M', 3600 * 24 * 30),
+                ('D', 3600 * 24),
+            ],
+        )
+    }
+
+    fn parse(v: &str, date_units: &[(char, i64)]) -> Result<Self, Error> {
+        let mut chars = v.chars();
+        let make_err = || Error::InvalidEdmDuration(v.into());
+        let overflow_err = || Error::Overflow(v.into());
+        let maybe_sign = chars.next().ok_or_else(make_err)?;
+        let (neg, p) = if maybe_sign == '-' {
+            (Decimal::NEGATIVE_ONE, chars.next().ok_or_else(make_err)?)
+        } else {
+            (Decimal::ONE, maybe_sign)
+        };
+        (p == 'P').then_some(()).ok_or_else(make_err)?;
+
+        let to_decimal = |val: &str, mul| {
+            Decimal::from_str_exact(val)
+                .map(|d| d.checked_mul(Decimal::from(mul)).ok_or_else(&overflow_err))
+                .map_err(|_| make_err())
+                .flatten()
+        };
+
+        let mut result = Decimal::ZERO;
+        // Date units must appear in order and at most once.
+        let mut date_units = date_units.iter();
+        let mut has_date = false;
+        loop {
+            let (val, maybe_next, new_chars) = Self::take_digits(&chars);
+            chars = new_chars;
+            match maybe_next {
+                Some('T') if val.is_empty() => break,
+                None if val.is_empty() && has_date => return Ok(Self(result * neg)),
+                Some(unit) => {
+                    let (_, mul) = date_units
+                        .find(|(date_unit, _)| *date_unit == unit)
+                        .ok_or_else(make_err)?;
+                    result = result
+                        .checked_add(to_decimal(val, *mul)?)
+                        .ok_or_else(overflow_err)?;
+                    has_date = true;
+                }
+                None => Err(make_err())?,
+            }
+        }
+
+        loop {
+            let (val, maybe_next, new_chars) = Self::take_digits(&chars);
+            chars = new_chars;
+            let mul = match maybe_next {
+                Some('H') => 3600,
+                Some('M') => 60,
+                Some('S') => 1,
+                Some(_) => Err(make_err())?,
+                None => break,
+            };
+            result = result
+                .checked_add(to_decimal(val, mul)?)
+                .ok_or_else(overflow_err)?;
+        }
+        Ok(Self(result * neg))
+    }
+
     fn take_digits<'a>(chars: &Chars<'a>) -> (&'a str, Option<char>, Chars<'a>) {
         let s = chars.as_str();
         for (i, ch) in s.char_indices() {
@@ -182,58 +263,20 @@ impl TryFrom<EdmDuration> for StdDuration {
     }
 }
 
+// Conversion from the standard duration is lossless: `Decimal` holds
+// any `u64` number of seconds with nanosecond fraction.
+impl From<StdDuration> for EdmDuration {
+    fn from(v: StdDuration) -> Self {
+        Self(
+            (Decimal::from(v.as_secs()) + Decimal::new(i64::from(v.subsec_nanos()), 9)).normalize(),
+        )
+    }
+}
+
 impl FromStr for EdmDuration {
     type Err = Error;
     fn from_str(v: &str) -> Result<Self, Error> {
-        let mut chars = v.chars();
-        let make_err = || Error::InvalidEdmDuration(v.into());
-        let overflow_err = || Error::Overflow(v.into());
-        let maybe_sign = chars.next().ok_or_else(make_err)?;
-        let (neg, p) = if maybe_sign == '-' {
-            (Decimal::NEGATIVE_ONE, chars.next().ok_or_else(make_err)?)
-        } else {
-            (Decimal::ONE, maybe_sign)
-        };
-        (p == 'P').then_some(()).ok_or_else(make_err)?;
-
-        let to_decimal = |val: &str, mul| {
-            Decimal::from_str_exact(val)
-                .map(|d| d.checked_mul(Decimal::from(mul)).ok_or_else(&overflow_err))
-                .map_err(|_| make_err())
-                .flatten()
-        };
-
-        let mut result = Decimal::ZERO;
-        let (val, maybe_next, mut chars) = Self::take_digits(&chars);
-        match maybe_next {
-            Some('T') => (),
-            Some('D') => match chars.next() {
-                Some('T') => {
-                    result = result
-                        .checked_add(to_decimal(val, 3600 * 24)?)
-                        .ok_or_else(overflow_err)?;
-                }
-                None => return to_decimal(val, 3600 * 24).map(|v| Self(v * neg)),
-                _ => Err(make_err())?,
-            },
-            _ => Err(make_err())?,
-        }
-
-        loop {
-            let (val, maybe_next, new_chars) = Self::take_digits(&chars);
-            chars = new_chars;
-            let mul = match maybe_next {
-                Some('H') => 3600,
-                Some('M') => 60,
-                Some('S') => 1,
-                Some(_) => Err(make_err())?,
-                None => break,
-            };
-            result = result
-                .checked_add(to_decimal(val, mul)?)
-                .ok_or_else(overflow_err)?;
-        }
-        Ok(Self(result * neg))
+        Self::parse(v, &[('D', 3600 * 24)])
     }
 }
 
@@ -465,6 +508,81 @@ mod tests {
         assert_eq!(format!("{}", d), "PT1.23S");
     }
 
+    #[test]
+    fn converts_to_std_duration() {
+        let d = EdmDuration::from_str("PT1H30M").unwrap();
+        assert_eq!(
+            StdDuration::try_from(d).unwrap(),
+            StdDuration::from_secs(5400)
+        );
+
+        let d = EdmDuration::from_str("P1DT2H").unwrap();
+        assert_eq!(
+            StdDuration::try_from(d).unwrap(),
+            StdDuration::from_secs(86400 + 2 * 3600)
+        );
+
+        let d = EdmDuration::from_str("PT2.000000005S").unwrap();
+        assert_eq!(StdDuration::try_from(d).unwrap(), StdDuration::new(2, 5));
+    }
+
+    #[test]
+    fn rejects_unrepresentable_std_duration() {
+        let d = EdmDuration::from_str("-PT1S").unwrap();
+        assert!(matches!(
+            StdDuration::try_from(d),
+            Err(Error::CannotConvertNegativeEdmDuration)
+        ));
+        // Edm.Duration has no year or month designators.
+        assert!(EdmDuration::from_str("P1M").is_err());
+        assert!(EdmDuration::from_str("P1Y").is_err());
+    }
+
+    #[test]
+    fn lenient_parser_accepts_years_and_months_as_days() {
+        let d = EdmDuration::parse_lenient("P1Y").unwrap();
+        assert_eq!(d.0, Decimal::from(365 * 86400));
+
+        let d = EdmDuration::parse_lenient("P1Y2M3DT4H").unwrap();
+        assert_eq!(d.0, Decimal::from((365 + 60 + 3) * 86400 + 4 * 3600));
+
+        let d = EdmDuration::parse_lenient("P1MT1M").unwrap();
+        assert_eq!(d.0, Decimal::from(30 * 86400 + 60));
+
+        let d = EdmDuration::parse_lenient("-PT1H30M").unwrap();
+        assert_eq!(d.0, Decimal::from(-5400));
+
+        assert!(EdmDuration::parse_lenient("P1D1Y").is_err());
+        assert!(EdmDuration::parse_lenient("P1M1M").is_err());
+        assert!(EdmDuration::parse_lenient("P1H").is_err());
+    }
+
+    #[test]
+    fn std_duration_round_trip() {
+        for std in [
+            StdDuration::ZERO,
+            StdDuration::from_secs(5400),
+            StdDuration::new(86400 + 1, 1),
+        ] {
+            let edm = EdmDuration::from(std);
+            assert_eq!(StdDuration::try_from(edm).unwrap(), std);
+            let reparsed = EdmDuration::from_str(&edm.to_string()).unwrap();
+            assert_eq!(StdDuration::try_from(reparsed).unwrap(), std);
+        }
+    }
+
+    #[test]
+    fn converts_from_std_duration() {
+        let d = EdmDuration::from(StdDuration::from_secs(5400));
+        assert_eq!(d.to_string(), "PT1H30M0S");
+
+        let d = EdmDuration::from(StdDuration::new(2, 500_000_000));
+        assert_eq!(d.to_string(), "PT2.5S");
+
+        let std = StdDuration::new(86400 * 365, 999_999_999);
+        assert_eq!(StdDuration::try_from(EdmDuration::from(std)).unwrap(), std);
+    }
+
     #[test]
     fn test_exact_division() {
         let (q, r) = EdmDuration::div_with_reminder(Decimal::new(10, 0), Decimal::new(5, 0));
@@ -101,6 +101,8 @@ pub use action::ActionError;
 #[doc(inline)]
 pub use bmc::Bmc;
 #[doc(inline)]
+pub use bmc::NotFoundError;
+#[doc(inline)]
 pub use deserialize::de_optional_nullable;
 #[doc(inline)]
 pub use deserialize::de_required_nullable;
@@ -125,6 +127,10 @@ pub use odata::ODataId;
 #[doc(inline)]
 pub use query::FilterQuery;
 #[doc(inline)]
+pub use query::PageQuery;
+#[doc(inline)]
+pub use query::SelectQuery;
+#[doc(inline)]
 pub use query::ToFilterLiteral;
 #[doc(inline)]
 pub use serde_json::Value as AdditionalProperties;
@@ -142,6 +148,8 @@ pub use upload::OemMultipartPartNameError;
 #[doc(inline)]
 pub use upload::OemMultipartPartReader;
 #[doc(inline)]
+pub use upload::UploadProgressFn;
+#[doc(inline)]
 pub use upload::UploadReader;
 #[cfg(feature = "update-service-deprecated")]
 #[doc(inline)]
@@ -160,6 +168,9 @@ pub trait EntityTypeRef: Send + Sync + Sized {
     fn etag(&self) -> Option<&ODataETag>;
 
     /// Refresh the entity by fetching it again from the BMC.
+    ///
+    /// Use [`Expandable::expand`] to refresh the entity in its expanded
+    /// form with a single request.
     fn refresh<B: Bmc>(&self, bmc: &B) -> impl Future<Output = Result<Arc<Self>, B::Error>> + Send
     where
         Self: for<'de> Deserialize<'de> + 'static,
@@ -171,6 +182,9 @@ pub trait EntityTypeRef: Send + Sync + Sized {
 /// Defines entity types that support `$expand` via query parameters.
 pub trait Expandable: EntityTypeRef + for<'de> Deserialize<'de> + 'static {
     /// Expand the entity according to the provided query.
+    ///
+    /// The entity is fetched again by its own `@odata.id` with `$expand`
+    /// applied, so this is also the way to refresh an expanded entity.
     fn expand<B: Bmc>(
         &self,
         bmc: &B,
@@ -419,7 +433,8 @@ mod tests {
     #[test]
     fn try_map_entity_maps_entity_and_propagates_error() -> Result<(), &'static str> {
         assert_entity(
-            ModificationResponse::Entity(21_u32).try_map_entity(|value| Ok(value * 2))?,
+            ModificationResponse::Entity(21_u32)
+                .try_map_entity(|value| Ok::<u32, &'static str>(value * 2))?,
             42,
         )?;
 
@@ -447,7 +462,7 @@ mod tests {
     ) -> Result<(), &'static str> {
         assert_entity(
             ModificationResponse::Entity(21_u32)
-                .try_map_entity_async(|value| async move { Ok(value * 2) })
+                .try_map_entity_async(|value| async move { Ok::<u32, &'static str>(value * 2) })
                 .await?,
             42,
         )?;
@@ -39,6 +39,7 @@ use crate::Deletable;
 use crate::EntityTypeRef;
 use crate::Expandable;
 use crate::FilterQuery;
+use crate::NotFoundError;
 use crate::ODataETag;
 use crate::ODataId;
 use crate::Updatable;
@@ -223,6 +224,26 @@ impl<T: EntityTypeRef + for<'de> Deserialize<'de> + 'static> NavProperty<T> {
         }
     }
 
+    /// Get the property value, or `None` if the referenced entity does
+    /// not exist.
+    ///
+    /// # Errors
+    ///
+    /// If the navigation is a reference then a BMC error may be returned if
+    /// retrieval of the entity fails for reason other than absence of the
+    /// entity.
+    pub async fn try_get<B>(&self, bmc: &B) -> Result<Option<Arc<T>>, B::Error>
+    where
+        B: Bmc,
+        B::Error: NotFoundError,
+    {
+        match self.get(bmc).await {
+            Ok(v) => Ok(Some(v)),
+            Err(err) if err.is_not_found() => Ok(None),
+            Err(err) => Err(err),
+        }
+    }
+
     /// Filter the property value using the provided query.
     ///
     /// # Errors
@@ -71,6 +71,37 @@ impl ODataId {
             .or_else(|| (!path.is_empty()).then_some(path))
     }
 
+    /// Parent path of `ODataId`, i.e. the path without the last segment.
+    ///
+    /// Returns `None` for the service root and for paths without
+    /// a parent.
+    ///
+    /// # Examples
+    /// * `"/redfish/v1/Chassis/1" -> Some("/redfish/v1/Chassis")`
+    /// * `"/redfish/v1/Chassis/1/" -> Some("/redfish/v1/Chassis")`
+    /// * `"/redfish/v1" -> None`
+    #[must_use]
+    pub fn parent(&self) -> Option<Self> {
+        let path = self.0.trim_end_matches('/');
+        if path == Self::service_root().0 {
+            return None;
+        }
+        path.rsplit_once('/')
+            .map(|(parent, _)| parent)
+            .filter(|parent| !parent.is_empty())
+            .map(|parent| Self(parent.into()))
+    }
+
+    /// Path of the child `segment` of `ODataId`.
+    ///
+    /// # Examples
+    /// * `"/redfish/v1/Chassis", "1" -> "/redfish/v1/Chassis/1"`
+    /// * `"/redfish/v1/Chassis/", "1" -> "/redfish/v1/Chassis/1"`
+    #[must_use]
+    pub fn child(&self, segment: &str) -> Self {
+        Self(format!("{}/{segment}", self.0.trim_end_matches('/')))
+    }
+
     /// Returns whether this path is a segment-aware prefix of another path.
     ///
     /// Equal paths return `true`.
@@ -160,6 +191,40 @@ mod tests {
         assert!(odata_type.is_none());
     }
 
+    #[test]
+    fn parent_strips_last_path_segment() {
+        let id = ODataId("/redfish/v1/Chassis/1".into());
+        assert_eq!(id.parent(), Some(ODataId("/redfish/v1/Chassis".into())));
+    }
+
+    #[test]
+    fn parent_ignores_trailing_slash() {
+        let id = ODataId("/redfish/v1/Chassis/1/".into());
+        assert_eq!(id.parent(), Some(ODataId("/redfish/v1/Chassis".into())));
+    }
+
+    #[test]
+    fn parent_of_service_root_is_none() {
+        assert_eq!(ODataId::service_root().parent(), None);
+        assert_eq!(ODataId("/redfish/v1/".into()).parent(), None);
+        assert_eq!(
+            ODataId("/redfish/v1/Chassis".into()).parent(),
+            Some(ODataId::service_root())
+        );
+    }
+
+    #[test]
+    fn child_appends_path_segment() {
+        let id = ODataId("/redfish/v1/Chassis".into());
+        assert_eq!(id.child("1"), ODataId("/redfish/v1/Chassis/1".into()));
+        let id = ODataId("/redfish/v1/Chassis/".into());
+        assert_eq!(id.child("1"), ODataId("/redfish/v1/Chassis/1".into()));
+        assert_eq!(
+            ODataId::service_root().child("Chassis"),
+            ODataId("/redfish/v1/Chassis".into())
+        );
+    }
+
     #[test]
     fn last_segment_returns_last_path_segment() {
         let id = ODataId("/redfish/v1/Systems/1".into());
@@ -266,4 +331,36 @@ mod tests {
 
         assert!(prefix.is_path_prefix(&id));
     }
+
+    #[test]
+    fn odata_id_serializes_as_plain_string() {
+        let id = ODataId("/redfish/v1/Systems/1".into());
+        let json = serde_json::to_value(&id).expect("serializable");
+        assert_eq!(json, serde_json::json!("/redfish/v1/Systems/1"));
+    }
+
+    #[test]
+    fn odata_etag_serializes_as_plain_string() {
+        let etag = ODataETag("W/\"12345\"".into());
+        let json = serde_json::to_string(&etag).expect("serializable");
+        assert_eq!(json, r#""W/\"12345\"""#);
+    }
+
+    proptest::proptest! {
+        #[test]
+        fn odata_id_json_round_trip(value in ".*") {
+            let id = ODataId(value);
+            let json = serde_json::to_string(&id).expect("serializable");
+            let parsed: ODataId = serde_json::from_str(&json).expect("deserializable");
+            proptest::prop_assert_eq!(parsed, id);
+        }
+
+        #[test]
+        fn odata_etag_json_round_trip(value in ".*") {
+            let etag = ODataETag(value);
+            let json = serde_json::to_string(&etag).expect("serializable");
+            let parsed: ODataETag = serde_json::from_str(&json).expect("deserializable");
+            proptest::prop_assert_eq!(parsed, etag);
+        }
+    }
 }
@@ -53,9 +53,49 @@
 //! assert_eq!(query.to_query_string(), "$filter=Temperature gt 50 and Temperature lt 80");
 //! ```
 //!
+//! ## Select Query (`$select`)
+//!
+//! The [`SelectQuery`] builder constructs `$select` parameters to limit the
+//! properties returned by the service.
+//!
+//! ```rust
+//! use nv_redfish_core::query::SelectQuery;
+//!
+//! let query = SelectQuery::properties(&["Status", "PowerState"]);
+//! assert_eq!(query.to_query_string(), "$select=Status,PowerState");
+//! ```
+//!
+//! ## Paging Query (`$top` / `$skip`)
+//!
+//! The [`PageQuery`] builder constructs `$top` and `$skip` parameters to
+//! retrieve a single page of a collection.
+//!
+//! ```rust
+//! use nv_redfish_core::query::PageQuery;
+//!
+//! let query = PageQuery::new().top(50).skip(100);
+//! assert_eq!(query.to_query_string(), "$top=50&$skip=100");
+//! ```
+//!
+//! ## URL Encoding
+//!
+//! `to_query_string` returns the query in the readable form used by
+//! the specification. Use `to_encoded_query_string` to get the query
+//! with percent-encoded parameter values suitable for a request URL.
+//!
+//! ```rust
+//! use nv_redfish_core::query::FilterQuery;
+//!
+//! let query = FilterQuery::eq(&"Status/Health", "OK");
+//! assert_eq!(
+//!     query.to_encoded_query_string(),
+//!     "$filter=Status/Health%20eq%20%27OK%27"
+//! );
+//! ```
+//!
 //! # Type Safety
 //!
-//! Both builders use traits to ensure type safety:
+//! The builders use traits to ensure type safety:
 //!
 //! - [`crate::FilterProperty`]: Types that can be used as filter property paths
 //! - [`ToFilterLiteral`]: Types that can be converted to filter literal values
@@ -70,6 +110,32 @@
 //! - [Redfish Specification DSP0266](https://redfish.dmtf.org/schemas/DSP0266_1.15.0.html)
 //! - [OData Version 4.0 Protocol](http://docs.oasis-open.org/odata/odata/v4.0/os/part2-url-conventions/odata-v4.0-os-part2-url-conventions.html)
 
+use crate::EdmDateTimeOffset;
+use core::fmt::Write as _;
+
+/// Characters that are kept as is in encoded query parameter values.
+///
+/// Everything else, including spaces, quotes and `&`/`+`/`#`/`%`
+/// that could change the meaning of the query, is percent-encoded.
+const QUERY_VALUE_SAFE: &[u8] = b"-._~$()*,/:;@!=";
+
+/// Percent-encode the value of a `$name=value` query parameter leaving
+/// the `$`-prefixed name intact.
+fn encode_query_parameter(parameter: &str) -> String {
+    let Some((name, value)) = parameter.split_once('=') else {
+        return parameter.to_string();
+    };
+    let mut encoded = format!("{name}=");
+    for byte in value.bytes() {
+        if byte.is_ascii_alphanumeric() || QUERY_VALUE_SAFE.contains(&byte) {
+            encoded.push(char::from(byte));
+        } else {
+            let _ = write!(encoded, "%{byte:02X}");
+        }
+    }
+    encoded
+}
+
 /// Builder for Redfish `$expand` query parameters according to DSP0266 specification.
 ///
 /// The `$expand` query parameter allows clients to request that the server expand
@@ -283,6 +349,15 @@ impl ExpandQuery {
     ///
     /// let system = ExpandQuery::properties(&["Processors", "Memory", "Storage"]);
     /// assert_eq!(system.to_query_string(), "$expand=Processors,Memory,Storage($levels=1)");
+    ///
+    /// // Empty list falls back to the current resource until a
+    /// // property is added
+    /// let empty = ExpandQuery::properties(&[]);
+    /// assert_eq!(empty.to_query_string(), "$expand=.($levels=1)");
+    /// assert_eq!(
+    ///     empty.add_property("Thermal").to_query_string(),
+    ///     "$expand=Thermal($levels=1)"
+    /// );
     /// ```
     #[must_use]
     pub fn properties(properties: &[&str]) -> Self {
@@ -292,6 +367,25 @@ impl ExpandQuery {
         }
     }
 
+    /// Add one more navigation property to expand.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use nv_redfish_core::query::ExpandQuery;
+    ///
+    /// let env = ExpandQuery::property("Thermal").add_property("Power");
+    /// assert_eq!(env.to_query_string(), "$expand=Thermal,Power($levels=1)");
+    /// ```
+    #[must_use]
+    pub fn add_property(mut self, property: &str) -> Self {
+        if !self.expand_expression.is_empty() {
+            self.expand_expression.push(',');
+        }
+        self.expand_expression.push_str(property);
+        self
+    }
+
     /// Set the number of levels to cascade the expand operation.
     ///
     /// The `$levels` parameter controls how deep the expansion goes:
@@ -320,6 +414,25 @@ impl ExpandQuery {
         self
     }
 
+    /// Remove the `$levels` qualifier from the expand operation.
+    ///
+    /// Some servers reject `$expand=*($levels=1)` and only accept the
+    /// plain `$expand=*` form.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use nv_redfish_core::query::ExpandQuery;
+    ///
+    /// let query = ExpandQuery::all().without_levels();
+    /// assert_eq!(query.to_query_string(), "$expand=*");
+    /// ```
+    #[must_use]
+    pub const fn without_levels(mut self) -> Self {
+        self.levels = None;
+        self
+    }
+
     /// Convert to the `OData` query string according to Redfish specification.
     ///
     /// This generates the actual query parameter string that will be appended to
@@ -344,11 +457,23 @@ impl ExpandQuery {
     #[must_use]
     #[allow(clippy::option_if_let_else)]
     pub fn to_query_string(&self) -> String {
+        // No properties to expand means the current resource.
+        let expand_expression = if self.expand_expression.is_empty() {
+            "."
+        } else {
+            &self.expand_expression
+        };
         match self.levels {
-            Some(levels) => format!("$expand={}($levels={})", self.expand_expression, levels),
-            None => format!("$expand={}", self.expand_expression),
+            Some(levels) => format!("$expand={expand_expression}($levels={levels})"),
+            None => format!("$expand={expand_expression}"),
         }
     }
+
+    /// Convert to the `OData` query string with percent-encoded value.
+    #[must_use]
+    pub fn to_encoded_query_string(&self) -> String {
+        encode_query_parameter(&self.to_query_string())
+    }
 }
 
 /// Literal value types supported in filter expressions
@@ -362,6 +487,8 @@ pub enum FilterLiteral {
     Integer(i64),
     /// Boolean literal value
     Boolean(bool),
+    /// Date and time literal value
+    DateTimeOffset(EdmDateTimeOffset),
 }
 
 impl FilterLiteral {
@@ -371,6 +498,7 @@ impl FilterLiteral {
             Self::Number(n) => n.to_string(),
             Self::Integer(i) => i.to_string(),
             Self::Boolean(b) => b.to_string(),
+            Self::DateTimeOffset(dt) => dt.to_string(),
         }
     }
 }
@@ -417,6 +545,12 @@ impl ToFilterLiteral for bool {
     }
 }
 
+impl ToFilterLiteral for EdmDateTimeOffset {
+    fn to_filter_literal(self) -> FilterLiteral {
+        FilterLiteral::DateTimeOffset(self)
+    }
+}
+
 /// Filter expression component
 #[derive(Debug, Clone)]
 enum FilterExpr {
@@ -425,6 +559,11 @@ enum FilterExpr {
         operator: &'static str,
         value: FilterLiteral,
     },
+    Function {
+        function: &'static str,
+        property: String,
+        value: FilterLiteral,
+    },
     And(Box<Self>, Box<Self>),
     Or(Box<Self>, Box<Self>),
     Not(Box<Self>),
@@ -432,6 +571,24 @@ enum FilterExpr {
 }
 
 impl FilterExpr {
+    fn any_of<P, V, I>(property: &P, values: I) -> Option<Self>
+    where
+        P: crate::FilterProperty,
+        V: ToFilterLiteral,
+        I: IntoIterator<Item = V>,
+    {
+        let property = property.property_path();
+        values
+            .into_iter()
+            .map(|value| Self::Comparison {
+                property: property.to_string(),
+                operator: "eq",
+                value: value.to_filter_literal(),
+            })
+            .reduce(|left, right| Self::Or(Box::new(left), Box::new(right)))
+            .map(|expr| Self::Group(Box::new(expr)))
+    }
+
     fn to_odata_string(&self) -> String {
         match self {
             Self::Comparison {
@@ -441,15 +598,23 @@ impl FilterExpr {
             } => {
                 format!("{} {} {}", property, operator, value.to_odata_string())
             }
+            Self::Function {
+                function,
+                property,
+                value,
+            } => {
+                format!("{}({},{})", function, property, value.to_odata_string())
+            }
             Self::And(left, right) => {
                 format!("{} and {}", left.to_odata_string(), right.to_odata_string())
             }
             Self::Or(left, right) => {
                 format!("{} or {}", left.to_odata_string(), right.to_odata_string())
             }
-            Self::Not(expr) => {
-                format!("not {}", expr.to_odata_string())
-            }
+            Self::Not(expr) => match expr.as_ref() {
+                Self::Group(_) => format!("not {}", expr.to_odata_string()),
+                _ => format!("not ({})", expr.to_odata_string()),
+            },
             Self::Group(expr) => {
                 format!("({})", expr.to_odata_string())
             }
@@ -465,6 +630,7 @@ impl FilterExpr {
 /// # Supported Operators
 ///
 /// - Comparison: `eq`, `ne`, `gt`, `ge`, `lt`, `le`
+/// - String functions: `contains`, `startswith`, `endswith`
 /// - Logical: `and`, `or`, `not`
 /// - Grouping: `()`
 ///
@@ -493,6 +659,36 @@ impl FilterExpr {
 ///     .group()
 ///     .or()
 ///     .eq_then(&"SystemType", "Physical");
+/// assert_eq!(
+///     filter.to_query_string(),
+///     "$filter=(Status/State eq 'Enabled' and Status/Health eq 'OK') or SystemType eq 'Physical'"
+/// );
+///
+/// // Negation
+/// let filter = FilterQuery::eq(&"Status/State", "Disabled").not();
+/// assert_eq!(filter.to_query_string(), "$filter=not (Status/State eq 'Disabled')");
+///
+/// // Nested `or` inside `and`
+/// let filter = FilterQuery::eq(&"SystemType", "Physical")
+///     .and()
+///     .group_then(
+///         FilterQuery::eq(&"Status/Health", "OK")
+///             .or()
+///             .eq_then(&"Status/Health", "Warning"),
+///     );
+/// assert_eq!(
+///     filter.to_query_string(),
+///     "$filter=SystemType eq 'Physical' and (Status/Health eq 'OK' or Status/Health eq 'Warning')"
+/// );
+///
+/// // String functions
+/// let filter = FilterQuery::contains(&"Model", "DGX")
+///     .and()
+///     .startswith_then(&"Manufacturer", "NVIDIA");
+/// assert_eq!(
+///     filter.to_query_string(),
+///     "$filter=contains(Model,'DGX') and startswith(Manufacturer,'NVIDIA')"
+/// );
 /// ```
 #[derive(Debug, Clone)]
 pub struct FilterQuery {
@@ -579,6 +775,58 @@ impl FilterQuery {
         }
     }
 
+    /// Create a new filter with a substring match (`contains` string function)
+    pub fn contains<P: crate::FilterProperty, V: Into<String>>(property: &P, value: V) -> Self {
+        Self {
+            expr: Some(FilterExpr::Function {
+                function: "contains",
+                property: property.property_path().to_string(),
+                value: FilterLiteral::String(value.into()),
+            }),
+            pending_logical_op: None,
+        }
+    }
+
+    /// Create a new filter with a prefix match (`startswith` string function)
+    pub fn startswith<P: crate::FilterProperty, V: Into<String>>(property: &P, value: V) -> Self {
+        Self {
+            expr: Some(FilterExpr::Function {
+                function: "startswith",
+                property: property.property_path().to_string(),
+                value: FilterLiteral::String(value.into()),
+            }),
+            pending_logical_op: None,
+        }
+    }
+
+    /// Create a new filter with a suffix match (`endswith` string function)
+    pub fn endswith<P: crate::FilterProperty, V: Into<String>>(property: &P, value: V) -> Self {
+        Self {
+            expr: Some(FilterExpr::Function {
+                function: "endswith",
+                property: property.property_path().to_string(),
+                value: FilterLiteral::String(value.into()),
+            }),
+            pending_logical_op: None,
+        }
+    }
+
+    /// Create a new filter matching any of `values`, expanded to a
+    /// parenthesised chain of `eq` comparisons joined with `or`
+    ///
+    /// An empty `values` produces an empty filter.
+    pub fn any_of<P, V, I>(property: &P, values: I) -> Self
+    where
+        P: crate::FilterProperty,
+        V: ToFilterLiteral,
+        I: IntoIterator<Item = V>,
+    {
+        Self {
+            expr: FilterExpr::any_of(property, values),
+            pending_logical_op: None,
+        }
+    }
+
     /// Add a logical AND operator (must be followed by another comparison)
     #[must_use]
     pub const fn and(mut self) -> Self {
@@ -612,6 +860,22 @@ impl FilterQuery {
         self
     }
 
+    /// Chain a grouped sub-expression (after .`and()` or .`or()`)
+    ///
+    /// An empty `inner` is skipped together with the pending operator.
+    #[must_use]
+    pub fn group_then(self, inner: Self) -> Self {
+        self.combine_or_skip(inner.expr.map(|expr| FilterExpr::Group(Box::new(expr))))
+    }
+
+    /// Chain a negated sub-expression (after .`and()` or .`or()`)
+    ///
+    /// An empty `inner` is skipped together with the pending operator.
+    #[must_use]
+    pub fn not_then(self, inner: Self) -> Self {
+        self.combine_or_skip(inner.expr.map(|expr| FilterExpr::Not(Box::new(expr))))
+    }
+
     /// Chain an equality comparison (after .`and()` or .`or()`)
     #[must_use]
     pub fn eq_then<P: crate::FilterProperty, V: ToFilterLiteral>(
@@ -702,6 +966,71 @@ impl FilterQuery {
         self.combine_with_pending_op(new_expr)
     }
 
+    /// Chain a substring match (after .`and()` or .`or()`)
+    #[must_use]
+    pub fn contains_then<P: crate::FilterProperty, V: Into<String>>(
+        self,
+        property: &P,
+        value: V,
+    ) -> Self {
+        let new_expr = FilterExpr::Function {
+            function: "contains",
+            property: property.property_path().to_string(),
+            value: FilterLiteral::String(value.into()),
+        };
+        self.combine_with_pending_op(new_expr)
+    }
+
+    /// Chain a prefix match (after .`and()` or .`or()`)
+    #[must_use]
+    pub fn startswith_then<P: crate::FilterProperty, V: Into<String>>(
+        self,
+        property: &P,
+        value: V,
+    ) -> Self {
+        let new_expr = FilterExpr::Function {
+            function: "startswith",
+            property: property.property_path().to_string(),
+            value: FilterLiteral::String(value.into()),
+        };
+        self.combine_with_pending_op(new_expr)
+    }
+
+    /// Chain a suffix match (after .`and()` or .`or()`)
+    #[must_use]
+    pub fn endswith_then<P: crate::FilterProperty, V: Into<String>>(
+        self,
+        property: &P,
+        value: V,
+    ) -> Self {
+        let new_expr = FilterExpr::Function {
+            function: "endswith",
+            property: property.property_path().to_string(),
+            value: FilterLiteral::String(value.into()),
+        };
+        self.combine_with_pending_op(new_expr)
+    }
+
+    /// Chain a match of any of `values` (after .`and()` or .`or()`)
+    #[must_use]
+    pub fn any_of_then<P, V, I>(self, property: &P, values: I) -> Self
+    where
+        P: crate::FilterProperty,
+        V: ToFilterLiteral,
+        I: IntoIterator<Item = V>,
+    {
+        self.combine_or_skip(FilterExpr::any_of(property, values))
+    }
+
+    fn combine_or_skip(mut self, new_expr: Option<FilterExpr>) -> Self {
+        if let Some(expr) = new_expr {
+            self.combine_with_pending_op(expr)
+        } else {
+            self.pending_logical_op = None;
+            self
+        }
+    }
+
     fn combine_with_pending_op(mut self, new_expr: FilterExpr) -> Self {
         if let Some(existing) = self.expr.take() {
             self.expr = Some(match self.pending_logical_op.take() {
@@ -722,6 +1051,163 @@ impl FilterQuery {
             format!("$filter={}", expr.to_odata_string())
         })
     }
+
+    /// Convert to the `OData` query string with percent-encoded value.
+    #[must_use]
+    pub fn to_encoded_query_string(&self) -> String {
+        encode_query_parameter(&self.to_query_string())
+    }
+}
+
+/// Builder for Redfish `$select` query parameters according to DSP0266 specification.
+///
+/// The `$select` query parameter allows clients to request a subset of the
+/// resource properties, which reduces payload size when polling large
+/// resources such as `ComputerSystem`.
+///
+/// # Examples
+///
+/// ```rust
+/// use nv_redfish_core::query::SelectQuery;
+///
+/// let select = SelectQuery::properties(&["Status", "PowerState"]);
+/// assert_eq!(select.to_query_string(), "$select=Status,PowerState");
+///
+/// // Nested properties are addressed with `/`
+/// let health = SelectQuery::property(&"Status/Health");
+/// assert_eq!(health.to_query_string(), "$select=Status/Health");
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct SelectQuery {
+    properties: Vec<String>,
+}
+
+impl SelectQuery {
+    /// Select a single property.
+    pub fn property<P: crate::FilterProperty + ?Sized>(property: &P) -> Self {
+        Self {
+            properties: vec![property.property_path().to_string()],
+        }
+    }
+
+    /// Select multiple properties.
+    pub fn properties<P: crate::FilterProperty>(properties: &[P]) -> Self {
+        Self {
+            properties: properties
+                .iter()
+                .map(|p| p.property_path().to_string())
+                .collect(),
+        }
+    }
+
+    /// Add one more property to the selection.
+    #[must_use]
+    pub fn and_property<P: crate::FilterProperty + ?Sized>(mut self, property: &P) -> Self {
+        self.properties.push(property.property_path().to_string());
+        self
+    }
+
+    /// Convert to the `OData` query string
+    #[must_use]
+    pub fn to_query_string(&self) -> String {
+        if self.properties.is_empty() {
+            String::new()
+        } else {
+            format!("$select={}", self.properties.join(","))
+        }
+    }
+
+    /// Convert to the `OData` query string with percent-encoded values.
+    #[must_use]
+    pub fn to_encoded_query_string(&self) -> String {
+        encode_query_parameter(&self.to_query_string())
+    }
+}
+
+/// Builder for Redfish `$top` and `$skip` paging query parameters according
+/// to DSP0266 specification.
+///
+/// Paging lets clients walk large collections, such as
+/// `LogEntryCollection`, one page at a time. Absent parameters are omitted
+/// from the query string.
+///
+/// # Examples
+///
+/// ```rust
+/// use nv_redfish_core::query::ExpandQuery;
+/// use nv_redfish_core::query::PageQuery;
+///
+/// let page = PageQuery::new().top(50).skip(100);
+/// assert_eq!(page.to_query_string(), "$top=50&$skip=100");
+///
+/// let page = PageQuery::new().top(50).expand(ExpandQuery::current());
+/// assert_eq!(page.to_query_string(), "$expand=.($levels=1)&$top=50");
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct PageQuery {
+    top: Option<u32>,
+    skip: Option<u32>,
+    expand: Option<ExpandQuery>,
+}
+
+impl PageQuery {
+    /// Create a new paging query without any parameters.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Limit the number of returned members. Zero is clamped to one
+    /// because an empty page cannot make progress.
+    #[must_use]
+    pub fn top(mut self, top: u32) -> Self {
+        self.top = Some(top.max(1));
+        self
+    }
+
+    /// Skip the given number of members.
+    #[must_use]
+    pub const fn skip(mut self, skip: u32) -> Self {
+        self.skip = Some(skip);
+        self
+    }
+
+    /// Expand members of the requested page.
+    #[must_use]
+    pub fn expand(mut self, expand: ExpandQuery) -> Self {
+        self.expand = Some(expand);
+        self
+    }
+
+    /// Convert to the `OData` query string
+    #[must_use]
+    pub fn to_query_string(&self) -> String {
+        self.params().join("&")
+    }
+
+    /// Convert to the `OData` query string with percent-encoded values.
+    #[must_use]
+    pub fn to_encoded_query_string(&self) -> String {
+        self.params()
+            .iter()
+            .map(|param| encode_query_parameter(param))
+            .collect::<Vec<_>>()
+            .join("&")
+    }
+
+    fn params(&self) -> Vec<String> {
+        let mut params = Vec::new();
+        if let Some(expand) = &self.expand {
+            params.push(expand.to_query_string());
+        }
+        if let Some(top) = self.top {
+            params.push(format!("$top={top}"));
+        }
+        if let Some(skip) = self.skip {
+            params.push(format!("$skip={skip}"));
+        }
+        params
+    }
 }
 
 /// Implement `FilterProperty` for `&str`
@@ -741,6 +1227,7 @@ impl crate::FilterProperty for String {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use time::OffsetDateTime;
 
     #[test]
     fn test_default_expand() {
@@ -748,6 +1235,18 @@ mod tests {
         assert_eq!(query.to_query_string(), "$expand=.($levels=1)");
     }
 
+    #[test]
+    fn test_expand_without_levels() {
+        let query = ExpandQuery::all().without_levels();
+        assert_eq!(query.to_query_string(), "$expand=*");
+
+        let query = ExpandQuery::property("Thermal").levels(2).without_levels();
+        assert_eq!(query.to_query_string(), "$expand=Thermal");
+
+        let query = ExpandQuery::all().without_levels().levels(2);
+        assert_eq!(query.to_query_string(), "$expand=*($levels=2)");
+    }
+
     #[test]
     fn test_expand_all() {
         let query = ExpandQuery::all();
@@ -778,6 +1277,56 @@ mod tests {
         assert_eq!(query.to_query_string(), "$expand=Thermal,Power($levels=1)");
     }
 
+    #[test]
+    fn test_expand_three_properties_with_levels() {
+        let query = ExpandQuery::properties(&["Thermal", "Power", "Sensors"]).levels(2);
+        assert_eq!(
+            query.to_query_string(),
+            "$expand=Thermal,Power,Sensors($levels=2)"
+        );
+    }
+
+    #[test]
+    fn test_expand_keywords_are_unchanged() {
+        assert_eq!(ExpandQuery::all().to_query_string(), "$expand=*($levels=1)");
+        assert_eq!(
+            ExpandQuery::current().to_query_string(),
+            "$expand=.($levels=1)"
+        );
+        assert_eq!(
+            ExpandQuery::links().to_query_string(),
+            "$expand=~($levels=1)"
+        );
+    }
+
+    #[test]
+    fn test_expand_properties_empty_falls_back_to_current() {
+        let query = ExpandQuery::properties(&[]);
+        assert_eq!(query.to_query_string(), "$expand=.($levels=1)");
+    }
+
+    #[test]
+    fn test_expand_add_property_to_empty_properties() {
+        let query = ExpandQuery::properties(&[]).add_property("Thermal");
+        assert_eq!(query.to_query_string(), "$expand=Thermal($levels=1)");
+    }
+
+    #[test]
+    fn test_expand_single_property_list_equals_property() {
+        assert_eq!(
+            ExpandQuery::properties(&["Thermal"]).to_query_string(),
+            ExpandQuery::property("Thermal").to_query_string()
+        );
+    }
+
+    #[test]
+    fn test_expand_add_property() {
+        let query = ExpandQuery::property("Thermal")
+            .add_property("Power")
+            .levels(2);
+        assert_eq!(query.to_query_string(), "$expand=Thermal,Power($levels=2)");
+    }
+
     #[test]
     fn test_expand_with_levels() {
         let query = ExpandQuery::all().levels(3);
@@ -816,7 +1365,20 @@ mod tests {
     #[test]
     fn test_not_operator() {
         let filter = FilterQuery::eq(&"Count", 2).not();
-        assert_eq!(filter.to_query_string(), "$filter=not Count eq 2");
+        assert_eq!(filter.to_query_string(), "$filter=not (Count eq 2)");
+    }
+
+    #[test]
+    fn test_not_of_group_is_not_double_parenthesised() {
+        let filter = FilterQuery::eq(&"Count", 2)
+            .or()
+            .eq_then(&"Count", 4)
+            .group()
+            .not();
+        assert_eq!(
+            filter.to_query_string(),
+            "$filter=not (Count eq 2 or Count eq 4)"
+        );
     }
 
     #[test]
@@ -833,6 +1395,53 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_nested_or_inside_and() {
+        let filter = FilterQuery::eq(&"Type", "Physical").and().group_then(
+            FilterQuery::eq(&"Health", "OK")
+                .or()
+                .eq_then(&"Health", "Warning"),
+        );
+        assert_eq!(
+            filter.to_query_string(),
+            "$filter=Type eq 'Physical' and (Health eq 'OK' or Health eq 'Warning')"
+        );
+    }
+
+    #[test]
+    fn test_not_then() {
+        let filter = FilterQuery::eq(&"Type", "Physical")
+            .and()
+            .not_then(FilterQuery::eq(&"State", "Disabled"));
+        assert_eq!(
+            filter.to_query_string(),
+            "$filter=Type eq 'Physical' and not (State eq 'Disabled')"
+        );
+    }
+
+    #[test]
+    fn test_empty_sub_expression_is_skipped() {
+        let empty = || FilterQuery::any_of(&"Health", Vec::<&str>::new());
+
+        let filter = FilterQuery::eq(&"Type", "Physical")
+            .and()
+            .group_then(empty());
+        assert_eq!(filter.to_query_string(), "$filter=Type eq 'Physical'");
+
+        let filter = FilterQuery::eq(&"Type", "Physical")
+            .and()
+            .not_then(empty())
+            .or()
+            .eq_then(&"Health", "OK");
+        assert_eq!(
+            filter.to_query_string(),
+            "$filter=Type eq 'Physical' or Health eq 'OK'"
+        );
+
+        assert_eq!(empty().not().to_query_string(), "");
+        assert_eq!(empty().group().to_query_string(), "");
+    }
+
     #[test]
     fn test_all_comparison_operators() {
         assert_eq!(FilterQuery::ne(&"A", 1).to_query_string(), "$filter=A ne 1");
@@ -842,6 +1451,30 @@ mod tests {
         assert_eq!(FilterQuery::le(&"E", 5).to_query_string(), "$filter=E le 5");
     }
 
+    #[test]
+    fn test_range_filter() {
+        let filter = FilterQuery::ge(&"Reading", 50)
+            .and()
+            .le_then(&"Reading", 80.5);
+        assert_eq!(
+            filter.to_query_string(),
+            "$filter=Reading ge 50 and Reading le 80.5"
+        );
+    }
+
+    #[test]
+    fn test_chained_comparison_operators() {
+        let filter = FilterQuery::ne(&"State", "Absent")
+            .and()
+            .ne_then(&"Health", "Critical")
+            .or()
+            .ge_then(&"Count", 1);
+        assert_eq!(
+            filter.to_query_string(),
+            "$filter=State ne 'Absent' and Health ne 'Critical' or Count ge 1"
+        );
+    }
+
     #[test]
     fn test_boolean_literal() {
         let filter = FilterQuery::eq(&"Enabled", true);
@@ -854,12 +1487,138 @@ mod tests {
         assert_eq!(filter.to_query_string(), "$filter=Temperature gt 98.6");
     }
 
+    #[test]
+    fn test_date_time_offset_literal() {
+        let since = EdmDateTimeOffset::from(OffsetDateTime::UNIX_EPOCH);
+        let filter = FilterQuery::ge(&"Created", since);
+        assert_eq!(
+            filter.to_query_string(),
+            "$filter=Created ge 1970-01-01T00:00:00Z"
+        );
+    }
+
     #[test]
     fn test_string_escaping() {
         let filter = FilterQuery::eq(&"Name", "O'Brien");
         assert_eq!(filter.to_query_string(), "$filter=Name eq 'O''Brien'");
     }
 
+    #[test]
+    fn test_string_functions() {
+        assert_eq!(
+            FilterQuery::contains(&"Model", "DGX").to_query_string(),
+            "$filter=contains(Model,'DGX')"
+        );
+        assert_eq!(
+            FilterQuery::startswith(&"Name", "GPU").to_query_string(),
+            "$filter=startswith(Name,'GPU')"
+        );
+        assert_eq!(
+            FilterQuery::endswith(&"Status/Health", "OK").to_query_string(),
+            "$filter=endswith(Status/Health,'OK')"
+        );
+    }
+
+    #[test]
+    fn test_string_function_escaping() {
+        let filter = FilterQuery::contains(&"Description", "O'Brien's");
+        assert_eq!(
+            filter.to_query_string(),
+            "$filter=contains(Description,'O''Brien''s')"
+        );
+    }
+
+    #[test]
+    fn test_chained_string_functions() {
+        let filter = FilterQuery::eq(&"SystemType", "Physical")
+            .and()
+            .contains_then(&"Model", "DGX")
+            .or()
+            .endswith_then(&"Name", "'0'");
+        assert_eq!(
+            filter.to_query_string(),
+            "$filter=SystemType eq 'Physical' and contains(Model,'DGX') or endswith(Name,'''0''')"
+        );
+    }
+
+    #[test]
+    fn test_any_of() {
+        let filter = FilterQuery::any_of(&"Status/Health", ["Warning", "Critical"]);
+        assert_eq!(
+            filter.to_query_string(),
+            "$filter=(Status/Health eq 'Warning' or Status/Health eq 'Critical')"
+        );
+        let filter = FilterQuery::any_of(&"Status/Health", Vec::<&str>::new());
+        assert_eq!(filter.to_query_string(), "");
+    }
+
+    #[test]
+    fn test_any_of_then() {
+        let filter = FilterQuery::eq(&"Status/State", "Enabled")
+            .and()
+            .any_of_then(&"Status/Health", ["Warning", "Critical"]);
+        assert_eq!(
+            filter.to_query_string(),
+            "$filter=Status/State eq 'Enabled' and (Status/Health eq 'Warning' or Status/Health eq 'Critical')"
+        );
+    }
+
+    #[test]
+    fn test_two_level_grouping() {
+        let inner = FilterQuery::any_of(&"Status/Health", ["Warning", "Critical"])
+            .and()
+            .eq_then(&"Status/State", "Enabled");
+        let filter = FilterQuery::group(inner)
+            .or()
+            .eq_then(&"PowerState", "Off")
+            .group()
+            .and()
+            .eq_then(&"SystemType", "Physical");
+        assert_eq!(
+            filter.to_query_string(),
+            "$filter=(((Status/Health eq 'Warning' or Status/Health eq 'Critical') and Status/State eq 'Enabled') or PowerState eq 'Off') and SystemType eq 'Physical'"
+        );
+    }
+
+    #[test]
+    fn test_encoded_filter_with_spaces_and_quotes() {
+        let filter = FilterQuery::eq(&"Name", "O'Brien & Co")
+            .or()
+            .contains_then(&"Model", "100%+");
+        assert_eq!(
+            filter.to_query_string(),
+            "$filter=Name eq 'O''Brien & Co' or contains(Model,'100%+')"
+        );
+        assert_eq!(
+            filter.to_encoded_query_string(),
+            "$filter=Name%20eq%20%27O%27%27Brien%20%26%20Co%27%20or%20contains(Model,%27100%25%2B%27)"
+        );
+    }
+
+    #[test]
+    fn test_encoded_query_keeps_plain_values() {
+        let expand = ExpandQuery::properties(&["Thermal", "Power"]).levels(2);
+        assert_eq!(expand.to_encoded_query_string(), expand.to_query_string());
+        let select = SelectQuery::properties(&["Status/Health", "PowerState"]);
+        assert_eq!(select.to_encoded_query_string(), select.to_query_string());
+        assert_eq!(
+            FilterQuery::any_of(&"Id", Vec::<&str>::new()).to_encoded_query_string(),
+            ""
+        );
+    }
+
+    #[test]
+    fn test_encoded_page_query() {
+        let page = PageQuery::new()
+            .top(10)
+            .skip(20)
+            .expand(ExpandQuery::links());
+        assert_eq!(
+            page.to_encoded_query_string(),
+            "$expand=~($levels=1)&$top=10&$skip=20"
+        );
+    }
+
     #[test]
     fn test_complex_filter() {
         let filter = FilterQuery::eq(&"ProcessorSummary/Count", 2)
@@ -870,4 +1629,58 @@ mod tests {
             "$filter=ProcessorSummary/Count eq 2 and MemorySummary/TotalSystemMemoryGiB gt 64"
         );
     }
+
+    #[test]
+    fn test_select_single_property() {
+        let select = SelectQuery::property(&"PowerState");
+        assert_eq!(select.to_query_string(), "$select=PowerState");
+    }
+
+    #[test]
+    fn test_select_multiple_properties() {
+        let select = SelectQuery::properties(&["Status", "PowerState"]);
+        assert_eq!(select.to_query_string(), "$select=Status,PowerState");
+
+        let select = SelectQuery::property(&"Id").and_property(&"Name");
+        assert_eq!(select.to_query_string(), "$select=Id,Name");
+    }
+
+    #[test]
+    fn test_select_nested_property() {
+        let select = SelectQuery::properties(&["Status/Health", "Status/State"]);
+        assert_eq!(
+            select.to_query_string(),
+            "$select=Status/Health,Status/State"
+        );
+    }
+
+    #[test]
+    fn test_page_top_and_skip() {
+        let page = PageQuery::new().top(50).skip(100);
+        assert_eq!(page.to_query_string(), "$top=50&$skip=100");
+    }
+
+    #[test]
+    fn test_page_omits_absent_parameters() {
+        assert_eq!(PageQuery::new().to_query_string(), "");
+        assert_eq!(PageQuery::new().top(10).to_query_string(), "$top=10");
+        assert_eq!(PageQuery::new().skip(20).to_query_string(), "$skip=20");
+    }
+
+    #[test]
+    fn test_page_top_is_clamped_to_non_zero() {
+        assert_eq!(PageQuery::new().top(0).to_query_string(), "$top=1");
+    }
+
+    #[test]
+    fn test_page_with_expand() {
+        let page = PageQuery::new()
+            .skip(5)
+            .top(5)
+            .expand(ExpandQuery::property("Members").levels(2));
+        assert_eq!(
+            page.to_query_string(),
+            "$expand=Members($levels=2)&$top=5&$skip=5"
+        );
+    }
 }
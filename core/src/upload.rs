@@ -16,6 +16,7 @@
 use core::pin::Pin;
 use std::error::Error as StdError;
 use std::fmt;
+use std::sync::Arc;
 use std::time::Duration;
 
 use futures_io::AsyncRead;
@@ -27,7 +28,17 @@ pub trait UploadReader: AsyncRead + Send + 'static {}
 
 impl<T> UploadReader for T where T: AsyncRead + Send + 'static {}
 
+/// Callback observing upload progress.
+///
+/// Called with the total number of bytes read from the stream so far.
+pub type UploadProgressFn = Arc<dyn Fn(u64) + Send + Sync>;
+
 /// Named data stream accepted by multipart upload methods.
+///
+/// The data is streamed from `reader` while the request is sent, so
+/// large images are never buffered in memory. With a known
+/// `content_length` the part is sent with that length; otherwise the
+/// request body uses chunked transfer encoding.
 pub struct DataStream<R> {
     /// Multipart filename for this stream.
     pub name: String,
@@ -37,6 +48,9 @@ pub struct DataStream<R> {
 
     /// Known stream length, when available.
     pub content_length: Option<u64>,
+
+    /// Upload progress callback, when requested.
+    pub progress: Option<UploadProgressFn>,
 }
 
 impl<R> DataStream<R> {
@@ -47,6 +61,7 @@ impl<R> DataStream<R> {
             name: name.into(),
             reader,
             content_length: None,
+            progress: None,
         }
     }
 
@@ -56,6 +71,16 @@ impl<R> DataStream<R> {
         self.content_length = Some(content_length);
         self
     }
+
+    /// Observe upload progress.
+    ///
+    /// `progress` is called with the total number of bytes read from
+    /// the stream each time another chunk is passed to the transport.
+    #[must_use]
+    pub fn with_progress(mut self, progress: impl Fn(u64) + Send + Sync + 'static) -> Self {
+        self.progress = Some(Arc::new(progress));
+        self
+    }
 }
 
 /// Streamed body accepted by deprecated raw upload methods.
@@ -41,9 +41,11 @@ use crate::optimizer::Config as OptimizerConfig;
 use crate::Error;
 use clap::Subcommand;
 use std::collections::BTreeMap;
+use std::fs::read_dir;
 use std::fs::write;
 use std::fs::File;
 use std::io::Read as _;
+use std::path::Path;
 use std::path::PathBuf;
 
 /// Default root singleton to compile.
@@ -202,6 +204,37 @@ pub fn process_command(command: &Commands) -> Result<Vec<String>, Error> {
     }
 }
 
+/// Read all `*.xml` CSDL files found in `dir` and its subdirectories.
+///
+/// All documents are treated as the root set, so the returned bundle
+/// is ready for [`SchemaBundle::compile_all`]. Files are read in
+/// lexicographic path order.
+///
+/// # Errors
+///
+/// Returns an error if a directory or file cannot be read, if any
+/// file is not a valid EDMX document, or if the same namespace is
+/// defined in more than one file.
+pub fn read_csdl_dir(dir: &Path) -> Result<SchemaBundle, Error> {
+    let mut csdls = Vec::new();
+    collect_csdl_files(dir, &mut csdls)?;
+    csdls.sort();
+    read_csdls(&[], &csdls)
+}
+
+fn collect_csdl_files(dir: &Path, csdls: &mut Vec<String>) -> Result<(), Error> {
+    let io_error = |err| Error::Io(dir.display().to_string(), err);
+    for entry in read_dir(dir).map_err(io_error)? {
+        let path = entry.map_err(io_error)?.path();
+        if path.is_dir() {
+            collect_csdl_files(&path, csdls)?;
+        } else if path.extension().is_some_and(|ext| ext == "xml") {
+            csdls.push(path.display().to_string());
+        }
+    }
+    Ok(())
+}
+
 fn read_csdls(root_csdls: &[String], resolve_csdls: &[String]) -> Result<SchemaBundle, Error> {
     let csdls = root_csdls
         .iter()
@@ -249,3 +282,68 @@ fn read_csdls(root_csdls: &[String], resolve_csdls: &[String]) -> Result<SchemaB
         },
     })
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::env::temp_dir;
+    use std::fs::create_dir_all;
+    use std::fs::remove_dir_all;
+    use std::process;
+
+    fn edmx(namespace: &str, types: &str) -> String {
+        format!(
+            r#"<edmx:Edmx Version="4.0">
+                 <edmx:DataServices>
+                   <Schema xmlns="http://docs.oasis-open.org/odata/ns/edm" Namespace="{namespace}">
+                     {types}
+                   </Schema>
+                 </edmx:DataServices>
+               </edmx:Edmx>"#
+        )
+    }
+
+    #[test]
+    fn read_csdl_dir_compiles_nested_files() {
+        let dir = temp_dir().join(format!("csdl-dir-{}", process::id()));
+        create_dir_all(dir.join("nested")).expect("temporary directory must be created");
+        write(
+            dir.join("Resource_v1.xml"),
+            edmx(
+                "Resource",
+                r#"<EntityType Name="Resource" Abstract="true"/>
+                   <EntityType Name="ResourceCollection" Abstract="true"/>"#,
+            ),
+        )
+        .expect("schema must be written");
+        write(
+            dir.join("nested/Settings_v1.xml"),
+            edmx(
+                "Settings",
+                r#"<ComplexType Name="Settings"/>
+                   <ComplexType Name="PreferredApplyTime"/>"#,
+            ),
+        )
+        .expect("schema must be written");
+        write(dir.join("nested/README.md"), "not a schema").expect("file must be written");
+
+        let bundle = read_csdl_dir(&dir);
+        remove_dir_all(&dir).expect("temporary directory must be removed");
+
+        let bundle = bundle.expect("directory must be read");
+        assert_eq!(bundle.edmx_docs.len(), 2);
+        assert!(bundle.root_set_threshold.is_none());
+        let compiled = bundle
+            .compile_all(CompilerConfig::default())
+            .expect("schemas must compile");
+        assert_eq!(compiled.complex_types.len(), 2);
+    }
+
+    #[test]
+    fn read_csdl_dir_reports_missing_directory() {
+        let dir = temp_dir().join("csdl-dir-that-does-not-exist");
+        assert!(
+            matches!(read_csdl_dir(&dir), Err(Error::Io(path, _)) if path == dir.display().to_string())
+        );
+    }
+}
@@ -78,7 +78,17 @@ impl<'a> MapBase<'a> for ComplexType<'a> {
     }
 }
 
-impl ComplexType<'_> {
+impl<'a> ComplexType<'a> {
+    /// Whether complex type `name` has no sub-types, i.e. no complex
+    /// type in `compiled` has it as its base.
+    #[must_use]
+    pub fn is_leaf(name: QualifiedName<'a>, compiled: &Compiled<'a>) -> bool {
+        !compiled
+            .complex_types
+            .values()
+            .any(|ct| ct.base == Some(name))
+    }
+
     /// Whether this type own definition warrants an Update struct.
     #[must_use]
     pub fn generates_update(&self) -> bool {
@@ -50,7 +50,7 @@ impl<'a> EntityType<'a> {
     /// Whether this type's own definition warrants an `Update` struct.
     #[must_use]
     pub fn generates_update(&self) -> bool {
-        self.odata.updatable.is_some_and(|v| v.inner().value) || self.is_abstract.into_inner()
+        self.odata.is_updatable() || self.is_abstract.into_inner()
     }
 
     /// Compile an `EntityType` with the specified name, including all
@@ -124,7 +124,7 @@ impl<'a> EntityType<'a> {
     ///
     #[must_use]
     pub fn insertable_member_type(&self) -> Option<QualifiedName<'a>> {
-        if self.odata.insertable.is_some_and(|v| v.inner().value) {
+        if self.odata.is_insertable() {
             self.properties
                 .nav_properties
                 .iter()
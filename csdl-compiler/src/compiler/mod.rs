@@ -77,6 +77,8 @@ pub mod properties;
 pub mod qualified_name;
 /// Compiled Redfish-specific attributes.
 pub mod redfish;
+/// Validation of cross-schema type references.
+pub mod reference;
 /// Index over parsed schemas.
 pub mod schema_index;
 /// Compilation stack.
@@ -146,6 +148,12 @@ pub use qualified_name::QualifiedName;
 #[doc(inline)]
 pub use redfish::Redfish;
 #[doc(inline)]
+pub use reference::TypeReference;
+#[doc(inline)]
+pub use reference::UnresolvedReference;
+#[doc(inline)]
+pub use reference::ValidatedReference;
+#[doc(inline)]
 pub use type_definition::TypeDefinition;
 
 // Trait re-exports
@@ -235,6 +243,20 @@ impl SchemaBundle {
         self.compile_root_set(&root_set, &ctx)
     }
 
+    /// Check that types of all properties and navigation properties
+    /// resolve without compiling schemas.
+    ///
+    /// # Errors
+    ///
+    /// Returns all references to types that cannot be found in the
+    /// bundle.
+    pub fn validate_references(
+        &self,
+    ) -> Result<Vec<ValidatedReference<'_>>, Vec<UnresolvedReference<'_>>> {
+        let schema_index = SchemaIndex::build_unchecked(&self.edmx_docs);
+        reference::validate(&self.edmx_docs, &schema_index)
+    }
+
     /// Compile multiple schemas, resolving all type dependencies.
     ///
     /// The root set includes all entity and complex types.
@@ -534,6 +556,108 @@ mod test {
         );
     }
 
+    #[test]
+    fn compile_bound_action_parameters() {
+        let schema = r#"<edmx:Edmx Version="4.0">
+             <edmx:DataServices>
+               <Schema xmlns="http://docs.oasis-open.org/odata/ns/edm" Namespace="Resource">
+                 <EntityType Name="Resource" Abstract="true"/>
+                 <EntityType Name="ResourceCollection" Abstract="true"/>
+               </Schema>
+               <Schema xmlns="http://docs.oasis-open.org/odata/ns/edm" Namespace="Thing">
+                 <EntityType Name="Thing" BaseType="Resource.Resource">
+                   <Property Name="Actions" Type="Thing.Actions" Nullable="false"/>
+                 </EntityType>
+                 <ComplexType Name="Actions"/>
+                 <Action Name="Reset" IsBound="true">
+                   <Parameter Name="Thing" Type="Thing.Actions"/>
+                   <Parameter Name="ResetType" Type="Edm.String" Nullable="true"/>
+                   <Parameter Name="Force" Type="Edm.Boolean" Nullable="false"/>
+                 </Action>
+               </Schema>
+               <Schema Namespace="Settings">
+                 <ComplexType Name="Settings"/>
+                 <ComplexType Name="PreferredApplyTime"/>
+               </Schema>
+             </edmx:DataServices>
+           </edmx:Edmx>"#;
+        let bundle = SchemaBundle {
+            edmx_docs: vec![Edmx::parse(schema).expect("action schema must be valid")],
+            root_set_threshold: None,
+        };
+        let compiled = bundle
+            .compile_all(Config::default())
+            .expect("action schema must compile");
+        let qtype: QualifiedTypeName = "Thing.Actions".parse().expect("must be parsed");
+        let actions = compiled
+            .actions
+            .get(&(&qtype).into())
+            .expect("actions must be attached to binding type");
+        let action = actions.values().next().expect("action must be compiled");
+        assert_eq!(actions.len(), 1);
+        assert_eq!(action.name.to_string(), "Reset");
+        assert_eq!(action.binding_name.to_string(), "Thing");
+        let params: Vec<_> = action
+            .parameters
+            .iter()
+            .map(|p| (p.name.to_string(), p.nullable.into_inner()))
+            .collect();
+        assert_eq!(
+            params,
+            [("ResetType".into(), true), ("Force".into(), false)]
+        );
+    }
+
+    #[test]
+    fn compile_complex_type_inheritance() {
+        let schema = r#"<edmx:Edmx Version="4.0">
+             <edmx:DataServices>
+               <Schema xmlns="http://docs.oasis-open.org/odata/ns/edm" Namespace="Resource">
+                 <EntityType Name="Resource" Abstract="true"/>
+                 <EntityType Name="ResourceCollection" Abstract="true"/>
+               </Schema>
+               <Schema xmlns="http://docs.oasis-open.org/odata/ns/edm" Namespace="Inherit">
+                 <ComplexType Name="A">
+                   <Property Name="x" Type="Edm.String"/>
+                 </ComplexType>
+                 <ComplexType Name="B" BaseType="Inherit.A">
+                   <Property Name="y" Type="Edm.String"/>
+                 </ComplexType>
+                 <ComplexType Name="C" BaseType="Inherit.B">
+                   <Property Name="z" Type="Edm.String"/>
+                 </ComplexType>
+               </Schema>
+               <Schema Namespace="Settings">
+                 <ComplexType Name="Settings"/>
+                 <ComplexType Name="PreferredApplyTime"/>
+               </Schema>
+             </edmx:DataServices>
+           </edmx:Edmx>"#;
+        let bundle = SchemaBundle {
+            edmx_docs: vec![Edmx::parse(schema).expect("inheritance schema must be valid")],
+            root_set_threshold: None,
+        };
+        let compiled = bundle
+            .compile_all(Config::default())
+            .expect("inheritance schema must compile");
+        let property_names = |name: &str| {
+            let qtype: QualifiedTypeName = name.parse().expect("must be parsed");
+            let mut cur_type = Some((&qtype).into());
+            let mut names = Vec::new();
+            while let Some(t) = cur_type {
+                let ct = compiled
+                    .complex_types
+                    .get(&t)
+                    .expect("base type must be compiled");
+                names.extend(ct.properties.properties.iter().map(|p| p.name.to_string()));
+                cur_type = ct.base;
+            }
+            names
+        };
+        assert_eq!(property_names("Inherit.B"), ["y", "x"]);
+        assert_eq!(property_names("Inherit.C"), ["z", "y", "x"]);
+    }
+
     #[test]
     fn schema_test() {
         let schema = r#"<edmx:Edmx Version="4.0">
@@ -599,4 +723,55 @@ mod test {
             &"The version of the Redfish service."
         );
     }
+
+    #[test]
+    fn validate_references_reports_broken_cross_reference() {
+        let thing = r#"<edmx:Edmx Version="4.0">
+             <edmx:DataServices>
+               <Schema xmlns="http://docs.oasis-open.org/odata/ns/edm" Namespace="Thing">
+                 <EntityType Name="Thing">
+                   <Property Name="Status" Type="Other.Status"/>
+                   <Property Name="Location" Type="Other.Location"/>
+                   <NavigationProperty Name="Parts" Type="Collection(Other.Part)"/>
+                 </EntityType>
+               </Schema>
+             </edmx:DataServices>
+           </edmx:Edmx>"#;
+        let other = r#"<edmx:Edmx Version="4.0">
+             <edmx:DataServices>
+               <Schema xmlns="http://docs.oasis-open.org/odata/ns/edm" Namespace="Other">
+                 <ComplexType Name="Status">
+                   <Property Name="State" Type="Edm.String"/>
+                 </ComplexType>
+                 <EntityType Name="Part"/>
+               </Schema>
+             </edmx:DataServices>
+           </edmx:Edmx>"#;
+        let bundle = SchemaBundle {
+            edmx_docs: vec![
+                Edmx::parse(thing).expect("thing schema must be valid"),
+                Edmx::parse(other).expect("other schema must be valid"),
+            ],
+            root_set_threshold: None,
+        };
+
+        let unresolved = bundle
+            .validate_references()
+            .expect_err("broken reference must be reported");
+        assert_eq!(unresolved.len(), 1);
+        assert_eq!(unresolved[0].schema.to_string(), "Thing");
+        assert_eq!(unresolved[0].owner.to_string(), "Thing.Thing");
+        assert_eq!(unresolved[0].property.to_string(), "Location");
+        assert_eq!(unresolved[0].ptype.to_string(), "Other.Location");
+
+        let bundle = SchemaBundle {
+            edmx_docs: vec![Edmx::parse(other).expect("other schema must be valid")],
+            root_set_threshold: None,
+        };
+        let validated = bundle
+            .validate_references()
+            .expect("all references must be resolved");
+        assert_eq!(validated.len(), 1);
+        assert_eq!(validated[0].ptype.to_string(), "Edm.String");
+    }
 }
@@ -66,6 +66,24 @@ pub struct OData<'a> {
     pub deletable: Option<Deletable<'a>>,
 }
 
+impl Default for OData<'_> {
+    /// No annotations: neither `@odata.id` nor `@odata.type` is
+    /// required and all capabilities are unspecified.
+    fn default() -> Self {
+        Self {
+            must_have_id: MustHaveId::new(false),
+            must_have_type: MustHaveType::new(false),
+            description: None,
+            long_description: None,
+            permissions: None,
+            additional_properties: None,
+            insertable: None,
+            updatable: None,
+            deletable: None,
+        }
+    }
+}
+
 impl<'a> OData<'a> {
     /// Create a new instance from an object that provides `OData` annotations.
     pub fn new(must_have_id: MustHaveId, src: &'a impl ODataAnnotations) -> Self {
@@ -82,6 +100,62 @@ impl<'a> OData<'a> {
         }
     }
 
+    /// Entity type can be created (`Capabilities.InsertRestrictions`
+    /// with `Insertable` set to `true`).
+    ///
+    /// ```
+    /// use nv_redfish_csdl_compiler::compiler::odata::OData;
+    /// use nv_redfish_csdl_compiler::odata::annotations::Capability;
+    /// use nv_redfish_csdl_compiler::odata::annotations::Insertable;
+    ///
+    /// let mut odata = OData::default();
+    /// assert!(!odata.is_insertable());
+    /// odata.insertable = Some(Insertable::new(Capability { value: true, description: None }));
+    /// assert!(odata.is_insertable());
+    /// ```
+    #[must_use]
+    pub fn is_insertable(&self) -> bool {
+        self.insertable.is_some_and(|v| v.inner().value)
+    }
+
+    /// Entity type can be updated (`Capabilities.UpdateRestrictions`
+    /// with `Updatable` set to `true`).
+    ///
+    /// ```
+    /// use nv_redfish_csdl_compiler::compiler::odata::OData;
+    /// use nv_redfish_csdl_compiler::odata::annotations::Capability;
+    /// use nv_redfish_csdl_compiler::odata::annotations::Updatable;
+    ///
+    /// let mut odata = OData::default();
+    /// assert!(!odata.is_updatable());
+    /// odata.updatable = Some(Updatable::new(Capability { value: false, description: None }));
+    /// assert!(!odata.is_updatable());
+    /// odata.updatable = Some(Updatable::new(Capability { value: true, description: None }));
+    /// assert!(odata.is_updatable());
+    /// ```
+    #[must_use]
+    pub fn is_updatable(&self) -> bool {
+        self.updatable.is_some_and(|v| v.inner().value)
+    }
+
+    /// Entity type can be deleted (`Capabilities.DeleteRestrictions`
+    /// with `Deletable` set to `true`).
+    ///
+    /// ```
+    /// use nv_redfish_csdl_compiler::compiler::odata::OData;
+    /// use nv_redfish_csdl_compiler::odata::annotations::Capability;
+    /// use nv_redfish_csdl_compiler::odata::annotations::Deletable;
+    ///
+    /// let mut odata = OData::default();
+    /// assert!(!odata.is_deletable());
+    /// odata.deletable = Some(Deletable::new(Capability { value: true, description: None }));
+    /// assert!(odata.is_deletable());
+    /// ```
+    #[must_use]
+    pub fn is_deletable(&self) -> bool {
+        self.deletable.is_some_and(|v| v.inner().value)
+    }
+
     /// Whether no OData-related attributes are present.
     #[must_use]
     pub const fn is_empty(&self) -> bool {
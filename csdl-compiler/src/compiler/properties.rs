@@ -284,7 +284,7 @@ pub struct Property<'a> {
     /// Attached `OData` annotations.
     pub odata: OData<'a>,
     /// Redfish-specific property annotations.
-    pub redfish: RedfishProperty,
+    pub redfish: RedfishProperty<'a>,
     /// Whether the property is nullable.
     pub nullable: IsNullable,
     /// Redfish specification is not very specific about which
@@ -317,6 +317,9 @@ impl<'a> NavPropertyType<'a> {
 }
 
 /// Navigation property, either expandable or reference.
+// Expandable variant is not boxed to keep it matchable by
+// destructuring patterns.
+#[allow(clippy::large_enum_variant)]
 #[derive(Debug)]
 pub enum NavProperty<'a> {
     /// Expandable navigation property (with known type).
@@ -346,7 +349,7 @@ pub struct NavPropertyExpandable<'a> {
     /// Attached `OData` annotations.
     pub odata: OData<'a>,
     /// Redfish-specific property annotations.
-    pub redfish: RedfishProperty,
+    pub redfish: RedfishProperty<'a>,
     /// Whether the property is nullable.
     pub nullable: IsNullable,
 }
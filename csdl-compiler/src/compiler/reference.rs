@@ -0,0 +1,106 @@
+// SPDX-FileCopyrightText: Copyright (c) 2025 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Validation of cross-schema type references.
+//!
+//! It is a fast pre-check that doesn't compile anything: it only
+//! verifies that types of all properties and navigation properties
+//! can be found in the schema index.
+
+use crate::compiler::schema_index::SchemaIndex;
+use crate::compiler::Namespace;
+use crate::compiler::QualifiedName;
+use crate::edmx::property::PropertyAttrs;
+use crate::edmx::Edmx;
+use crate::edmx::Property;
+use crate::edmx::PropertyName;
+use crate::edmx::Type;
+
+/// Reference from a property to its type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TypeReference<'a> {
+    /// Schema where the property is defined.
+    pub schema: Namespace<'a>,
+    /// Entity or complex type that defines the property.
+    pub owner: QualifiedName<'a>,
+    /// Name of the property.
+    pub property: &'a PropertyName,
+    /// Type referenced by the property.
+    pub ptype: QualifiedName<'a>,
+}
+
+/// Reference that was resolved in the schema index.
+pub type ValidatedReference<'a> = TypeReference<'a>;
+
+/// Reference to a type that cannot be found in the schema index.
+pub type UnresolvedReference<'a> = TypeReference<'a>;
+
+/// Check all property types of entity and complex types defined in
+/// `edmx_docs` against `schema_index`.
+///
+/// # Errors
+///
+/// Returns all unresolved references if any.
+pub fn validate<'a>(
+    edmx_docs: &'a [Edmx],
+    schema_index: &SchemaIndex<'a>,
+) -> Result<Vec<ValidatedReference<'a>>, Vec<UnresolvedReference<'a>>> {
+    let (resolved, unresolved): (Vec<_>, Vec<_>) = edmx_docs
+        .iter()
+        .flat_map(|edmx| edmx.data_services.schemas.iter())
+        .flat_map(|s| {
+            let entity_types = s
+                .entity_types
+                .values()
+                .map(|et| (et.name.inner(), &et.properties));
+            let complex_types = s.types.values().filter_map(|t| {
+                if let Type::ComplexType(ct) = t {
+                    Some((ct.name.inner(), &ct.properties))
+                } else {
+                    None
+                }
+            });
+            entity_types
+                .chain(complex_types)
+                .flat_map(move |(name, properties)| {
+                    let owner = QualifiedName::new(&s.namespace, name);
+                    properties.iter().map(move |p| TypeReference {
+                        schema: Namespace::new(&s.namespace),
+                        owner,
+                        property: &p.name,
+                        ptype: property_type(p),
+                    })
+                })
+        })
+        .partition(|r| is_resolved(r.ptype, schema_index));
+    if unresolved.is_empty() {
+        Ok(resolved)
+    } else {
+        Err(unresolved)
+    }
+}
+
+fn property_type(p: &Property) -> QualifiedName<'_> {
+    match &p.attrs {
+        PropertyAttrs::StructuralProperty(v) => v.ptype.inner().into(),
+        PropertyAttrs::NavigationProperty(v) => v.ptype.inner().into(),
+    }
+}
+
+fn is_resolved(qtype: QualifiedName<'_>, schema_index: &SchemaIndex<'_>) -> bool {
+    qtype.namespace.is_edm()
+        || schema_index.find_type(qtype).is_some()
+        || schema_index.find_entity_type(qtype).is_some()
+}
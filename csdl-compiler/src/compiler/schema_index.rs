@@ -16,6 +16,7 @@
 use crate::compiler::Error;
 use crate::compiler::Namespace;
 use crate::compiler::QualifiedName;
+use crate::edmx::Annotation;
 use crate::edmx::ComplexType;
 use crate::edmx::Edmx;
 use crate::edmx::EntityType;
@@ -33,6 +34,10 @@ pub struct SchemaIndex<'a> {
     /// Mapping from base types to all inherited types. This index is
     /// built for complex and entity types.
     child_map: HashMap<QualifiedName<'a>, Vec<QualifiedName<'a>>>,
+    /// Annotations defined by `edm:Annotations` elements, keyed by
+    /// their target. Targets may live in a different schema than the
+    /// annotations themselves.
+    external_annotations: HashMap<QualifiedName<'a>, Vec<&'a Annotation>>,
 }
 
 impl<'a> SchemaIndex<'a> {
@@ -42,6 +47,20 @@ impl<'a> SchemaIndex<'a> {
     ///
     /// Returns an error if entity or complex type inheritance contains a cycle.
     pub fn build(edmx_docs: &'a [Edmx]) -> Result<Self, Error<'a>> {
+        let (index, base_map) = Self::build_with_base_map(edmx_docs);
+        find_inheritance_cycle(&base_map).map_or(Ok(index), |cycle| Err(Error::CyclicType(cycle)))
+    }
+
+    /// Build an index from the provided documents without checking
+    /// type inheritance for cycles.
+    #[must_use]
+    pub fn build_unchecked(edmx_docs: &'a [Edmx]) -> Self {
+        Self::build_with_base_map(edmx_docs).0
+    }
+
+    fn build_with_base_map(
+        edmx_docs: &'a [Edmx],
+    ) -> (Self, HashMap<QualifiedName<'a>, QualifiedName<'a>>) {
         let index = edmx_docs
             .iter()
             .flat_map(|v| {
@@ -85,9 +104,36 @@ impl<'a> SchemaIndex<'a> {
                 })
             },
         );
-        find_inheritance_cycle(&base_map).map_or(Ok(Self { index, child_map }), |cycle| {
-            Err(Error::CyclicType(cycle))
-        })
+        let external_annotations = edmx_docs
+            .iter()
+            .flat_map(|doc| doc.data_services.schemas.iter())
+            .flat_map(|s| s.external_annotations.iter())
+            .fold(
+                HashMap::<QualifiedName<'a>, Vec<&'a Annotation>>::new(),
+                |mut map, anns| {
+                    map.entry((&anns.target).into())
+                        .or_default()
+                        .extend(anns.annotations.iter());
+                    map
+                },
+            );
+        (
+            Self {
+                index,
+                child_map,
+                external_annotations,
+            },
+            base_map,
+        )
+    }
+
+    /// Find annotations applied to the type by `edm:Annotations`
+    /// elements of any indexed schema.
+    #[must_use]
+    pub fn external_annotations(&self, qtype: QualifiedName<'a>) -> &[&'a Annotation] {
+        self.external_annotations
+            .get(&qtype)
+            .map_or(&[], Vec::as_slice)
     }
 
     /// Find schema by namespace.
@@ -450,6 +496,51 @@ mod test {
         );
     }
 
+    #[test]
+    fn external_annotations_from_other_schema() {
+        let schemas = [
+            r#"<edmx:Edmx Version="4.0">
+             <edmx:DataServices>
+               <Schema Namespace="Base.v1_0_0">
+                 <ComplexType Name="Status"/>
+               </Schema>
+             </edmx:DataServices>
+           </edmx:Edmx>"#,
+            r#"<edmx:Edmx Version="4.0">
+             <edmx:DataServices>
+               <Schema Namespace="Ext.v1_0_0">
+                 <Annotations Target="Base.v1_0_0.Status">
+                   <Annotation Term="OData.Description" String="Status of the resource."/>
+                   <Annotation Term="OData.AdditionalProperties" Bool="false"/>
+                 </Annotations>
+               </Schema>
+             </edmx:DataServices>
+           </edmx:Edmx>"#,
+        ]
+        .iter()
+        .map(|s| Edmx::parse(s))
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap();
+
+        let index = SchemaIndex::build(&schemas).expect("acyclic schemas must be indexed");
+        let ns = "Base.v1_0_0".parse().unwrap();
+        let status: SimpleIdentifier = "Status".parse().unwrap();
+        let anns = index.external_annotations(QualifiedName::new(&ns, &status));
+        assert_eq!(
+            anns.iter()
+                .map(|a| QualifiedName::from(&a.term).to_string())
+                .collect::<Vec<_>>(),
+            ["OData.Description", "OData.AdditionalProperties"]
+        );
+        assert_eq!(anns[0].string.as_deref(), Some("Status of the resource."));
+        assert_eq!(anns[1].bool_value, Some(false));
+
+        let other: SimpleIdentifier = "Other".parse().unwrap();
+        assert!(index
+            .external_annotations(QualifiedName::new(&ns, &other))
+            .is_empty());
+    }
+
     #[test]
     fn schema_index_test() {
         let schemas = [
@@ -47,6 +47,20 @@ pub struct Annotation {
     pub record: Option<AnnotationRecord>,
 }
 
+/// 14.2 Element edm:Annotations
+///
+/// Applies annotations to a model element defined elsewhere, possibly
+/// in another schema.
+#[derive(Debug, Deserialize)]
+pub struct Annotations {
+    /// 14.2.1 Attribute Target
+    #[serde(rename = "@Target")]
+    pub target: QualifiedTypeName,
+    /// Annotations applied to the target.
+    #[serde(rename = "Annotation", default)]
+    pub annotations: Vec<Annotation>,
+}
+
 #[derive(Debug, Deserialize)]
 pub struct AnnotationCollection {
     #[serde(rename = "String", default)]
@@ -67,6 +67,8 @@ pub use annotation::Annotation;
 #[doc(inline)]
 pub use annotation::AnnotationRecord;
 #[doc(inline)]
+pub use annotation::Annotations;
+#[doc(inline)]
 pub use attribute_values::Namespace;
 #[doc(inline)]
 pub use attribute_values::QualifiedName;
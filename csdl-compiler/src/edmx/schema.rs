@@ -19,6 +19,7 @@ use crate::edmx::entity_type::DeEntityType;
 use crate::edmx::enum_type::DeEnumType;
 use crate::edmx::Action;
 use crate::edmx::Annotation;
+use crate::edmx::Annotations;
 use crate::edmx::ComplexType;
 use crate::edmx::EntityContainer;
 use crate::edmx::EntityType;
@@ -54,6 +55,7 @@ pub enum DeSchemaItem {
     EntityContainer(EntityContainer),
     Term(Term),
     Annotation(Annotation),
+    Annotations(Annotations),
     Action(DeAction),
 }
 
@@ -74,6 +76,8 @@ pub struct Schema {
     pub entity_container: Option<EntityContainer>,
     pub actions: Vec<Action>,
     pub annotations: Vec<Annotation>,
+    /// External annotations targeting elements of this or other schemas.
+    pub external_annotations: Vec<Annotations>,
 }
 
 impl DeSchema {
@@ -81,48 +85,57 @@ impl DeSchema {
     ///
     /// Returns error if any of items failed to validate.
     pub fn validate(self) -> Result<Schema, ValidateError> {
-        let (types, entity_types, annotations, terms, actions, mut entity_containers) =
-            self.items.into_iter().fold(
-                (
-                    Vec::new(),
-                    Vec::new(),
-                    Vec::new(),
-                    Vec::new(),
-                    Vec::new(),
-                    Vec::new(),
-                ),
-                |(mut ts, mut ets, mut anns, mut terms, mut acts, mut ecs), v| {
-                    match v {
-                        DeSchemaItem::EntityType(v) => {
-                            ets.push(v.validate().map(|v| (v.name.clone().into_inner(), v)));
-                        }
-                        DeSchemaItem::ComplexType(v) => {
-                            ts.push(
-                                v.validate()
-                                    .map(|v| (v.name.clone().into_inner(), Type::ComplexType(v))),
-                            );
-                        }
-                        DeSchemaItem::EnumType(v) => {
-                            ts.push(
-                                v.validate()
-                                    .map(|v| (v.name.clone().into_inner(), Type::EnumType(v))),
-                            );
-                        }
-                        DeSchemaItem::TypeDefinition(v) => {
-                            ts.push(Ok((v.name.clone().into_inner(), Type::TypeDefinition(v))));
-                        }
-                        DeSchemaItem::EntityContainer(v) => {
-                            ecs.push(v);
-                        }
-                        DeSchemaItem::Term(v) => {
-                            terms.push(Ok((v.name.clone().into_inner(), v)));
-                        }
-                        DeSchemaItem::Annotation(v) => anns.push(v),
-                        DeSchemaItem::Action(v) => acts.push(v.validate()),
+        let (
+            types,
+            entity_types,
+            annotations,
+            external_annotations,
+            terms,
+            actions,
+            mut entity_containers,
+        ) = self.items.into_iter().fold(
+            (
+                Vec::new(),
+                Vec::new(),
+                Vec::new(),
+                Vec::new(),
+                Vec::new(),
+                Vec::new(),
+                Vec::new(),
+            ),
+            |(mut ts, mut ets, mut anns, mut ext_anns, mut terms, mut acts, mut ecs), v| {
+                match v {
+                    DeSchemaItem::EntityType(v) => {
+                        ets.push(v.validate().map(|v| (v.name.clone().into_inner(), v)));
+                    }
+                    DeSchemaItem::ComplexType(v) => {
+                        ts.push(
+                            v.validate()
+                                .map(|v| (v.name.clone().into_inner(), Type::ComplexType(v))),
+                        );
+                    }
+                    DeSchemaItem::EnumType(v) => {
+                        ts.push(
+                            v.validate()
+                                .map(|v| (v.name.clone().into_inner(), Type::EnumType(v))),
+                        );
+                    }
+                    DeSchemaItem::TypeDefinition(v) => {
+                        ts.push(Ok((v.name.clone().into_inner(), Type::TypeDefinition(v))));
+                    }
+                    DeSchemaItem::EntityContainer(v) => {
+                        ecs.push(v);
+                    }
+                    DeSchemaItem::Term(v) => {
+                        terms.push(Ok((v.name.clone().into_inner(), v)));
                     }
-                    (ts, ets, anns, terms, acts, ecs)
-                },
-            );
+                    DeSchemaItem::Annotation(v) => anns.push(v),
+                    DeSchemaItem::Annotations(v) => ext_anns.push(v),
+                    DeSchemaItem::Action(v) => acts.push(v.validate()),
+                }
+                (ts, ets, anns, ext_anns, terms, acts, ecs)
+            },
+        );
         let namespace = self.namespace;
         let types = types
             .into_iter()
@@ -160,6 +173,7 @@ impl DeSchema {
             entity_container,
             actions,
             annotations,
+            external_annotations,
         })
     }
 }
@@ -42,6 +42,7 @@ impl EnumDef<'_> {
         let top = &config.top_module_alias;
         let mut members_content = TokenStream::new();
         let mut snake_case_match_arms = TokenStream::new();
+        let mut display_match_arms = TokenStream::new();
 
         for m in self.compiled.members {
             let rename = Literal::string(m.name.inner().inner());
@@ -61,6 +62,9 @@ impl EnumDef<'_> {
             snake_case_match_arms.extend(quote! {
                 Self::#member_name => #snake_case_literal,
             });
+            display_match_arms.extend(quote! {
+                Self::#member_name => #rename,
+            });
         }
         members_content.extend(quote! {
             #[doc = " Fallback value for values that are not supported by current version of Redfish schema."]
@@ -70,6 +74,9 @@ impl EnumDef<'_> {
         snake_case_match_arms.extend(quote! {
             Self::UnsupportedValue => "unsupported_value",
         });
+        display_match_arms.extend(quote! {
+            Self::UnsupportedValue => "UnsupportedValue",
+        });
         tokens.extend([
             doc_format_and_generate(self.name, &self.compiled.odata),
             quote! {
@@ -88,6 +95,14 @@ impl EnumDef<'_> {
                     }
                 }
             }
+
+            impl core::fmt::Display for #name {
+                fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                    f.write_str(match self {
+                        #display_match_arms
+                    })
+                }
+            }
         });
     }
 }
@@ -107,3 +122,62 @@ impl ToTokens for EnumMemberName<'_> {
         tokens.append(ident::escaped(&casemungler::to_camel(self.0)));
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::EnumDef;
+    use crate::compiler::Config as CompilerConfig;
+    use crate::compiler::SchemaBundle;
+    use crate::edmx::Edmx;
+    use crate::generator::rust::Config;
+    use crate::generator::rust::TypeName;
+    use proc_macro2::TokenStream;
+
+    #[test]
+    fn enum_implements_display() {
+        let schema = r#"<edmx:Edmx Version="4.0">
+             <edmx:DataServices>
+               <Schema xmlns="http://docs.oasis-open.org/odata/ns/edm" Namespace="Resource">
+                 <EntityType Name="Resource" Abstract="true"/>
+                 <EntityType Name="ResourceCollection" Abstract="true"/>
+               </Schema>
+               <Schema xmlns="http://docs.oasis-open.org/odata/ns/edm" Namespace="Boot">
+                 <EnumType Name="BootSource">
+                   <Member Name="Pxe"/>
+                   <Member Name="UefiHttp"/>
+                 </EnumType>
+                 <ComplexType Name="Boot">
+                   <Property Name="BootSourceOverrideTarget" Type="Boot.BootSource"/>
+                 </ComplexType>
+               </Schema>
+               <Schema Namespace="Settings">
+                 <ComplexType Name="Settings"/>
+                 <ComplexType Name="PreferredApplyTime"/>
+               </Schema>
+             </edmx:DataServices>
+           </edmx:Edmx>"#;
+        let bundle = SchemaBundle {
+            edmx_docs: vec![Edmx::parse(schema).expect("enum schema must be valid")],
+            root_set_threshold: None,
+        };
+        let mut compiled = bundle
+            .compile_all(CompilerConfig::default())
+            .expect("enum schema must compile");
+        let (qtype, compiled) = compiled
+            .enum_types
+            .drain()
+            .next()
+            .expect("enum must be compiled");
+        let mut tokens = TokenStream::new();
+        EnumDef {
+            name: TypeName::new_qualified(qtype.name),
+            compiled,
+        }
+        .generate(&mut tokens, &Config::default());
+
+        let generated = tokens.to_string();
+        assert!(generated.contains("impl core :: fmt :: Display for BootSource"));
+        assert!(generated.contains(r#"Self :: UefiHttp => "UefiHttp""#));
+        assert!(generated.contains(r#"Self :: UnsupportedValue => "UnsupportedValue""#));
+    }
+}
@@ -34,6 +34,7 @@ use crate::generator::rust::SerializableProperties;
 use crate::generator::rust::StructFieldName;
 use crate::generator::rust::TypeName;
 use crate::odata::annotations::Permissions;
+use crate::redfish::Deprecated;
 use crate::redfish::DynamicProperties;
 use crate::redfish::ExcerptCopy;
 use crate::IsNullable;
@@ -234,6 +235,7 @@ impl<'a> StructDef<'a> {
         });
 
         if impl_type != ImplType::None {
+            self.generate_redfish_settings_accessor(tokens, impl_type, config);
             self.generate_entity_type_traits(tokens, impl_type, config);
         }
 
@@ -534,13 +536,25 @@ impl<'a> StructDef<'a> {
             p.redfish.is_required,
             p.rigid_array_support,
         );
+        let deprecated = Self::gen_deprecated_attr(p.redfish.deprecated);
         let name = StructFieldName::new_property(p.name);
         quote! {
-            #doc #serde
+            #doc #deprecated #serde
             pub #name: #field_type,
         }
     }
 
+    fn gen_deprecated_attr(deprecated: Option<Deprecated<'_>>) -> TokenStream {
+        match deprecated {
+            Some(Deprecated { note: Some(note) }) => {
+                let note = Literal::string(note);
+                quote! { #[deprecated(note = #note)] }
+            }
+            Some(Deprecated { note: None }) => quote! { #[deprecated] },
+            None => TokenStream::new(),
+        }
+    }
+
     // Returns serde annotation and field type token streams.
     fn gen_de_struct_field<T>(
         cardinality: &OneOrCollection<T>,
@@ -627,6 +641,8 @@ impl<'a> StructDef<'a> {
                     return TokenStream::new();
                 }
                 let doc = doc_format_and_generate(p.ptype.name(), &p.odata);
+                let deprecated = Self::gen_deprecated_attr(p.redfish.deprecated);
+                let doc = quote! { #doc #deprecated };
                 let ptype = p.redfish.excerpt_copy.as_ref().map_or_else(
                     || {
                         let full_type = FullTypeName::new(p.ptype.name(), config);
@@ -783,6 +799,30 @@ impl<'a> StructDef<'a> {
         }
     }
 
+    // Access to `@Redfish.Settings` annotation that is defined only
+    // in the root of the type hierarchy.
+    fn generate_redfish_settings_accessor(
+        &self,
+        tokens: &mut TokenStream,
+        impl_type: ImplType,
+        config: &Config,
+    ) {
+        let name = self.name;
+        let top = &config.top_module_alias;
+        let fn_impl = match impl_type {
+            ImplType::Root => quote! { self.redfish_settings.as_ref() },
+            ImplType::Child => quote! { self.base.redfish_settings() },
+            ImplType::None => return,
+        };
+        tokens.extend(quote! {
+            impl #name {
+                #[doc = "Value of `@Redfish.Settings` annotation."]
+                #[inline]
+                pub fn redfish_settings(&self) -> Option<&#top::settings::Settings> { #fn_impl }
+            }
+        });
+    }
+
     fn generate_entity_type_traits(
         &self,
         tokens: &mut TokenStream,
@@ -810,7 +850,7 @@ impl<'a> StructDef<'a> {
         };
 
         let update_name = self.name.for_update(None);
-        if self.odata.updatable.is_some_and(|v| v.inner().value) {
+        if self.odata.is_updatable() {
             tokens.extend(quote! {
                 impl #top::Updatable<#update_name> for #name {}
             });
@@ -823,7 +863,7 @@ impl<'a> StructDef<'a> {
             });
         }
 
-        if self.odata.deletable.is_some_and(|v| v.inner().value) {
+        if self.odata.is_deletable() {
             tokens.extend(quote! {
                 impl #top::Deletable for #name {}
             });
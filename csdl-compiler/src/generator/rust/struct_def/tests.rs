@@ -1,4 +1,12 @@
 use super::StructDef;
+use crate::compiler::Config as CompilerConfig;
+use crate::compiler::RigidArraySupport;
+use crate::compiler::SchemaBundle;
+use crate::edmx::Edmx;
+use crate::edmx::QualifiedTypeName;
+use crate::generator::rust::Config;
+use crate::generator::rust::FullTypeName;
+use crate::redfish::Deprecated;
 use crate::IsNullable;
 use crate::IsRequired;
 use crate::OneOrCollection;
@@ -119,6 +127,117 @@ fn action_parameter_field_generation_combinations() {
     }
 }
 
+#[test]
+fn deprecated_attribute_generation() {
+    let note = String::from("This property has been deprecated in favor of Foo.");
+    let cases = [
+        ("not deprecated", None, quote! {}),
+        (
+            "deprecated without note",
+            Some(Deprecated { note: None }),
+            quote! { #[deprecated] },
+        ),
+        (
+            "deprecated with note",
+            Some(Deprecated { note: Some(&note) }),
+            quote! { #[deprecated(note = "This property has been deprecated in favor of Foo.")] },
+        ),
+    ];
+
+    for (name, deprecated, expected) in cases {
+        assert_token_eq(
+            &StructDef::gen_deprecated_attr(deprecated),
+            &expected,
+            name,
+            "deprecated attribute",
+        );
+    }
+}
+
+#[test]
+fn edm_time_types_map_to_core_types() {
+    let config = Config::default();
+    let cases = [
+        (
+            "Edm.DateTimeOffset",
+            false,
+            quote! { redfish::edm::DateTimeOffset },
+        ),
+        (
+            "Edm.DateTimeOffset",
+            true,
+            quote! { Option<redfish::edm::DateTimeOffset> },
+        ),
+        ("Edm.Duration", false, quote! { redfish::edm::Duration }),
+        (
+            "Edm.Duration",
+            true,
+            quote! { Option<redfish::edm::Duration> },
+        ),
+    ];
+
+    for (name, nullable, expected) in cases {
+        let qtype: QualifiedTypeName = name.parse().expect("must be parsed");
+        let (_, field_type) = StructDef::gen_de_struct_field(
+            &OneOrCollection::One(()),
+            FullTypeName::new((&qtype).into(), &config),
+            Literal::string("TestProperty"),
+            IsNullable::new(nullable),
+            IsRequired::new(true),
+            RigidArraySupport::new(false),
+        );
+        // Type path separators are emitted as joint puncts, so compare
+        // without whitespace.
+        assert_eq!(
+            field_type.to_string().replace(' ', ""),
+            expected.to_string().replace(' ', ""),
+            "{name}: field type"
+        );
+    }
+}
+
+#[test]
+fn property_field_is_renamed_to_csdl_name() {
+    const SCHEMA: &str = r#"<edmx:Edmx Version="4.0">
+         <edmx:DataServices>
+           <Schema xmlns="http://docs.oasis-open.org/odata/ns/edm" Namespace="Resource">
+             <EntityType Name="Resource" Abstract="true"/>
+             <EntityType Name="ResourceCollection" Abstract="true"/>
+           </Schema>
+           <Schema xmlns="http://docs.oasis-open.org/odata/ns/edm" Namespace="Thing">
+             <ComplexType Name="Chassis">
+               <Property Name="PCIeDevices" Type="Edm.String"/>
+             </ComplexType>
+           </Schema>
+           <Schema Namespace="Settings">
+             <ComplexType Name="Settings"/>
+             <ComplexType Name="PreferredApplyTime"/>
+           </Schema>
+         </edmx:DataServices>
+       </edmx:Edmx>"#;
+
+    let bundle = SchemaBundle {
+        edmx_docs: vec![Edmx::parse(SCHEMA).expect("schema must be valid")],
+        root_set_threshold: None,
+    };
+    let compiled = bundle
+        .compile_all(CompilerConfig::default())
+        .expect("schema must compile");
+    let qtype: QualifiedTypeName = "Thing.Chassis".parse().expect("must be parsed");
+    let property = compiled
+        .complex_types
+        .get(&(&qtype).into())
+        .expect("complex type must be compiled")
+        .properties
+        .properties
+        .first()
+        .expect("property must be compiled");
+
+    let field = StructDef::generate_property(property, &Config::default()).to_string();
+    assert!(field.contains(r#"rename = "PCIeDevices""#));
+    assert!(field.contains("pub pcie_devices :"));
+}
+
 fn assert_token_eq(actual: &TokenStream, expected: &TokenStream, case: &str, field: &str) {
     assert_eq!(actual.to_string(), expected.to_string(), "{case}: {field}");
 }
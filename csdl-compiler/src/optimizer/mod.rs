@@ -21,6 +21,7 @@
 //! data structure and produce new, optimized one.
 
 mod prune_complex_type_inheritance;
+mod prune_deprecated_properties;
 mod prune_entity_type_inheritance;
 mod prune_namespaces;
 mod remove_empty_complex_types;
@@ -32,6 +33,7 @@ use crate::compiler::MapType as _;
 use crate::compiler::QualifiedName;
 use crate::compiler::TypeActions;
 use prune_complex_type_inheritance::prune_complex_type_inheritance;
+use prune_deprecated_properties::prune_deprecated_properties;
 use prune_entity_type_inheritance::prune_entity_type_inheritance;
 use prune_namespaces::prune_namespaces;
 use remove_empty_complex_types::remove_empty_complex_types;
@@ -40,6 +42,11 @@ use std::collections::HashMap;
 
 pub struct Config {
     pub never_prune: EntityTypeFilter,
+    /// Remove properties annotated with `Redfish.Deprecated`.
+    pub prune_deprecated: bool,
+    /// Remove empty abstract complex types that only serve as bases
+    /// of other complex types.
+    pub remove_abstract_wrappers: bool,
 }
 
 impl Default for Config {
@@ -51,6 +58,8 @@ impl Default for Config {
                     .map(|f| f.parse().expect("must be correct filter"))
                     .collect(),
             ),
+            prune_deprecated: false,
+            remove_abstract_wrappers: false,
         }
     }
 }
@@ -64,6 +73,7 @@ pub fn optimize<'a>(input: Compiled<'a>, config: &Config) -> Compiled<'a> {
         prune_complex_type_inheritance,
         prune_entity_type_inheritance,
         prune_namespaces,
+        prune_deprecated_properties,
     ]
     .iter()
     .fold(input, |input, f| f(input, config))
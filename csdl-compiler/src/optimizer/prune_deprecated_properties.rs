@@ -0,0 +1,123 @@
+// SPDX-FileCopyrightText: Copyright (c) 2026 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Prune deprecated properties optimization.
+//!
+//! Redfish marks properties that are kept only for backward
+//! compatibility with `Redfish.Deprecated`. When requested by
+//! configuration such properties are removed from complex and entity
+//! types so no code is generated for them.
+
+use crate::compiler::Compiled;
+use crate::compiler::Properties;
+use crate::optimizer::Config;
+
+pub fn prune_deprecated_properties<'a>(input: Compiled<'a>, config: &Config) -> Compiled<'a> {
+    if !config.prune_deprecated {
+        return input;
+    }
+    Compiled {
+        complex_types: input
+            .complex_types
+            .into_iter()
+            .map(|(name, mut v)| {
+                v.properties = prune(v.properties);
+                (name, v)
+            })
+            .collect(),
+        entity_types: input
+            .entity_types
+            .into_iter()
+            .map(|(name, mut v)| {
+                v.properties = prune(v.properties);
+                (name, v)
+            })
+            .collect(),
+        ..input
+    }
+}
+
+fn prune(mut properties: Properties<'_>) -> Properties<'_> {
+    properties
+        .properties
+        .retain(|p| p.redfish.deprecated.is_none());
+    properties
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::compiler::Config as CompilerConfig;
+    use crate::compiler::SchemaBundle;
+    use crate::edmx::Edmx;
+    use crate::edmx::QualifiedTypeName;
+    use crate::optimizer::optimize;
+
+    const SCHEMA: &str = r#"<edmx:Edmx Version="4.0">
+         <edmx:DataServices>
+           <Schema xmlns="http://docs.oasis-open.org/odata/ns/edm" Namespace="Resource">
+             <EntityType Name="Resource" Abstract="true"/>
+             <EntityType Name="ResourceCollection" Abstract="true"/>
+           </Schema>
+           <Schema xmlns="http://docs.oasis-open.org/odata/ns/edm" Namespace="Thing">
+             <ComplexType Name="Status">
+               <Property Name="State" Type="Edm.String"/>
+               <Property Name="Health" Type="Edm.String">
+                 <Annotation Term="Redfish.Deprecated" String="Use State instead."/>
+               </Property>
+             </ComplexType>
+           </Schema>
+           <Schema Namespace="Settings">
+             <ComplexType Name="Settings"/>
+             <ComplexType Name="PreferredApplyTime"/>
+           </Schema>
+         </edmx:DataServices>
+       </edmx:Edmx>"#;
+
+    fn property_names(config: &Config) -> Vec<String> {
+        let bundle = SchemaBundle {
+            edmx_docs: vec![Edmx::parse(SCHEMA).expect("schema must be valid")],
+            root_set_threshold: None,
+        };
+        let compiled = bundle
+            .compile_all(CompilerConfig::default())
+            .expect("schema must compile");
+        let compiled = optimize(compiled, config);
+        let qtype: QualifiedTypeName = "Thing.Status".parse().expect("must be parsed");
+        compiled
+            .complex_types
+            .get(&(&qtype).into())
+            .expect("complex type must be compiled")
+            .properties
+            .properties
+            .iter()
+            .map(|p| p.name.to_string())
+            .collect()
+    }
+
+    #[test]
+    fn deprecated_properties_are_kept_by_default() {
+        assert_eq!(property_names(&Config::default()), ["State", "Health"]);
+    }
+
+    #[test]
+    fn deprecated_properties_are_pruned_when_enabled() {
+        let config = Config {
+            prune_deprecated: true,
+            ..Config::default()
+        };
+        assert_eq!(property_names(&config), ["State"]);
+    }
+}
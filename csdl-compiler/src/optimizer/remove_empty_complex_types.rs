@@ -18,11 +18,18 @@
 //! Compiler can remove complex types that doesn't have any properties
 //! and navigation properties. Redfish schema introduces plenty of
 //! such types. They are definitely not needed for code generation.
+//!
+//! If `remove_abstract_wrappers` is enabled, empty types without
+//! non-empty parent are removed when they are abstract wrappers:
+//! abstract types that have sub-types and are not used as type of any
+//! property or action parameter. Sub-types of such wrappers are
+//! rebased to the wrapper's own base.
 
 use crate::compiler::Compiled;
 use crate::compiler::ComplexType;
 use crate::compiler::MapBase as _;
 use crate::compiler::MapType as _;
+use crate::compiler::ParameterType;
 use crate::compiler::PropertiesManipulation as _;
 use crate::compiler::Property;
 use crate::compiler::QualifiedName;
@@ -30,25 +37,37 @@ use crate::optimizer::map_types_in_actions;
 use crate::optimizer::replace;
 use crate::optimizer::Config;
 use std::collections::HashMap;
+use std::collections::HashSet;
 
 type Replacements<'a> = HashMap<QualifiedName<'a>, QualifiedName<'a>>;
 
-pub fn remove_empty_complex_types<'a>(input: Compiled<'a>, _config: &Config) -> Compiled<'a> {
+pub fn remove_empty_complex_types<'a>(input: Compiled<'a>, config: &Config) -> Compiled<'a> {
     let ct_replacements = collect_ct_replacements(&input);
+    let wrappers = if config.remove_abstract_wrappers {
+        collect_abstract_wrappers(&input, &ct_replacements)
+    } else {
+        HashSet::new()
+    };
+    let wrapper_bases = wrappers
+        .iter()
+        .map(|name| (*name, wrapper_base(&input, &wrappers, *name)))
+        .collect::<HashMap<_, _>>();
     let map_prop = |p: Property<'a>| p.map_type(|t| replace(&t, &ct_replacements));
     Compiled {
         complex_types: input
             .complex_types
             .into_iter()
             .filter_map(|(name, v)| {
-                if ct_replacements.contains_key(&name) {
+                if ct_replacements.contains_key(&name) || wrappers.contains(&name) {
                     None
                 } else {
-                    Some((
-                        name,
-                        v.map_properties(map_prop)
-                            .map_base(|base| replace(&base, &ct_replacements)),
-                    ))
+                    let mut v = v
+                        .map_properties(map_prop)
+                        .map_base(|base| replace(&base, &ct_replacements));
+                    if let Some(base) = v.base.and_then(|base| wrapper_bases.get(&base)) {
+                        v.base = *base;
+                    }
+                    Some((name, v))
                 }
             })
             .collect(),
@@ -83,6 +102,63 @@ fn collect_ct_replacements<'a>(input: &Compiled<'a>) -> Replacements<'a> {
         .collect()
 }
 
+/// Empty abstract types that have sub-types, no non-empty parent and
+/// are not used as a type anywhere.
+fn collect_abstract_wrappers<'a>(
+    input: &Compiled<'a>,
+    ct_replacements: &Replacements<'a>,
+) -> HashSet<QualifiedName<'a>> {
+    let used = used_types(input);
+    input
+        .complex_types
+        .values()
+        .filter(|v| {
+            ct_is_empty(v)
+                && v.is_abstract.into_inner()
+                && !ct_replacements.contains_key(&v.name)
+                && !used.contains(&v.name)
+                && !ComplexType::is_leaf(v.name, input)
+        })
+        .map(|v| v.name)
+        .collect()
+}
+
+/// Closest base of the wrapper that is not a wrapper itself.
+fn wrapper_base<'a>(
+    input: &Compiled<'a>,
+    wrappers: &HashSet<QualifiedName<'a>>,
+    mut qname: QualifiedName<'a>,
+) -> Option<QualifiedName<'a>> {
+    while wrappers.contains(&qname) {
+        qname = input.complex_types.get(&qname)?.base?;
+    }
+    Some(qname)
+}
+
+fn used_types<'a>(input: &Compiled<'a>) -> HashSet<QualifiedName<'a>> {
+    let ct_props = input.complex_types.values().map(|v| &v.properties);
+    let et_props = input.entity_types.values().map(|v| &v.properties);
+    let props = ct_props
+        .chain(et_props)
+        .flat_map(|v| v.properties.iter().map(|p| p.ptype.name()));
+    let params = input
+        .actions
+        .values()
+        .flat_map(|actions| actions.values())
+        .flat_map(|action| {
+            let ret = action.return_type.as_ref().map(|t| *t.inner());
+            action
+                .parameters
+                .iter()
+                .filter_map(|p| match p.ptype {
+                    ParameterType::Type(t) => Some(t.name()),
+                    ParameterType::Entity(_) => None,
+                })
+                .chain(ret)
+        });
+    props.chain(params).collect()
+}
+
 fn find_non_empty_parent<'a>(
     input: &Compiled<'a>,
     mut qname: QualifiedName<'a>,
@@ -99,3 +175,144 @@ fn find_non_empty_parent<'a>(
     }
     None
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::compiler::Config as CompilerConfig;
+    use crate::compiler::SchemaBundle;
+    use crate::edmx::Edmx;
+    use crate::edmx::QualifiedTypeName;
+    use crate::generator::rust::Config as GeneratorConfig;
+    use crate::generator::rust::RustGenerator;
+
+    const SCHEMA: &str = r#"<edmx:Edmx Version="4.0">
+         <edmx:DataServices>
+           <Schema xmlns="http://docs.oasis-open.org/odata/ns/edm" Namespace="Resource">
+             <EntityType Name="Resource" Abstract="true"/>
+             <EntityType Name="ResourceCollection" Abstract="true"/>
+           </Schema>
+           <Schema Namespace="Settings">
+             <ComplexType Name="Settings"/>
+             <ComplexType Name="PreferredApplyTime"/>
+           </Schema>
+           <Schema xmlns="http://docs.oasis-open.org/odata/ns/edm" Namespace="Thing">
+             <EntityType Name="Thing">
+               <Property Name="Leaf" Type="Thing.Leaf" Nullable="false"/>
+             </EntityType>
+             <ComplexType Name="Base" Abstract="true"/>
+             <ComplexType Name="Middle" BaseType="Thing.Base" Abstract="true"/>
+             <ComplexType Name="Leaf" BaseType="Thing.Middle">
+               <Property Name="Value" Type="Edm.String"/>
+             </ComplexType>
+           </Schema>
+         </edmx:DataServices>
+       </edmx:Edmx>"#;
+
+    fn qname(name: &str) -> QualifiedTypeName {
+        name.parse().expect("must be parsed")
+    }
+
+    fn parse_schema() -> SchemaBundle {
+        SchemaBundle {
+            edmx_docs: vec![Edmx::parse(SCHEMA).expect("schema must be valid")],
+            root_set_threshold: None,
+        }
+    }
+
+    fn remove_wrappers() -> Config {
+        Config {
+            remove_abstract_wrappers: true,
+            ..Config::default()
+        }
+    }
+
+    fn generated_structs(config: &Config) -> Vec<String> {
+        let bundle = parse_schema();
+        let compiled = bundle
+            .compile_all(CompilerConfig::default())
+            .expect("schema must compile");
+        let compiled = remove_empty_complex_types(compiled, config);
+        let generated = RustGenerator::new(compiled, GeneratorConfig::default())
+            .map_err(|err| err.to_string())
+            .expect("code must be generated")
+            .generate();
+        let syntax_tree = syn::parse2::<syn::File>(generated).expect("generated code must parse");
+        let mut structs = Vec::new();
+        collect_structs(&syntax_tree.items, &mut structs);
+        structs
+    }
+
+    fn collect_structs(items: &[syn::Item], structs: &mut Vec<String>) {
+        for item in items {
+            match item {
+                syn::Item::Struct(s) => structs.push(s.ident.to_string()),
+                syn::Item::Mod(m) => {
+                    if let Some((_, items)) = &m.content {
+                        collect_structs(items, structs);
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    #[test]
+    fn abstract_wrappers_are_kept_by_default() {
+        let bundle = parse_schema();
+        let compiled = bundle
+            .compile_all(CompilerConfig::default())
+            .expect("schema must compile");
+        let (base, middle, leaf) = (
+            qname("Thing.Base"),
+            qname("Thing.Middle"),
+            qname("Thing.Leaf"),
+        );
+        let compiled = remove_empty_complex_types(compiled, &Config::default());
+        assert!(compiled.complex_types.contains_key(&(&base).into()));
+        assert!(compiled.complex_types.contains_key(&(&middle).into()));
+        let leaf = compiled
+            .complex_types
+            .get(&(&leaf).into())
+            .expect("leaf must be kept");
+        assert_eq!(leaf.base, Some((&middle).into()));
+    }
+
+    #[test]
+    fn abstract_wrappers_are_removed_when_enabled() {
+        let bundle = parse_schema();
+        let compiled = bundle
+            .compile_all(CompilerConfig::default())
+            .expect("schema must compile");
+        let (base, middle, leaf) = (
+            qname("Thing.Base"),
+            qname("Thing.Middle"),
+            qname("Thing.Leaf"),
+        );
+        assert!(!ComplexType::is_leaf((&base).into(), &compiled));
+        assert!(!ComplexType::is_leaf((&middle).into(), &compiled));
+        assert!(ComplexType::is_leaf((&leaf).into(), &compiled));
+
+        let compiled = remove_empty_complex_types(compiled, &remove_wrappers());
+        assert!(!compiled.complex_types.contains_key(&(&base).into()));
+        assert!(!compiled.complex_types.contains_key(&(&middle).into()));
+        let leaf = compiled
+            .complex_types
+            .get(&(&leaf).into())
+            .expect("leaf must be kept");
+        assert_eq!(leaf.base, None);
+    }
+
+    #[test]
+    fn abstract_wrappers_are_not_generated_when_enabled() {
+        let structs = generated_structs(&Config::default());
+        assert!(structs.iter().any(|s| s == "Base"));
+        assert!(structs.iter().any(|s| s == "Middle"));
+        assert!(structs.iter().any(|s| s == "Leaf"));
+
+        let structs = generated_structs(&remove_wrappers());
+        assert!(!structs.iter().any(|s| s == "Base"));
+        assert!(!structs.iter().any(|s| s == "Middle"));
+        assert!(structs.iter().any(|s| s == "Leaf"));
+    }
+}
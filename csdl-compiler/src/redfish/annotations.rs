@@ -18,6 +18,7 @@ use crate::edmx::ComplexType;
 use crate::edmx::NavigationProperty;
 use crate::edmx::Parameter;
 use crate::edmx::StructuralProperty;
+use crate::redfish::Deprecated;
 use crate::redfish::DynamicProperties;
 use crate::redfish::Excerpt;
 use crate::redfish::ExcerptCopy;
@@ -69,6 +70,17 @@ pub trait RedfishAnnotations {
             )
     }
 
+    /// Returns deprecation of the property. If None then property is
+    /// not deprecated.
+    fn deprecated(&self) -> Option<Deprecated<'_>> {
+        self.annotations()
+            .iter()
+            .find(|a| a.is_redfish_annotation("Deprecated"))
+            .map(|v| Deprecated {
+                note: v.string.as_ref(),
+            })
+    }
+
     /// Returns excerpt keyse of the property. If None then it is not
     /// except property.
     fn excerpt(&self) -> Option<Excerpt> {
@@ -36,3 +36,10 @@ pub struct DynamicProperties<'a> {
     pub pattern: &'a String,
     pub ptype: &'a String,
 }
+
+/// Deprecation of a property (`Redfish.Deprecated` annotation).
+#[derive(Debug, Clone, Copy)]
+pub struct Deprecated<'a> {
+    /// Explanation of the deprecation, usually naming the replacement.
+    pub note: Option<&'a String>,
+}
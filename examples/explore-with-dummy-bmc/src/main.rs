@@ -506,6 +506,22 @@ impl Bmc for MockBmc {
         todo!("unimplimented")
     }
 
+    async fn select<T: EntityTypeRef + for<'de> Deserialize<'de>>(
+        &self,
+        _id: &ODataId,
+        _query: nv_redfish_core::SelectQuery,
+    ) -> Result<Arc<T>, Error> {
+        todo!("unimplimented")
+    }
+
+    async fn page<T: EntityTypeRef + for<'de> Deserialize<'de>>(
+        &self,
+        _id: &ODataId,
+        _query: nv_redfish_core::PageQuery,
+    ) -> Result<Arc<T>, Error> {
+        todo!("unimplimented")
+    }
+
     async fn get<T: EntityTypeRef + for<'de> Deserialize<'de>>(
         &self,
         id: &ODataId,
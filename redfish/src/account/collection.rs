@@ -221,6 +221,33 @@ impl<B: Bmc> AccountCollection<B> {
         }
     }
 
+    /// Find the account with the given `username`.
+    ///
+    /// Accounts are retrieved one by one and the search stops at the
+    /// first match. Accounts hidden by [`Self::all_accounts_data`] are
+    /// never returned.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if retrieving account data fails.
+    pub async fn find_by_username(&self, username: &str) -> Result<Option<Account<B>>, Error<B>> {
+        let hide_disabled = self
+            .config
+            .slot_defined_user_accounts
+            .as_ref()
+            .is_some_and(|cfg| cfg.hide_disabled);
+        for m in &self.collection.members {
+            let account = Account::new(&self.bmc, m, &self.config.account).await?;
+            if hide_disabled && !account.is_enabled() {
+                continue;
+            }
+            if account.raw().user_name.as_deref() == Some(username) {
+                return Ok(Some(account));
+            }
+        }
+        Ok(None)
+    }
+
     /// Retrieve account data.
     ///
     /// This method does not update the collection itself. It only
@@ -17,8 +17,8 @@
 //!
 //! Provides `Account`, an ergonomic handle over a Redfish `ManagerAccount`:
 //! - Read raw data with `raw()`
-//! - Update fields via `update()`, or use helpers `update_password()` and
-//!   `update_user_name()`
+//! - Update fields via `update()`, or use helpers `update_password()`,
+//!   `update_user_name()`, `update_enabled()` and `update_role_id()`
 //! - Delete the account with `delete()`; optionally disable instead of deleting
 //!   when configured
 //!
@@ -189,6 +189,56 @@ impl<B: Bmc> Account<B> {
         .await
     }
 
+    /// Enable or disable the account.
+    ///
+    /// Returns one of the following modification outcomes:
+    ///
+    /// - `ModificationResponse::Entity` contains the updated account.
+    /// - `ModificationResponse::Task` identifies an asynchronous operation.
+    /// - `ModificationResponse::Empty` reports synchronous success without a
+    ///   response body.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the server responds with an error or if the
+    /// response cannot be parsed.
+    pub async fn update_enabled(
+        &self,
+        enabled: bool,
+    ) -> Result<ModificationResponse<Self>, Error<B>> {
+        self.update(
+            &ManagerAccountUpdate::builder()
+                .with_enabled(enabled)
+                .build(),
+        )
+        .await
+    }
+
+    /// Update the account's role.
+    ///
+    /// Returns one of the following modification outcomes:
+    ///
+    /// - `ModificationResponse::Entity` contains the updated account.
+    /// - `ModificationResponse::Task` identifies an asynchronous operation.
+    /// - `ModificationResponse::Empty` reports synchronous success without a
+    ///   response body.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the server responds with an error or if the
+    /// response cannot be parsed.
+    pub async fn update_role_id(
+        &self,
+        role_id: String,
+    ) -> Result<ModificationResponse<Self>, Error<B>> {
+        self.update(
+            &ManagerAccountUpdate::builder()
+                .with_role_id(role_id)
+                .build(),
+        )
+        .await
+    }
+
     /// Delete the current account.
     ///
     /// Returns one of the following modification outcomes:
@@ -205,8 +255,7 @@ impl<B: Bmc> Account<B> {
     /// Returns an error if deletion fails.
     pub async fn delete(&self) -> Result<ModificationResponse<Self>, Error<B>> {
         if self.config.disable_account_on_delete {
-            self.update(&ManagerAccountUpdate::builder().with_enabled(false).build())
-                .await
+            self.update_enabled(false).await
         } else {
             self.bmc
                 .as_ref()
@@ -22,6 +22,7 @@
 //! - `AccountService`: entry point to manage accounts
 //! - `AccountCollection`: access and create `ManagerAccount` members
 //! - `Account`: operate on an individual `ManagerAccount`
+//! - `RoleCollection` / `Role`: manage privilege roles (feature `roles`)
 //!
 //! Vendor compatibility
 //! - Some implementations omit fields marked as `Redfish.Required`.
@@ -33,9 +34,12 @@
 mod collection;
 /// Account inside account service.
 mod item;
+/// Roles defined in account service.
+#[cfg(feature = "roles")]
+mod role;
 
+use crate::patch_support::compose_patches;
 use crate::patch_support::JsonValue;
-use crate::patch_support::ReadPatchFn;
 use crate::schema::account_service::AccountService as SchemaAccountService;
 use crate::Error;
 use crate::NvBmc;
@@ -52,6 +56,22 @@ pub use crate::schema::manager_account::ManagerAccountUpdate;
 #[doc(inline)]
 pub use item::Account;
 
+#[doc(inline)]
+#[cfg(feature = "roles")]
+pub use crate::schema::privileges::PrivilegeType;
+#[doc(inline)]
+#[cfg(feature = "roles")]
+pub use crate::schema::role::RoleCreate;
+#[doc(inline)]
+#[cfg(feature = "roles")]
+pub use crate::schema::role::RoleUpdate;
+#[doc(inline)]
+#[cfg(feature = "roles")]
+pub use role::Role;
+#[doc(inline)]
+#[cfg(feature = "roles")]
+pub use role::RoleCollection;
+
 #[doc(inline)]
 pub use collection::AccountCollection;
 #[doc(inline)]
@@ -82,13 +102,7 @@ impl<B: Bmc> AccountService<B> {
         if bmc.quirks.bug_no_account_type_in_accounts() {
             patches.push(append_default_account_type);
         }
-        let account_read_patch_fn = if patches.is_empty() {
-            None
-        } else {
-            let account_read_patch_fn: ReadPatchFn =
-                Arc::new(move |v| patches.iter().fold(v, |acc, f| f(acc)));
-            Some(account_read_patch_fn)
-        };
+        let account_read_patch_fn = (!patches.is_empty()).then(|| compose_patches(patches));
         let slot_defined_user_accounts = bmc.quirks.slot_defined_user_accounts();
         Ok(Some(Self {
             collection_config: collection::Config {
@@ -134,6 +148,51 @@ impl<B: Bmc> AccountService<B> {
             Ok(None)
         }
     }
+
+    /// Get the roles collection.
+    ///
+    /// Uses `$expand` to retrieve members in a single request when supported.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if expanding the collection fails.
+    #[cfg(feature = "roles")]
+    pub async fn roles(&self) -> Result<Option<RoleCollection<B>>, Error<B>> {
+        if let Some(collection_ref) = self.service.roles.as_ref() {
+            RoleCollection::new(self.bmc.clone(), collection_ref)
+                .await
+                .map(Some)
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Change password of the account with user name `username`.
+    ///
+    /// Looks the account up with [`AccountCollection::find_by_username`]
+    /// and updates only its `Password` property.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::AccountNotFound` if there is no account with
+    /// this user name, or an error if retrieving accounts or updating
+    /// the account fails.
+    pub async fn change_password(
+        &self,
+        username: &str,
+        new_password: &str,
+    ) -> Result<(), Error<B>> {
+        let not_found = || Error::AccountNotFound(username.to_string());
+        let accounts = self.accounts().await?.ok_or_else(not_found)?;
+        let account = accounts
+            .find_by_username(username)
+            .await?
+            .ok_or_else(not_found)?;
+        // Any successful outcome (entity, task or empty response)
+        // means the password change was accepted.
+        let _ = account.update_password(new_password.to_string()).await?;
+        Ok(())
+    }
 }
 
 // `AccountTypes` is marked as `Redfish.Required`, but some systems
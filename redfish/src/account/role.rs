@@ -0,0 +1,170 @@
+// SPDX-FileCopyrightText: Copyright (c) 2025 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Roles defined by the account service.
+
+use crate::account::RoleCreate;
+use crate::account::RoleUpdate;
+use crate::schema::privileges::PrivilegeType;
+use crate::schema::role::Role as RoleSchema;
+use crate::schema::role_collection::RoleCollection as RoleCollectionSchema;
+use crate::Error;
+use crate::NvBmc;
+use crate::Resource;
+use crate::ResourceSchema;
+use nv_redfish_core::Bmc;
+use nv_redfish_core::Deletable as _;
+use nv_redfish_core::EntityTypeRef as _;
+use nv_redfish_core::ModificationResponse;
+use nv_redfish_core::NavProperty;
+use std::sync::Arc;
+
+/// Role collection.
+///
+/// Provides functions to list and create roles.
+pub struct RoleCollection<B: Bmc> {
+    bmc: NvBmc<B>,
+    collection: Arc<RoleCollectionSchema>,
+}
+
+impl<B: Bmc> RoleCollection<B> {
+    pub(crate) async fn new(
+        bmc: NvBmc<B>,
+        collection_ref: &NavProperty<RoleCollectionSchema>,
+    ) -> Result<Self, Error<B>> {
+        let collection = bmc.expand_property(collection_ref).await?;
+        Ok(Self { bmc, collection })
+    }
+
+    /// List all roles defined in the account service.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if fetching role data fails.
+    pub async fn members(&self) -> Result<Vec<Role<B>>, Error<B>> {
+        let fetches = self
+            .collection
+            .members
+            .iter()
+            .map(|member| Role::new(&self.bmc, member))
+            .collect::<Vec<_>>();
+        self.bmc.fetch_members(fetches).await
+    }
+
+    /// Create a new custom role.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if creating the role fails.
+    pub async fn create(
+        &self,
+        create: &RoleCreate,
+    ) -> Result<ModificationResponse<Role<B>>, Error<B>> {
+        self.bmc
+            .as_ref()
+            .create::<_, RoleSchema>(self.collection.odata_id(), create)
+            .await
+            .map_err(Error::Bmc)
+            .map(|response| {
+                response.map_entity(|data| Role::from_data(self.bmc.clone(), Arc::new(data)))
+            })
+    }
+}
+
+/// Represents a Redfish `Role`.
+///
+/// A role defines the set of privileges granted to accounts that are
+/// assigned to it.
+pub struct Role<B: Bmc> {
+    bmc: NvBmc<B>,
+    data: Arc<RoleSchema>,
+}
+
+impl<B: Bmc> Role<B> {
+    pub(crate) async fn new(
+        bmc: &NvBmc<B>,
+        nav: &NavProperty<RoleSchema>,
+    ) -> Result<Self, Error<B>> {
+        nav.get(bmc.as_ref())
+            .await
+            .map_err(Error::Bmc)
+            .map(|data| Self::from_data(bmc.clone(), data))
+    }
+
+    pub(crate) const fn from_data(bmc: NvBmc<B>, data: Arc<RoleSchema>) -> Self {
+        Self { bmc, data }
+    }
+
+    /// Get the raw schema data for this role.
+    ///
+    /// Returns an `Arc` to the underlying schema, allowing cheap cloning
+    /// and sharing of the data.
+    #[must_use]
+    pub fn raw(&self) -> Arc<RoleSchema> {
+        self.data.clone()
+    }
+
+    /// Replace privileges assigned to this role.
+    ///
+    /// Returns one of the following modification outcomes:
+    ///
+    /// - `ModificationResponse::Entity` contains the updated role.
+    /// - `ModificationResponse::Task` identifies an asynchronous operation.
+    /// - `ModificationResponse::Empty` reports synchronous success without a
+    ///   response body.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if updating the role fails.
+    pub async fn update(
+        &self,
+        privileges: Vec<PrivilegeType>,
+    ) -> Result<ModificationResponse<Self>, Error<B>> {
+        let update = RoleUpdate::builder()
+            .with_assigned_privileges(privileges)
+            .build();
+        self.bmc
+            .as_ref()
+            .update::<_, NavProperty<RoleSchema>>(self.data.odata_id(), self.data.etag(), &update)
+            .await
+            .map_err(Error::Bmc)?
+            .try_map_entity_async(|nav| async move { Self::new(&self.bmc, &nav).await })
+            .await
+    }
+
+    /// Delete this role.
+    ///
+    /// Only custom roles can be deleted; predefined roles are
+    /// rejected by the service.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the delete request fails.
+    pub async fn delete(&self) -> Result<ModificationResponse<Self>, Error<B>> {
+        self.data
+            .delete(self.bmc.as_ref())
+            .await
+            .map_err(Error::Bmc)
+            .map(|response| {
+                response.map_entity(|data| Self::from_data(self.bmc.clone(), Arc::new(data)))
+            })
+    }
+}
+
+impl<B: Bmc> Resource for Role<B> {
+    fn resource_ref(&self) -> &ResourceSchema {
+        &self.data.as_ref().base
+    }
+}
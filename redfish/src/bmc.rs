@@ -18,23 +18,36 @@
 
 use crate::bmc_quirks::BmcQuirks;
 use crate::protocol_features::ExpandQueryFeatures;
+use crate::Error;
 use crate::ProtocolFeatures;
+use futures_util::stream;
+use futures_util::StreamExt as _;
+use futures_util::TryStreamExt as _;
 use nv_redfish_core::Bmc;
+use nv_redfish_core::EntityTypeRef;
+use nv_redfish_core::NavProperty;
+use nv_redfish_core::ODataETag;
+use nv_redfish_core::ODataId;
+use serde::Deserialize;
+use std::collections::HashSet;
+use std::future::Future;
 use std::sync::Arc;
 
-#[cfg(feature = "impl-nv-bmc-expand")]
-use crate::Error;
+/// Default number of collection members fetched concurrently.
+const DEFAULT_FETCH_CONCURRENCY: usize = 8;
+
 #[cfg(feature = "impl-nv-bmc-expand")]
 use nv_redfish_core::query::ExpandQuery;
 #[cfg(feature = "impl-nv-bmc-expand")]
 use nv_redfish_core::Expandable;
-#[cfg(feature = "impl-nv-bmc-expand")]
-use nv_redfish_core::NavProperty;
 
 pub struct NvBmc<B: Bmc> {
     bmc: Arc<B>,
     protocol_features: Arc<ProtocolFeatures>,
     pub(crate) quirks: Arc<BmcQuirks>,
+    fetch_concurrency: usize,
+    #[allow(dead_code)] // feature-enabled field
+    expand_levels: bool,
 }
 
 impl<B: Bmc> NvBmc<B> {
@@ -43,6 +56,8 @@ impl<B: Bmc> NvBmc<B> {
             bmc,
             protocol_features: protocol_features.into(),
             quirks: quirks.into(),
+            fetch_concurrency: DEFAULT_FETCH_CONCURRENCY,
+            expand_levels: true,
         }
     }
 
@@ -51,6 +66,8 @@ impl<B: Bmc> NvBmc<B> {
             bmc,
             protocol_features: self.protocol_features,
             quirks: self.quirks,
+            fetch_concurrency: self.fetch_concurrency,
+            expand_levels: self.expand_levels,
         }
     }
 
@@ -65,6 +82,22 @@ impl<B: Bmc> NvBmc<B> {
             }
             .into(),
             quirks: self.quirks,
+            fetch_concurrency: self.fetch_concurrency,
+            expand_levels: self.expand_levels,
+        }
+    }
+
+    pub(crate) fn limit_fetch_concurrency(self, limit: usize) -> Self {
+        Self {
+            fetch_concurrency: limit.max(1),
+            ..self
+        }
+    }
+
+    pub(crate) fn expand_without_levels(self) -> Self {
+        Self {
+            expand_levels: false,
+            ..self
         }
     }
 
@@ -92,6 +125,11 @@ impl<B: Bmc> NvBmc<B> {
         } else {
             None
         };
+        let optimal_query = if self.expand_levels {
+            optimal_query
+        } else {
+            optimal_query.map(ExpandQuery::without_levels)
+        };
         if let Some(optimal_query) = optimal_query {
             nav.expand(self.bmc.as_ref(), optimal_query)
                 .await
@@ -104,6 +142,92 @@ impl<B: Bmc> NvBmc<B> {
             nav.get(self.bmc.as_ref()).await.map_err(Error::Bmc)
         }
     }
+
+    /// Fetch collection members concurrently.
+    ///
+    /// At most `fetch_concurrency` of `fetches` are in flight at the
+    /// same time. Results are returned in the order of `fetches`.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first error returned by any of `fetches`.
+    #[allow(dead_code)] // feature-enabled func
+    pub(crate) async fn fetch_members<T, F>(
+        &self,
+        fetches: impl IntoIterator<Item = F>,
+    ) -> Result<Vec<T>, Error<B>>
+    where
+        F: Future<Output = Result<T, Error<B>>>,
+    {
+        stream::iter(fetches)
+            .buffered(self.fetch_concurrency)
+            .try_collect()
+            .await
+    }
+
+    /// Collect members of all pages of the collection.
+    ///
+    /// Starting from the collection `id`, every page is fetched and
+    /// `Members@odata.nextLink` is followed until the last page. Members
+    /// are returned as references in the order of pages.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::Bmc` if failed to send request to the BMC.
+    ///
+    /// Returns `Error::CollectionNextLinkLoop` if a page points back to
+    /// already visited page.
+    #[allow(dead_code)] // feature-enabled func
+    pub(crate) async fn collection_members<T>(
+        &self,
+        id: &ODataId,
+    ) -> Result<Vec<NavProperty<T>>, Error<B>>
+    where
+        T: EntityTypeRef + for<'de> Deserialize<'de> + 'static,
+    {
+        let mut members = Vec::new();
+        let mut visited = HashSet::new();
+        let mut next_page = Some(id.clone());
+        while let Some(page_id) = next_page.take() {
+            if !visited.insert(page_id.clone()) {
+                return Err(Error::CollectionNextLinkLoop(page_id));
+            }
+            let page = self
+                .bmc
+                .get::<CollectionPage<T>>(&page_id)
+                .await
+                .map_err(Error::Bmc)?;
+            members.extend(
+                page.members
+                    .iter()
+                    .map(|m| NavProperty::new_reference(m.id().clone())),
+            );
+            next_page.clone_from(&page.next_link);
+        }
+        Ok(members)
+    }
+}
+
+/// Single page of a collection. Only members and link to the next
+/// page are of interest.
+#[derive(Deserialize)]
+struct CollectionPage<T: EntityTypeRef> {
+    #[serde(rename = "@odata.id")]
+    odata_id: ODataId,
+    #[serde(rename = "Members", default = "Vec::new")]
+    members: Vec<NavProperty<T>>,
+    #[serde(rename = "Members@odata.nextLink")]
+    next_link: Option<ODataId>,
+}
+
+impl<T: EntityTypeRef> EntityTypeRef for CollectionPage<T> {
+    fn odata_id(&self) -> &ODataId {
+        &self.odata_id
+    }
+
+    fn etag(&self) -> Option<&ODataETag> {
+        None
+    }
 }
 
 // Implementing Clone because derive requires B to be Clone but NvBmc
@@ -114,6 +238,8 @@ impl<B: Bmc> Clone for NvBmc<B> {
             bmc: self.bmc.clone(),
             protocol_features: self.protocol_features.clone(),
             quirks: self.quirks.clone(),
+            fetch_concurrency: self.fetch_concurrency,
+            expand_levels: self.expand_levels,
         }
     }
 }
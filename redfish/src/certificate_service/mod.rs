@@ -0,0 +1,184 @@
+// SPDX-FileCopyrightText: Copyright (c) 2026 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Certificate Service entities and helpers.
+//!
+//! This module provides typed access to Redfish `CertificateService`,
+//! including listing and deleting installed certificates, generating
+//! certificate signing requests and replacing certificates.
+
+mod item;
+
+use crate::schema::certificate::Certificate as CertificateSchema;
+use crate::schema::certificate_service::CertificateService as CertificateServiceSchema;
+use crate::schema::certificate_service::CertificateServiceReplaceCertificateAction;
+use crate::Error;
+use crate::NvBmc;
+use crate::ServiceRoot;
+use nv_redfish_core::Bmc;
+use nv_redfish_core::EntityTypeRef as _;
+use nv_redfish_core::ModificationResponse;
+use nv_redfish_core::NavProperty;
+use nv_redfish_core::Reference;
+use std::sync::Arc;
+
+#[doc(inline)]
+pub use crate::schema::certificate::CertificateType;
+#[doc(inline)]
+pub use crate::schema::certificate_service::CertificateServiceGenerateCSRAction as GenerateCsrRequest;
+#[doc(inline)]
+pub use crate::schema::certificate_service::GenerateCsrResponse;
+#[doc(inline)]
+pub use item::Certificate;
+
+/// Certificate service.
+///
+/// Provides functions to list certificates and perform certificate
+/// management actions.
+pub struct CertificateService<B: Bmc> {
+    bmc: NvBmc<B>,
+    data: Arc<CertificateServiceSchema>,
+}
+
+impl<B: Bmc> CertificateService<B> {
+    /// Create a new certificate service handle.
+    pub(crate) async fn new(
+        bmc: &NvBmc<B>,
+        root: &ServiceRoot<B>,
+    ) -> Result<Option<Self>, Error<B>> {
+        if let Some(service_ref) = &root.root.certificate_service {
+            let data = service_ref.get(bmc.as_ref()).await.map_err(Error::Bmc)?;
+            Ok(Some(Self {
+                bmc: bmc.clone(),
+                data,
+            }))
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Get the raw schema data for this certificate service.
+    ///
+    /// Returns an `Arc` to the underlying schema, allowing cheap cloning
+    /// and sharing of the data.
+    #[must_use]
+    pub fn raw(&self) -> Arc<CertificateServiceSchema> {
+        self.data.clone()
+    }
+
+    /// List all certificates installed on the service.
+    ///
+    /// Certificates are discovered through `CertificateLocations`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if fetching certificate locations or certificate
+    /// data fails.
+    pub async fn certificates(&self) -> Result<Option<Vec<Certificate<B>>>, Error<B>> {
+        if let Some(locations_ref) = &self.data.certificate_locations {
+            let locations = locations_ref
+                .get(self.bmc.as_ref())
+                .await
+                .map_err(Error::Bmc)?;
+            let mut certificates = Vec::new();
+            for nav in locations
+                .links
+                .iter()
+                .filter_map(|links| links.certificates.as_ref())
+                .flatten()
+            {
+                certificates.push(Certificate::new(&self.bmc, nav).await?);
+            }
+            Ok(Some(certificates))
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Generate a certificate signing request.
+    ///
+    /// The `CertificateCollection` in the request selects where the
+    /// certificate signed from the returned CSR is installed later.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - The certificate service does not support the `GenerateCSR` action
+    /// - The action execution fails
+    pub async fn generate_csr(
+        &self,
+        request: &GenerateCsrRequest,
+    ) -> Result<ModificationResponse<GenerateCsrResponse>, Error<B>>
+    where
+        B::Error: nv_redfish_core::ActionError,
+    {
+        let actions = self
+            .data
+            .actions
+            .as_ref()
+            .ok_or(Error::ActionNotAvailable)?;
+
+        actions
+            .generate_csr(self.bmc.as_ref(), request)
+            .await
+            .map_err(Error::Bmc)
+    }
+
+    /// Replace `certificate` with a new one.
+    ///
+    /// # Arguments
+    ///
+    /// * `certificate` - The certificate to replace
+    /// * `certificate_string` - The string for the new certificate
+    /// * `certificate_type` - The format of `certificate_string`
+    /// * `password` - Optional password for the new certificate
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - The certificate service does not support the `ReplaceCertificate` action
+    /// - The action execution fails
+    pub async fn replace_certificate(
+        &self,
+        certificate: &Certificate<B>,
+        certificate_string: String,
+        certificate_type: CertificateType,
+        password: Option<String>,
+    ) -> Result<ModificationResponse<()>, Error<B>>
+    where
+        B::Error: nv_redfish_core::ActionError,
+    {
+        let actions = self
+            .data
+            .actions
+            .as_ref()
+            .ok_or(Error::ActionNotAvailable)?;
+
+        let certificate_uri =
+            NavProperty::<CertificateSchema>::new_reference(certificate.raw().odata_id().clone());
+        actions
+            .replace_certificate(
+                self.bmc.as_ref(),
+                &CertificateServiceReplaceCertificateAction {
+                    certificate_string,
+                    certificate_type,
+                    certificate_uri: Reference::from(&certificate_uri),
+                    password,
+                },
+            )
+            .await
+            .map_err(Error::Bmc)
+    }
+}
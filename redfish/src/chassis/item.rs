@@ -20,6 +20,7 @@ use crate::hardware_id::Manufacturer as HardwareIdManufacturer;
 use crate::hardware_id::Model as HardwareIdModel;
 use crate::hardware_id::PartNumber as HardwareIdPartNumber;
 use crate::hardware_id::SerialNumber as HardwareIdSerialNumber;
+use crate::patch_support::compose_patches;
 use crate::patch_support::JsonValue;
 use crate::patch_support::Payload;
 use crate::patch_support::ReadPatchFn;
@@ -43,10 +44,14 @@ use crate::chassis::NetworkAdapter;
 use crate::chassis::NetworkAdapterCollection;
 #[cfg(feature = "power")]
 use crate::chassis::Power;
+#[cfg(feature = "power-subsystem")]
+use crate::chassis::PowerSubsystem;
 #[cfg(feature = "power-supplies")]
 use crate::chassis::PowerSupply;
 #[cfg(feature = "thermal")]
 use crate::chassis::Thermal;
+#[cfg(feature = "thermal-subsystem")]
+use crate::chassis::ThermalSubsystem;
 #[cfg(feature = "controls")]
 use crate::control::extract_environment_power_limit_control;
 #[cfg(feature = "controls")]
@@ -104,8 +109,7 @@ impl Config {
         if quirks.bug_empty_uuid_field() {
             patches.push(normalize_empty_uuid_field);
         }
-        let read_patch_fn = (!patches.is_empty())
-            .then(|| Arc::new(move |v| patches.iter().fold(v, |acc, f| f(acc))) as ReadPatchFn);
+        let read_patch_fn = (!patches.is_empty()).then(|| compose_patches(patches));
         Self { read_patch_fn }
     }
 }
@@ -250,6 +254,22 @@ impl<B: Bmc> Chassis<B> {
         Ok(Vec::new())
     }
 
+    /// Get the power subsystem of this chassis.
+    ///
+    /// Returns `Ok(None)` when the `PowerSubsystem` link is absent.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if fetching power subsystem data fails.
+    #[cfg(feature = "power-subsystem")]
+    pub async fn power_subsystem(&self) -> Result<Option<PowerSubsystem<B>>, Error<B>> {
+        if let Some(ps) = &self.data.power_subsystem {
+            PowerSubsystem::new(&self.bmc, ps).await.map(Some)
+        } else {
+            Ok(None)
+        }
+    }
+
     /// Get LiteOn OEM power supplies from this chassis.
     ///
     /// # Errors
@@ -272,6 +292,7 @@ impl<B: Bmc> Chassis<B> {
     ///
     /// Returns an error if fetching power data fails.
     #[cfg(feature = "power")]
+    #[allow(deprecated)]
     pub async fn power(&self) -> Result<Option<Power<B>>, Error<B>> {
         if let Some(power_ref) = &self.data.power {
             Ok(Some(Power::new(&self.bmc, power_ref).await?))
@@ -310,6 +331,7 @@ impl<B: Bmc> Chassis<B> {
     ///
     /// Returns an error if fetching thermal data fails.
     #[cfg(feature = "thermal")]
+    #[allow(deprecated)]
     pub async fn thermal(&self) -> Result<Option<Thermal<B>>, Error<B>> {
         if let Some(thermal_ref) = &self.data.thermal {
             Thermal::new(&self.bmc, thermal_ref).await.map(Some)
@@ -318,6 +340,24 @@ impl<B: Bmc> Chassis<B> {
         }
     }
 
+    /// Get the thermal subsystem of this chassis.
+    ///
+    /// Returns `Ok(None)` when the `ThermalSubsystem` link is absent.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if fetching thermal subsystem data fails.
+    #[cfg(feature = "thermal-subsystem")]
+    pub async fn thermal_subsystem(&self) -> Result<Option<ThermalSubsystem<B>>, Error<B>> {
+        if let Some(thermal_ref) = &self.data.thermal_subsystem {
+            ThermalSubsystem::new(&self.bmc, thermal_ref)
+                .await
+                .map(Some)
+        } else {
+            Ok(None)
+        }
+    }
+
     /// Get network adapter resources
     ///
     /// Returns the `Chassis/NetworkAdapter` resources if available, and `Ok(None)` when
@@ -19,10 +19,14 @@ mod item;
 mod network_adapter;
 #[cfg(feature = "power")]
 mod power;
+#[cfg(feature = "power-subsystem")]
+mod power_subsystem;
 #[cfg(feature = "power-supplies")]
 mod power_supply;
 #[cfg(feature = "thermal")]
 mod thermal;
+#[cfg(feature = "thermal-subsystem")]
+mod thermal_subsystem;
 
 use nv_redfish_core::Bmc;
 use std::sync::Arc;
@@ -59,12 +63,22 @@ pub use network_adapter::SerialNumber as NetworkAdapterSerialNumber;
 #[cfg(feature = "power")]
 pub use power::Power;
 #[doc(inline)]
+#[cfg(feature = "power-subsystem")]
+pub use power_subsystem::PowerSubsystem;
+#[doc(inline)]
 #[cfg(feature = "power-supplies")]
 pub use power_supply::PowerSupply;
 #[doc(inline)]
 #[cfg(feature = "thermal")]
 pub use thermal::Thermal;
+#[doc(inline)]
+#[cfg(feature = "thermal-subsystem")]
+pub use thermal_subsystem::Fan;
+#[doc(inline)]
+#[cfg(feature = "thermal-subsystem")]
+pub use thermal_subsystem::ThermalSubsystem;
 
+use crate::core::EntityTypeRef as _;
 use crate::core::NavProperty;
 use crate::entity_link::EntityLink;
 use crate::patch_support::CollectionWithPatch;
@@ -125,12 +139,32 @@ impl<B: Bmc> ChassisCollection<B> {
     ///
     /// Returns an error if fetching collection data fails.
     pub async fn members(&self) -> Result<Vec<Chassis<B>>, Error<B>> {
-        let mut chassis_members = Vec::new();
-        for chassis in &self.collection.members {
-            chassis_members.push(Chassis::new(&self.bmc, chassis).await?);
-        }
+        let fetches = self
+            .collection
+            .members
+            .iter()
+            .map(|chassis| Chassis::new(&self.bmc, chassis))
+            .collect::<Vec<_>>();
+        self.bmc.fetch_members(fetches).await
+    }
 
-        Ok(chassis_members)
+    /// List all chassis avaiable in this BMC following
+    /// `Members@odata.nextLink` of paginated collection.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if fetching any page of the collection or
+    /// chassis data fails.
+    pub async fn all_members(&self) -> Result<Vec<Chassis<B>>, Error<B>> {
+        let refs = self
+            .bmc
+            .collection_members::<ChassisSchema>(self.collection.odata_id())
+            .await?;
+        let fetches = refs
+            .iter()
+            .map(|chassis| Chassis::new(&self.bmc, chassis))
+            .collect::<Vec<_>>();
+        self.bmc.fetch_members(fetches).await
     }
 }
 
@@ -0,0 +1,84 @@
+// SPDX-FileCopyrightText: Copyright (c) 2025 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::chassis::PowerSupply;
+use crate::schema::power_subsystem::PowerSubsystem as PowerSubsystemSchema;
+use crate::Error;
+use crate::NvBmc;
+use crate::Resource;
+use crate::ResourceSchema;
+use nv_redfish_core::Bmc;
+use nv_redfish_core::NavProperty;
+use std::sync::Arc;
+
+/// Power subsystem of a chassis.
+///
+/// Modern replacement of the legacy `Chassis/Power` resource. Provides
+/// access to power supplies of the chassis.
+pub struct PowerSubsystem<B: Bmc> {
+    bmc: NvBmc<B>,
+    data: Arc<PowerSubsystemSchema>,
+}
+
+impl<B: Bmc> PowerSubsystem<B> {
+    /// Create a new power subsystem handle.
+    pub(crate) async fn new(
+        bmc: &NvBmc<B>,
+        nav: &NavProperty<PowerSubsystemSchema>,
+    ) -> Result<Self, Error<B>> {
+        nav.get(bmc.as_ref())
+            .await
+            .map_err(Error::Bmc)
+            .map(|data| Self {
+                bmc: bmc.clone(),
+                data,
+            })
+    }
+
+    /// Get the raw schema data for this power subsystem.
+    ///
+    /// Returns an `Arc` to the underlying schema, allowing cheap cloning
+    /// and sharing of the data.
+    #[must_use]
+    pub fn raw(&self) -> Arc<PowerSubsystemSchema> {
+        self.data.clone()
+    }
+
+    /// Get power supplies of this power subsystem.
+    ///
+    /// Returns `Ok(None)` when the `PowerSupplies` link is absent.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if fetching power supply data fails.
+    pub async fn power_supplies(&self) -> Result<Option<Vec<PowerSupply<B>>>, Error<B>> {
+        if let Some(supplies) = &self.data.power_supplies {
+            let supplies = &self.bmc.expand_property(supplies).await?.members;
+            let mut power_supplies = Vec::with_capacity(supplies.len());
+            for power_supply in supplies {
+                power_supplies.push(PowerSupply::new(&self.bmc, power_supply).await?);
+            }
+            Ok(Some(power_supplies))
+        } else {
+            Ok(None)
+        }
+    }
+}
+
+impl<B: Bmc> Resource for PowerSubsystem<B> {
+    fn resource_ref(&self) -> &ResourceSchema {
+        &self.data.as_ref().base
+    }
+}
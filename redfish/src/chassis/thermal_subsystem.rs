@@ -0,0 +1,175 @@
+// SPDX-FileCopyrightText: Copyright (c) 2025 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::extract_sensor_uris;
+use crate::schema::fan::Fan as FanSchema;
+use crate::schema::thermal_subsystem::ThermalSubsystem as ThermalSubsystemSchema;
+use crate::sensor::SensorLink;
+use crate::Error;
+use crate::NvBmc;
+use crate::Resource;
+use crate::ResourceSchema;
+use nv_redfish_core::Bmc;
+use nv_redfish_core::NavProperty;
+use nv_redfish_core::ODataId;
+use std::sync::Arc;
+
+/// Thermal subsystem of a chassis.
+///
+/// Modern replacement of the legacy `Chassis/Thermal` resource. Provides
+/// access to fans and thermal metrics sensors of the chassis.
+pub struct ThermalSubsystem<B: Bmc> {
+    bmc: NvBmc<B>,
+    data: Arc<ThermalSubsystemSchema>,
+}
+
+impl<B: Bmc> ThermalSubsystem<B> {
+    /// Create a new thermal subsystem handle.
+    pub(crate) async fn new(
+        bmc: &NvBmc<B>,
+        nav: &NavProperty<ThermalSubsystemSchema>,
+    ) -> Result<Self, Error<B>> {
+        nav.get(bmc.as_ref())
+            .await
+            .map_err(Error::Bmc)
+            .map(|data| Self {
+                bmc: bmc.clone(),
+                data,
+            })
+    }
+
+    /// Get the raw schema data for this thermal subsystem.
+    ///
+    /// Returns an `Arc` to the underlying schema, allowing cheap cloning
+    /// and sharing of the data.
+    #[must_use]
+    pub fn raw(&self) -> Arc<ThermalSubsystemSchema> {
+        self.data.clone()
+    }
+
+    /// Get fans of this thermal subsystem.
+    ///
+    /// Returns `Ok(None)` when the `Fans` link is absent.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if fetching fan data fails.
+    pub async fn fans(&self) -> Result<Option<Vec<Fan<B>>>, Error<B>> {
+        if let Some(fans) = &self.data.fans {
+            let fans = &self.bmc.expand_property(fans).await?.members;
+            let mut result = Vec::with_capacity(fans.len());
+            for fan in fans {
+                result.push(Fan::new(&self.bmc, fan).await?);
+            }
+            Ok(Some(result))
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Get the thermal metrics sensors for this thermal subsystem.
+    ///
+    /// Returns an empty vector when the `ThermalMetrics` link is absent.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if get of thermal metrics failed.
+    pub async fn metrics_sensor_links(&self) -> Result<Vec<SensorLink<B>>, Error<B>> {
+        let sensor_refs = if let Some(metrics_ref) = &self.data.thermal_metrics {
+            metrics_ref
+                .get(self.bmc.as_ref())
+                .await
+                .map_err(Error::Bmc)
+                .map(|m| {
+                    extract_sensor_uris!(m,
+                        single: energyk_wh,
+                        single: power_watts,
+                        vec: temperature_readings_celsius
+                    )
+                })?
+        } else {
+            Vec::new()
+        };
+
+        Ok(sensor_refs
+            .into_iter()
+            .map(|r| SensorLink::new(&self.bmc, r))
+            .collect())
+    }
+}
+
+impl<B: Bmc> Resource for ThermalSubsystem<B> {
+    fn resource_ref(&self) -> &ResourceSchema {
+        &self.data.as_ref().base
+    }
+}
+
+/// Represents a fan in a thermal subsystem.
+pub struct Fan<B: Bmc> {
+    bmc: NvBmc<B>,
+    data: Arc<FanSchema>,
+}
+
+impl<B: Bmc> Fan<B> {
+    /// Create a new fan handle.
+    pub(crate) async fn new(
+        bmc: &NvBmc<B>,
+        nav: &NavProperty<FanSchema>,
+    ) -> Result<Self, Error<B>> {
+        nav.get(bmc.as_ref())
+            .await
+            .map_err(Error::Bmc)
+            .map(|data| Self {
+                bmc: bmc.clone(),
+                data,
+            })
+    }
+
+    /// Get the raw schema data for this fan.
+    ///
+    /// Returns an `Arc` to the underlying schema, allowing cheap cloning
+    /// and sharing of the data.
+    #[must_use]
+    pub fn raw(&self) -> Arc<FanSchema> {
+        self.data.clone()
+    }
+
+    /// Fan speed in percent as reported in the fan resource excerpt.
+    #[must_use]
+    pub fn speed_percent(&self) -> Option<f64> {
+        self.data
+            .speed_percent
+            .as_ref()
+            .and_then(|speed| speed.reading.flatten())
+    }
+
+    /// Link to the sensor that provides the fan speed.
+    ///
+    /// Returns `None` when the fan does not report `SpeedPercent/DataSourceUri`.
+    #[must_use]
+    pub fn speed_sensor_link(&self) -> Option<SensorLink<B>> {
+        self.data
+            .speed_percent
+            .as_ref()
+            .and_then(|speed| speed.data_source_uri.clone().flatten())
+            .map(|uri| SensorLink::new(&self.bmc, NavProperty::new_reference(ODataId::from(uri))))
+    }
+}
+
+impl<B: Bmc> Resource for Fan<B> {
+    fn resource_ref(&self) -> &ResourceSchema {
+        &self.data.as_ref().base
+    }
+}
@@ -30,6 +30,7 @@
 #[allow(clippy::struct_field_names)]
 #[allow(clippy::too_long_first_doc_paragraph)]
 #[allow(missing_docs)]
+#[allow(deprecated)]
 pub mod redfish {
     include!(concat!(env!("OUT_DIR"), "/redfish.rs"));
 }
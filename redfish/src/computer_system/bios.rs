@@ -13,22 +13,51 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 //! Bios
+//!
+//! Current attribute values are reported by the BIOS resource
+//! itself. Changes are written to the settings resource referenced
+//! by `@Redfish.Settings` and stay pending there until they are
+//! applied (for example, on the next reset).
 
 use crate::schema::bios::Bios as BiosSchema;
 use crate::Error;
 use crate::NvBmc;
 use nv_redfish_core::Bmc;
 use nv_redfish_core::EdmPrimitiveType;
+use nv_redfish_core::EntityTypeRef as _;
+use nv_redfish_core::ModificationResponse;
 use nv_redfish_core::NavProperty;
-use std::marker::PhantomData;
+use nv_redfish_core::RedfishSettings as _;
+use serde::Serialize;
+use std::collections::HashMap;
 use std::sync::Arc;
 
+#[doc(inline)]
+pub use crate::schema::settings::ApplyTime;
+
+#[derive(Serialize)]
+struct SettingsApplyTime {
+    #[serde(rename = "ApplyTime")]
+    apply_time: ApplyTime,
+}
+
+#[derive(Serialize)]
+struct BiosAttributesUpdate {
+    #[serde(rename = "Attributes")]
+    attributes: HashMap<String, EdmPrimitiveType>,
+    #[serde(
+        rename = "@Redfish.SettingsApplyTime",
+        skip_serializing_if = "Option::is_none"
+    )]
+    apply_time: Option<SettingsApplyTime>,
+}
+
 /// BIOS.
 ///
 /// Provides functions to access BIOS functions.
 pub struct Bios<B: Bmc> {
+    bmc: NvBmc<B>,
     data: Arc<BiosSchema>,
-    _marker: PhantomData<B>,
 }
 
 impl<B: Bmc> Bios<B> {
@@ -41,8 +70,8 @@ impl<B: Bmc> Bios<B> {
             .await
             .map_err(crate::Error::Bmc)
             .map(|data| Self {
+                bmc: bmc.clone(),
                 data,
-                _marker: PhantomData,
             })
     }
 
@@ -61,6 +90,93 @@ impl<B: Bmc> Bios<B> {
             .and_then(|attributes| attributes.dynamic_properties.get(name))
             .map(|v| BiosAttributeRef::new(v.as_ref()))
     }
+
+    /// Current values of all BIOS attributes.
+    pub fn attributes(&self) -> impl Iterator<Item = (&str, BiosAttributeRef<'_>)> {
+        self.data
+            .attributes
+            .iter()
+            .flat_map(|attributes| attributes.dynamic_properties.iter())
+            .map(|(name, v)| (name.as_str(), BiosAttributeRef::new(v.as_ref())))
+    }
+
+    /// Apply times supported by the settings resource, as advertised
+    /// in `@Redfish.Settings`. Returns `None` if not advertised.
+    #[must_use]
+    pub fn supported_apply_times(&self) -> Option<&[ApplyTime]> {
+        self.data
+            .redfish_settings()
+            .and_then(|settings| settings.supported_apply_times.as_ref())
+            .and_then(Option::as_ref)
+            .map(Vec::as_slice)
+    }
+
+    /// Pending BIOS settings.
+    ///
+    /// Fetches the settings resource referenced by `@Redfish.Settings`.
+    /// Its attributes are values that will be applied to the BIOS
+    /// according to the requested apply time. Returns `Ok(None)` when
+    /// the BIOS does not reference a settings resource.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if fetching the settings resource fails.
+    pub async fn pending(&self) -> Result<Option<Self>, Error<B>> {
+        if let Some(settings) = self.data.settings_object() {
+            Self::new(&self.bmc, &settings).await.map(Some)
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Set BIOS attributes.
+    ///
+    /// Attributes are written to the settings resource referenced by
+    /// `@Redfish.Settings` or to the BIOS resource itself if there is
+    /// no such reference. When `apply_time` is specified it is sent
+    /// as `@Redfish.SettingsApplyTime`.
+    ///
+    /// Returns one of the following modification outcomes:
+    ///
+    /// - `ModificationResponse::Entity` contains the updated settings
+    ///   (pending values).
+    /// - `ModificationResponse::Task` identifies an asynchronous operation.
+    /// - `ModificationResponse::Empty` reports synchronous success without a
+    ///   response body.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `apply_time` is not among advertised
+    /// supported apply times ([`Error::SettingsApplyTimeNotSupported`])
+    /// or if updating the settings fails.
+    pub async fn set_attributes(
+        &self,
+        attributes: HashMap<String, EdmPrimitiveType>,
+        apply_time: Option<ApplyTime>,
+    ) -> Result<ModificationResponse<Self>, Error<B>> {
+        if let (Some(apply_time), Some(supported)) = (apply_time, self.supported_apply_times()) {
+            if !supported.contains(&apply_time) {
+                return Err(Error::SettingsApplyTimeNotSupported(apply_time));
+            }
+        }
+        let update = BiosAttributesUpdate {
+            attributes,
+            apply_time: apply_time.map(|apply_time| SettingsApplyTime { apply_time }),
+        };
+
+        let settings = self.data.settings_object();
+        let update_odata = settings
+            .as_ref()
+            .map_or_else(|| self.data.odata_id(), |settings| settings.odata_id());
+
+        self.bmc
+            .as_ref()
+            .update::<_, NavProperty<BiosSchema>>(update_odata, None, &update)
+            .await
+            .map_err(Error::Bmc)?
+            .try_map_entity_async(|nav| async move { Self::new(&self.bmc, &nav).await })
+            .await
+    }
 }
 
 /// Reference to a BIOS attribute.
@@ -23,6 +23,7 @@ use crate::hardware_id::Manufacturer as HardwareIdManufacturer;
 use crate::hardware_id::Model as HardwareIdModel;
 use crate::hardware_id::PartNumber as HardwareIdPartNumber;
 use crate::hardware_id::SerialNumber as HardwareIdSerialNumber;
+use crate::patch_support::JsonValue;
 use crate::patch_support::Payload;
 use crate::patch_support::ReadPatchFn;
 use crate::resource::PowerState;
@@ -33,8 +34,12 @@ use crate::NvBmc;
 use crate::Resource;
 use crate::ResourceSchema;
 
+use serde::de::DeserializeOwned;
 use serde::Serialize;
+use serde_json::from_value;
+use serde_json::to_value;
 use std::convert::identity;
+use std::marker::PhantomData;
 use std::sync::Arc;
 use tagged_types::TaggedType;
 
@@ -58,6 +63,8 @@ use crate::log_service::LogService;
 use crate::oem::lenovo::computer_system::LenovoComputerSystem;
 #[cfg(feature = "oem-nvidia-bluefield")]
 use crate::oem::nvidia::bluefield::nvidia_computer_system::NvidiaComputerSystem;
+#[cfg(feature = "virtual-media")]
+use crate::virtual_media::VirtualMedia;
 
 #[doc(hidden)]
 pub enum ComputerSystemTag {}
@@ -107,13 +114,19 @@ struct ComputerSystemBootOrderUpdate {
 /// Represents a computer system in the BMC.
 ///
 /// Provides access to system information and sub-resources such as processors.
-pub struct ComputerSystem<B: Bmc> {
+///
+/// The `Oem` type parameter selects the type that the `Oem` property is
+/// deserialized into by [`ComputerSystem::oem`]. It defaults to
+/// [`JsonValue`]; use [`ComputerSystem::with_oem`] to substitute a
+/// vendor-specific struct.
+pub struct ComputerSystem<B: Bmc, Oem = JsonValue> {
     #[allow(dead_code)] // feature-enabled...
     bmc: NvBmc<B>,
     data: Arc<ComputerSystemSchema>,
+    _oem: PhantomData<fn() -> Oem>,
 }
 
-impl<B: Bmc> ComputerSystem<B> {
+impl<B: Bmc, Oem> ComputerSystem<B, Oem> {
     /// Create a new computer system handle.
     pub(crate) async fn new(
         bmc: &NvBmc<B>,
@@ -128,9 +141,45 @@ impl<B: Bmc> ComputerSystem<B> {
         .map(|data| Self {
             bmc: bmc.clone(),
             data,
+            _oem: PhantomData,
         })
     }
 
+    /// Reinterpret this handle with a different `Oem` type.
+    ///
+    /// No data is refetched; only the type used by
+    /// [`ComputerSystem::oem`] changes.
+    #[must_use]
+    pub fn with_oem<O>(self) -> ComputerSystem<B, O> {
+        ComputerSystem {
+            bmc: self.bmc,
+            data: self.data,
+            _oem: PhantomData,
+        }
+    }
+
+    /// OEM extension of this system deserialized into `Oem`.
+    ///
+    /// Returns `Ok(None)` when the system does not include the `Oem`
+    /// property.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the `Oem` property cannot be deserialized
+    /// into `Oem`.
+    pub fn oem(&self) -> Result<Option<Oem>, Error<B>>
+    where
+        Oem: DeserializeOwned + Send + Sync + 'static,
+    {
+        self.data
+            .base
+            .base
+            .oem
+            .as_ref()
+            .map(|oem| from_value(oem.additional_properties.clone()).map_err(Error::Json))
+            .transpose()
+    }
+
     /// Get the raw schema data for this computer system.
     ///
     /// Returns an `Arc` to the underlying schema, allowing cheap cloning
@@ -190,10 +239,14 @@ impl<B: Bmc> ComputerSystem<B> {
 
     /// Reset this computer system.
     ///
+    /// When the action advertises `ResetType@Redfish.AllowableValues`,
+    /// `reset_type` is checked against it before the action is invoked.
+    ///
     /// # Errors
     ///
-    /// Returns an error if the system does not support the `Reset` action or
-    /// if invoking the action fails.
+    /// Returns an error if the system does not support the `Reset` action,
+    /// if `reset_type` is not among the advertised allowable values
+    /// ([`Error::ResetTypeNotAllowed`]) or if invoking the action fails.
     pub async fn reset(
         &self,
         reset_type: Option<ResetType>,
@@ -207,8 +260,13 @@ impl<B: Bmc> ComputerSystem<B> {
             .as_ref()
             .ok_or(Error::ActionNotAvailable)?;
 
-        if actions.reset.is_none() {
-            return Err(Error::ActionNotAvailable);
+        let reset = actions.reset.as_ref().ok_or(Error::ActionNotAvailable)?;
+        if let (Some(reset_type), Some(allowed)) = (reset_type, reset.allowable_values("ResetType"))
+        {
+            let value = to_value(reset_type).map_err(Error::Json)?;
+            if !allowed.iter().any(|v| value.as_str() == Some(v.as_str())) {
+                return Err(Error::ResetTypeNotAllowed(reset_type));
+            }
         }
 
         actions
@@ -447,6 +505,22 @@ impl<B: Bmc> ComputerSystem<B> {
         }
     }
 
+    /// Get virtual media devices for this computer system.
+    ///
+    /// Returns `Ok(None)` when the virtual media link is absent.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if fetching virtual media data fails.
+    #[cfg(feature = "virtual-media")]
+    pub async fn virtual_media(&self) -> Result<Option<Vec<VirtualMedia<B>>>, Error<B>> {
+        if let Some(p) = &self.data.virtual_media {
+            VirtualMedia::list(&self.bmc, p).await.map(Some)
+        } else {
+            Ok(None)
+        }
+    }
+
     /// NVIDIA Bluefield OEM extension
     ///
     /// Returns `Ok(None)` when the system does not include NVIDIA OEM extension data.
@@ -476,7 +550,7 @@ impl<B: Bmc> ComputerSystem<B> {
     }
 }
 
-impl<B: Bmc> Resource for ComputerSystem<B> {
+impl<B: Bmc, Oem> Resource for ComputerSystem<B, Oem> {
     fn resource_ref(&self) -> &ResourceSchema {
         &self.data.as_ref().base
     }
@@ -34,7 +34,10 @@ pub mod processor;
 pub mod secure_boot;
 #[cfg(feature = "storages")]
 pub mod storage;
+#[cfg(feature = "storages")]
+pub mod volume;
 
+use crate::patch_support::compose_patches;
 use crate::patch_support::CollectionWithPatch;
 use crate::patch_support::FilterFn;
 use crate::patch_support::JsonValue;
@@ -47,6 +50,8 @@ use crate::Error;
 use crate::NvBmc;
 use crate::ServiceRoot;
 use nv_redfish_core::Bmc;
+use nv_redfish_core::EdmGuid;
+use nv_redfish_core::EntityTypeRef as _;
 use nv_redfish_core::NavProperty;
 use std::convert::identity;
 use std::sync::Arc;
@@ -56,6 +61,9 @@ pub use item::BootOptionReference;
 #[doc(inline)]
 pub use item::ComputerSystem;
 
+#[doc(inline)]
+#[cfg(feature = "storages")]
+pub use crate::schema::volume::VolumeCreate;
 #[doc(inline)]
 #[cfg(feature = "bios")]
 pub use bios::Bios;
@@ -81,8 +89,14 @@ pub use secure_boot::SecureBoot;
 #[cfg(feature = "secure-boot")]
 pub use secure_boot::SecureBootCurrentBootType;
 #[doc(inline)]
+#[cfg(feature = "secure-boot")]
+pub use secure_boot::SecureBootDatabase;
+#[doc(inline)]
 #[cfg(feature = "storages")]
 pub use storage::Storage;
+#[doc(inline)]
+#[cfg(feature = "storages")]
+pub use volume::Volume;
 
 /// Computer system collection.
 ///
@@ -114,8 +128,7 @@ impl<B: Bmc> SystemCollection<B> {
         if bmc.quirks.bug_empty_uuid_field() {
             patches.push(normalize_empty_uuid_field);
         }
-        let read_patch_fn = (!patches.is_empty())
-            .then(|| Arc::new(move |v| patches.iter().fold(v, |acc, f| f(acc))) as ReadPatchFn);
+        let read_patch_fn = (!patches.is_empty()).then(|| compose_patches(patches));
         let filters_fn = (!filters.is_empty())
             .then(move || Arc::new(move |v: &JsonValue| filters.iter().any(|f| f(v))) as FilterFn);
 
@@ -152,11 +165,70 @@ impl<B: Bmc> SystemCollection<B> {
     ///
     /// Returns an error if fetching system data fails.
     pub async fn members(&self) -> Result<Vec<ComputerSystem<B>>, Error<B>> {
-        let mut members = Vec::new();
-        for m in &self.collection.members {
-            members.push(ComputerSystem::new(&self.bmc, m, self.read_patch_fn.as_ref()).await?);
-        }
-        Ok(members)
+        let fetches = self
+            .collection
+            .members
+            .iter()
+            .map(|m| ComputerSystem::new(&self.bmc, m, self.read_patch_fn.as_ref()))
+            .collect::<Vec<_>>();
+        self.bmc.fetch_members(fetches).await
+    }
+
+    /// Find the computer system with the given serial number.
+    ///
+    /// Returns `Ok(None)` when no system reports `serial_number`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if fetching system data fails.
+    pub async fn find_by_serial_number(
+        &self,
+        serial_number: &str,
+    ) -> Result<Option<ComputerSystem<B>>, Error<B>> {
+        Ok(self.members().await?.into_iter().find(|system| {
+            system
+                .raw()
+                .serial_number
+                .as_ref()
+                .and_then(Option::as_deref)
+                == Some(serial_number)
+        }))
+    }
+
+    /// Find the computer system with the given UUID.
+    ///
+    /// Returns `Ok(None)` when no system reports `uuid`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if fetching system data fails.
+    pub async fn find_by_uuid(&self, uuid: EdmGuid) -> Result<Option<ComputerSystem<B>>, Error<B>> {
+        Ok(self
+            .members()
+            .await?
+            .into_iter()
+            .find(|system| system.raw().uuid.flatten() == Some(uuid)))
+    }
+
+    /// List all computer systems available in this BMC following
+    /// `Members@odata.nextLink` of paginated collection.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if fetching any page of the collection or
+    /// system data fails.
+    pub async fn all_members(&self) -> Result<Vec<ComputerSystem<B>>, Error<B>> {
+        let odata_id_filter = self.bmc.quirks.filter_computer_system_odata_ids();
+        let refs = self
+            .bmc
+            .collection_members::<ComputerSystemSchema>(self.collection.odata_id())
+            .await?;
+        let fetches = refs
+            .iter()
+            .filter(|m| odata_id_filter.is_none_or(|f| f(&m.id().to_string())))
+            .map(|m| ComputerSystem::new(&self.bmc, m, self.read_patch_fn.as_ref()))
+            .collect::<Vec<_>>();
+        self.bmc.fetch_members(fetches).await
     }
 }
 
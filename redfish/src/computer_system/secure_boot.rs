@@ -18,20 +18,58 @@ use crate::schema::secure_boot::SecureBoot as SecureBootSchema;
 use crate::Error;
 use crate::NvBmc;
 use nv_redfish_core::Bmc;
+use nv_redfish_core::EntityTypeRef as _;
+use nv_redfish_core::ModificationResponse;
 use nv_redfish_core::NavProperty;
+use serde::de::IgnoredAny;
+use serde::Serialize;
 use std::convert::identity;
-use std::marker::PhantomData;
 use std::sync::Arc;
 
 #[doc(inline)]
 pub use crate::schema::secure_boot::SecureBootCurrentBootType;
 
+/// UEFI Secure Boot database.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SecureBootDatabase {
+    /// Authorized signature database (`db`).
+    Db,
+    /// Forbidden signature database (`dbx`).
+    Dbx,
+    /// Platform key (`PK`).
+    Pk,
+    /// Key exchange key database (`KEK`).
+    Kek,
+}
+
+impl SecureBootDatabase {
+    /// Identifier of the database in the `SecureBootDatabases`
+    /// collection.
+    #[must_use]
+    pub const fn id(self) -> &'static str {
+        match self {
+            Self::Db => "db",
+            Self::Dbx => "dbx",
+            Self::Pk => "PK",
+            Self::Kek => "KEK",
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct CertificateCreate {
+    #[serde(rename = "CertificateString")]
+    certificate_string: String,
+    #[serde(rename = "CertificateType")]
+    certificate_type: &'static str,
+}
+
 /// Secure boot.
 ///
 /// Provides functions to access Secure Boot functions.
 pub struct SecureBoot<B: Bmc> {
+    bmc: NvBmc<B>,
     data: Arc<SecureBootSchema>,
-    _marker: PhantomData<B>,
 }
 
 impl<B: Bmc> SecureBoot<B> {
@@ -44,8 +82,8 @@ impl<B: Bmc> SecureBoot<B> {
             .await
             .map_err(crate::Error::Bmc)
             .map(|data| Self {
+                bmc: bmc.clone(),
                 data,
-                _marker: PhantomData,
             })
     }
 
@@ -66,4 +104,85 @@ impl<B: Bmc> SecureBoot<B> {
     pub fn secure_boot_current_boot(&self) -> Option<SecureBootCurrentBootType> {
         self.data.secure_boot_current_boot.and_then(identity)
     }
+
+    /// Enroll a DER-encoded certificate into the `db` Secure Boot
+    /// database.
+    ///
+    /// The certificate is posted in PEM form to
+    /// `SecureBootDatabases/{db}/Certificates` of this resource.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if creating the certificate fails.
+    pub async fn enroll_certificate(
+        &self,
+        db: SecureBootDatabase,
+        cert_der: Vec<u8>,
+    ) -> Result<ModificationResponse<()>, Error<B>> {
+        let certificates = self
+            .data
+            .odata_id()
+            .child("SecureBootDatabases")
+            .child(db.id())
+            .child("Certificates");
+        let create = CertificateCreate {
+            certificate_string: der_to_pem(&cert_der),
+            certificate_type: "PEM",
+        };
+        self.bmc
+            .as_ref()
+            .create::<_, IgnoredAny>(&certificates, &create)
+            .await
+            .map_err(Error::Bmc)
+            .map(|response| response.map_entity(|_| ()))
+    }
+}
+
+/// Wrap DER-encoded certificate into PEM.
+fn der_to_pem(der: &[u8]) -> String {
+    const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+    let mut base64 = Vec::with_capacity(der.len().div_ceil(3) * 4);
+    for chunk in der.chunks(3) {
+        let b = [
+            chunk[0],
+            chunk.get(1).copied().unwrap_or(0),
+            chunk.get(2).copied().unwrap_or(0),
+        ];
+        let n = (u32::from(b[0]) << 16) | (u32::from(b[1]) << 8) | u32::from(b[2]);
+        for i in 0..4 {
+            if i <= chunk.len() {
+                base64.push(ALPHABET[(n >> (18 - 6 * i)) as usize & 0x3f]);
+            } else {
+                base64.push(b'=');
+            }
+        }
+    }
+    let mut pem = String::from("-----BEGIN CERTIFICATE-----\n");
+    for line in base64.chunks(64) {
+        pem.extend(line.iter().copied().map(char::from));
+        pem.push('\n');
+    }
+    pem.push_str("-----END CERTIFICATE-----\n");
+    pem
+}
+
+#[cfg(test)]
+mod tests {
+    use super::der_to_pem;
+
+    #[test]
+    fn der_to_pem_encodes_base64_lines() {
+        assert_eq!(
+            der_to_pem(b"foobar"),
+            "-----BEGIN CERTIFICATE-----\nZm9vYmFy\n-----END CERTIFICATE-----\n"
+        );
+        assert!(der_to_pem(b"fo").contains("\nZm8=\n"));
+        assert!(der_to_pem(b"f").contains("\nZg==\n"));
+
+        let pem = der_to_pem(&[0; 96]);
+        let lines = pem.lines().collect::<Vec<_>>();
+        assert_eq!(lines.len(), 4);
+        assert_eq!(lines[1].len(), 64);
+        assert_eq!(lines[2].len(), 64);
+    }
 }
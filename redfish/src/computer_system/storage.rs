@@ -16,12 +16,16 @@
 //! Storage subsystem and its respective properties.
 
 use crate::computer_system::Drive;
+use crate::computer_system::Volume;
+use crate::computer_system::VolumeCreate;
 use crate::schema::storage::Storage as StorageSchema;
 use crate::Error;
 use crate::NvBmc;
 use crate::Resource;
 use crate::ResourceSchema;
 use nv_redfish_core::Bmc;
+use nv_redfish_core::Creatable as _;
+use nv_redfish_core::ModificationResponse;
 use nv_redfish_core::NavProperty;
 use std::sync::Arc;
 
@@ -78,6 +82,57 @@ impl<B: Bmc> Storage<B> {
             Ok(None)
         }
     }
+
+    /// Get volumes created on this storage controller.
+    ///
+    /// Fetches the volume collection and returns a list of [`Volume`] handles.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if fetching volume data fails.
+    pub async fn volumes(&self) -> Result<Option<Vec<Volume<B>>>, Error<B>> {
+        if let Some(volumes_ref) = &self.data.volumes {
+            let volume_collection = self.bmc.expand_property(volumes_ref).await?;
+
+            let mut volumes = Vec::new();
+            for m in &volume_collection.members {
+                volumes.push(Volume::new(&self.bmc, m).await?);
+            }
+
+            Ok(Some(volumes))
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Create a new volume on this storage controller.
+    ///
+    /// Volume creation usually completes asynchronously, in which case
+    /// [`ModificationResponse::Task`] is returned.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - The storage controller does not provide a volume collection
+    /// - The create request fails
+    pub async fn create_volume(
+        &self,
+        create: &VolumeCreate,
+    ) -> Result<ModificationResponse<Volume<B>>, Error<B>> {
+        let volumes_ref = self
+            .data
+            .volumes
+            .as_ref()
+            .ok_or(Error::StorageVolumesNotAvailable)?;
+
+        volumes_ref
+            .create(self.bmc.as_ref(), create)
+            .await
+            .map_err(Error::Bmc)
+            .map(|response| {
+                response.map_entity(|data| Volume::from_data(self.bmc.clone(), Arc::new(data)))
+            })
+    }
 }
 
 impl<B: Bmc> Resource for Storage<B> {
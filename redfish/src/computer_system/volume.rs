@@ -0,0 +1,84 @@
+// SPDX-FileCopyrightText: Copyright (c) 2025 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+
+//! Volume (logical drive) created on a storage subsystem.
+
+use crate::schema::volume::Volume as VolumeSchema;
+use crate::Error;
+use crate::NvBmc;
+use crate::Resource;
+use crate::ResourceSchema;
+use nv_redfish_core::Bmc;
+use nv_redfish_core::Deletable as _;
+use nv_redfish_core::ModificationResponse;
+use nv_redfish_core::NavProperty;
+use std::sync::Arc;
+
+/// Represents a volume of a storage subsystem.
+///
+/// Provides access to volume information and volume deletion.
+pub struct Volume<B: Bmc> {
+    bmc: NvBmc<B>,
+    data: Arc<VolumeSchema>,
+}
+
+impl<B: Bmc> Volume<B> {
+    /// Create a new volume handle.
+    pub(crate) async fn new(
+        bmc: &NvBmc<B>,
+        nav: &NavProperty<VolumeSchema>,
+    ) -> Result<Self, Error<B>> {
+        nav.get(bmc.as_ref())
+            .await
+            .map_err(Error::Bmc)
+            .map(|data| Self::from_data(bmc.clone(), data))
+    }
+
+    pub(crate) const fn from_data(bmc: NvBmc<B>, data: Arc<VolumeSchema>) -> Self {
+        Self { bmc, data }
+    }
+
+    /// Get the raw schema data for this volume.
+    ///
+    /// Returns an `Arc` to the underlying schema, allowing cheap cloning
+    /// and sharing of the data.
+    #[must_use]
+    pub fn raw(&self) -> Arc<VolumeSchema> {
+        self.data.clone()
+    }
+
+    /// Delete this volume.
+    ///
+    /// Deleting a volume usually completes asynchronously, in which case
+    /// [`ModificationResponse::Task`] is returned.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the delete request fails.
+    pub async fn delete(&self) -> Result<ModificationResponse<Self>, Error<B>> {
+        self.data
+            .delete(self.bmc.as_ref())
+            .await
+            .map_err(Error::Bmc)
+            .map(|response| {
+                response.map_entity(|data| Self::from_data(self.bmc.clone(), Arc::new(data)))
+            })
+    }
+}
+
+impl<B: Bmc> Resource for Volume<B> {
+    fn resource_ref(&self) -> &ResourceSchema {
+        &self.data.as_ref().base
+    }
+}
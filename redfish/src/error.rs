@@ -29,17 +29,37 @@ pub enum Error<B: Bmc> {
     /// `slot_defined_user_accounts` feature.
     #[cfg(feature = "accounts")]
     AccountSlotNotAvailable,
+    /// Account with the given user name is not found.
+    #[cfg(feature = "accounts")]
+    AccountNotFound(String),
     /// Action not available for this resource
     ActionNotAvailable,
+    /// Reset type is not in the allowable values advertised by the
+    /// reset action.
+    #[cfg(any(feature = "computer-systems", feature = "managers"))]
+    ResetTypeNotAllowed(crate::resource::ResetType),
+    /// Apply time is not in the supported apply times advertised by
+    /// the settings resource.
+    #[cfg(feature = "bios")]
+    SettingsApplyTimeNotSupported(crate::computer_system::bios::ApplyTime),
     /// Event service does not provide `ServerSentEventUri`
     #[cfg(feature = "event-service")]
     EventServiceServerSentEventUriNotAvailable,
+    /// Event service does not provide `Subscriptions` collection
+    #[cfg(feature = "event-service")]
+    EventServiceSubscriptionsNotAvailable,
     /// Update service does not provide `MultipartHttpPushUri`
     #[cfg(feature = "update-service")]
     UpdateServiceMultipartHttpPushUriNotAvailable,
     /// Update service does not provide `HttpPushUri`
     #[cfg(feature = "update-service-deprecated")]
     UpdateServiceHttpPushUriNotAvailable,
+    /// Session service does not provide `Sessions` collection
+    #[cfg(feature = "session-service")]
+    SessionServiceSessionsNotAvailable,
+    /// Storage does not provide `Volumes` collection
+    #[cfg(feature = "storages")]
+    StorageVolumesNotAvailable,
     /// Task service does not provide a Tasks collection.
     #[cfg(feature = "task-service")]
     TaskServiceTasksUnavailable,
@@ -51,12 +71,34 @@ pub enum Error<B: Bmc> {
         /// Expected TaskService Tasks collection path.
         task_collection: nv_redfish_core::ODataId,
     },
+    /// Task did not reach a terminal state within the allowed number of polls.
+    #[cfg(feature = "task-service")]
+    TaskNotCompleted {
+        /// Task resource.
+        task: nv_redfish_core::ODataId,
+        /// Number of polls made.
+        attempts: u32,
+    },
     /// Metric definitions are not available for telemetry service
     #[cfg(feature = "telemetry-service")]
     MetricDefinitionsNotAvailable,
     /// Metric report definitions are not available for telemetry service
     #[cfg(feature = "telemetry-service")]
     MetricReportDefinitionsNotAvailable,
+    /// Metric definition is not a member of telemetry service
+    /// `MetricDefinitions` collection
+    #[cfg(feature = "telemetry-service")]
+    MetricDefinitionNotAvailable(nv_redfish_core::ODataId),
+    /// Metric report definition is not a member of telemetry service
+    /// `MetricReportDefinitions` collection
+    #[cfg(feature = "telemetry-service")]
+    MetricReportDefinitionNotAvailable(nv_redfish_core::ODataId),
+    /// Metric report with the given id is not found in telemetry service
+    /// `MetricReports` collection
+    #[cfg(feature = "telemetry-service")]
+    MetricReportNotFound(String),
+    /// Collection `Members@odata.nextLink` points to already visited page.
+    CollectionNextLinkLoop(nv_redfish_core::ODataId),
     /// JSON parse error.
     Json(JsonError),
 }
@@ -71,13 +113,32 @@ impl<B: Bmc> Display for Error<B> {
             Self::AccountSlotNotAvailable => {
                 write!(f, "Free account slot is not found")
             }
+            #[cfg(feature = "accounts")]
+            Self::AccountNotFound(user_name) => {
+                write!(f, "Account with user name {user_name} is not found")
+            }
             Self::ActionNotAvailable => {
                 write!(f, "Action is not available for this resource")
             }
+            #[cfg(any(feature = "computer-systems", feature = "managers"))]
+            Self::ResetTypeNotAllowed(reset_type) => {
+                write!(f, "Reset type {reset_type:?} is not allowed by the resource")
+            }
+            #[cfg(feature = "bios")]
+            Self::SettingsApplyTimeNotSupported(apply_time) => {
+                write!(f, "Apply time {apply_time:?} is not supported by the settings")
+            }
+            Self::CollectionNextLinkLoop(id) => {
+                write!(f, "Collection next link loop detected at page {id}")
+            }
             #[cfg(feature = "event-service")]
             Self::EventServiceServerSentEventUriNotAvailable => {
                 write!(f, "Event service does not provide ServerSentEventUri")
             }
+            #[cfg(feature = "event-service")]
+            Self::EventServiceSubscriptionsNotAvailable => {
+                write!(f, "Event service does not provide Subscriptions collection")
+            }
             #[cfg(feature = "update-service")]
             Self::UpdateServiceMultipartHttpPushUriNotAvailable => {
                 write!(f, "Update service does not provide MultipartHttpPushUri")
@@ -86,6 +147,14 @@ impl<B: Bmc> Display for Error<B> {
             Self::UpdateServiceHttpPushUriNotAvailable => {
                 write!(f, "Update service does not provide HttpPushUri")
             }
+            #[cfg(feature = "session-service")]
+            Self::SessionServiceSessionsNotAvailable => {
+                write!(f, "Session service does not provide Sessions collection")
+            }
+            #[cfg(feature = "storages")]
+            Self::StorageVolumesNotAvailable => {
+                write!(f, "Storage does not provide Volumes collection")
+            }
             #[cfg(feature = "task-service")]
             Self::TaskServiceTasksUnavailable => {
                 write!(f, "Task service does not provide Tasks collection")
@@ -98,6 +167,10 @@ impl<B: Bmc> Display for Error<B> {
                 f,
                 "Task location {task_location} is not in TaskService Tasks collection {task_collection}"
             ),
+            #[cfg(feature = "task-service")]
+            Self::TaskNotCompleted { task, attempts } => {
+                write!(f, "Task {task} did not complete after {attempts} polls")
+            }
             #[cfg(feature = "telemetry-service")]
             Self::MetricDefinitionsNotAvailable => {
                 write!(f, "Metric definitions are not available")
@@ -106,6 +179,18 @@ impl<B: Bmc> Display for Error<B> {
             Self::MetricReportDefinitionsNotAvailable => {
                 write!(f, "Metric report definitions are not available")
             }
+            #[cfg(feature = "telemetry-service")]
+            Self::MetricDefinitionNotAvailable(id) => {
+                write!(f, "Metric definition {id} is not available")
+            }
+            #[cfg(feature = "telemetry-service")]
+            Self::MetricReportDefinitionNotAvailable(id) => {
+                write!(f, "Metric report definition {id} is not available")
+            }
+            #[cfg(feature = "telemetry-service")]
+            Self::MetricReportNotFound(id) => {
+                write!(f, "Metric report {id} is not found")
+            }
         }
     }
 }
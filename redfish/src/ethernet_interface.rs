@@ -15,8 +15,21 @@
 
 //! Ethernet interfaces
 //!
+//! # Example
+//!
+//! ```ignore
+//! use nv_redfish::ethernet_interface::Ipv4addressUpdate;
+//!
+//! let config = Ipv4addressUpdate::builder()
+//!     .with_address("192.168.0.10".into())
+//!     .with_subnet_mask("255.255.255.0".into())
+//!     .with_gateway("192.168.0.1".into())
+//!     .build();
+//! interface.update_ipv4(config).await?;
+//! ```
 
 use crate::mac_address::MacAddress;
+use crate::schema::ethernet_interface::Dhcpv4configurationUpdate;
 use crate::schema::ethernet_interface::EthernetInterface as EthernetInterfaceSchema;
 use crate::schema::ethernet_interface_collection::EthernetInterfaceCollection as EthernetInterfaceCollectionSchema;
 use crate::Error;
@@ -24,13 +37,26 @@ use crate::NvBmc;
 use crate::Resource;
 use crate::ResourceSchema;
 use nv_redfish_core::Bmc;
+use nv_redfish_core::EntityTypeRef as _;
+use nv_redfish_core::ModificationResponse;
 use nv_redfish_core::NavProperty;
-use std::marker::PhantomData;
 use std::sync::Arc;
 use tagged_types::TaggedType;
 
+#[doc(inline)]
+pub use crate::schema::ethernet_interface::EthernetInterfaceUpdate;
 #[doc(inline)]
 pub use crate::schema::ethernet_interface::LinkStatus;
+#[doc(inline)]
+pub use crate::schema::ip_addresses::Ipv4address;
+#[doc(inline)]
+pub use crate::schema::ip_addresses::Ipv4addressUpdate;
+#[doc(inline)]
+pub use crate::schema::ip_addresses::Ipv6address;
+#[doc(inline)]
+pub use crate::schema::vlan_network_interface::Vlan;
+#[doc(inline)]
+pub use crate::schema::vlan_network_interface::VlanUpdate;
 
 /// Ethernet interfaces collection.
 ///
@@ -83,8 +109,8 @@ pub enum UefiDevicePathTag {}
 ///
 /// Provides functions to access ethernet interface.
 pub struct EthernetInterface<B: Bmc> {
+    bmc: NvBmc<B>,
     data: Arc<EthernetInterfaceSchema>,
-    _marker: PhantomData<B>,
 }
 
 impl<B: Bmc> EthernetInterface<B> {
@@ -97,8 +123,8 @@ impl<B: Bmc> EthernetInterface<B> {
             .await
             .map_err(crate::Error::Bmc)
             .map(|data| Self {
+                bmc: bmc.clone(),
                 data,
-                _marker: PhantomData,
             })
     }
 
@@ -161,6 +187,83 @@ impl<B: Bmc> EthernetInterface<B> {
             .map(String::as_str)
             .map(UefiDevicePath::new)
     }
+
+    /// IPv4 addresses currently assigned to the interface.
+    #[must_use]
+    pub fn ipv4_addresses(&self) -> &[Ipv4address] {
+        self.data.ipv4addresses.as_deref().unwrap_or_default()
+    }
+
+    /// IPv6 addresses currently assigned to the interface.
+    #[must_use]
+    pub fn ipv6_addresses(&self) -> &[Ipv6address] {
+        self.data.ipv6addresses.as_deref().unwrap_or_default()
+    }
+
+    /// VLAN configuration of the interface.
+    #[must_use]
+    pub fn vlan(&self) -> Option<&Vlan> {
+        self.data.vlan.as_ref()
+    }
+
+    /// Update this ethernet interface.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if updating the ethernet interface fails.
+    pub async fn update(
+        &self,
+        update: &EthernetInterfaceUpdate,
+    ) -> Result<ModificationResponse<Self>, Error<B>> {
+        self.bmc
+            .as_ref()
+            .update::<_, NavProperty<EthernetInterfaceSchema>>(
+                self.data.odata_id(),
+                self.data.etag(),
+                update,
+            )
+            .await
+            .map_err(Error::Bmc)?
+            .try_map_entity_async(|nav| async move { Self::new(&self.bmc, &nav).await })
+            .await
+    }
+
+    /// Configure static IPv4 addressing.
+    ///
+    /// Disables DHCPv4 and replaces `IPv4StaticAddresses` with `config`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if updating the ethernet interface fails.
+    pub async fn update_ipv4(
+        &self,
+        config: Ipv4addressUpdate,
+    ) -> Result<ModificationResponse<Self>, Error<B>> {
+        let update = EthernetInterfaceUpdate::builder()
+            .with_dhcpv4(
+                Dhcpv4configurationUpdate::builder()
+                    .with_dhcp_enabled(false)
+                    .build(),
+            )
+            .with_ipv4static_addresses(vec![config])
+            .build();
+        self.update(&update).await
+    }
+
+    /// Enable or disable VLAN tagging on the interface.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if updating the ethernet interface fails.
+    pub async fn set_vlan_enabled(
+        &self,
+        enabled: bool,
+    ) -> Result<ModificationResponse<Self>, Error<B>> {
+        let update = EthernetInterfaceUpdate::builder()
+            .with_vlan(VlanUpdate::builder().with_vlan_enable(enabled).build())
+            .build();
+        self.update(&update).await
+    }
 }
 
 impl<B: Bmc> Resource for EthernetInterface<B> {
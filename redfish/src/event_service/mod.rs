@@ -18,6 +18,8 @@
 //! This module provides typed access to Redfish `EventService`.
 
 mod patch;
+mod reconnect;
+mod subscription;
 
 use crate::patch_support::ReadPatchFn;
 use crate::schema::event_service::EventService as EventServiceSchema;
@@ -27,21 +29,35 @@ use crate::Resource;
 use crate::ResourceSchema;
 use crate::ServiceRoot;
 use futures_util::future;
+use futures_util::StreamExt as _;
 use futures_util::TryStreamExt as _;
 use nv_redfish_core::odata::ODataType;
 use nv_redfish_core::Bmc;
 use nv_redfish_core::BoxTryStream;
+use nv_redfish_core::ModificationResponse;
 use serde::de;
 use serde::Deserialize;
 use serde::Deserializer;
 use serde_json::Value as JsonValue;
+use std::future::Future;
 use std::sync::Arc;
+use std::time::Duration;
 
 #[doc(inline)]
 pub use crate::schema::metric_report::MetricReport;
 
 #[doc(inline)]
 pub use crate::schema::event::Event;
+#[doc(inline)]
+pub use crate::schema::event::EventType;
+#[doc(inline)]
+pub use reconnect::ReconnectPolicy;
+#[doc(inline)]
+pub use subscription::EventDestination;
+#[doc(inline)]
+pub use subscription::EventSubscription;
+#[doc(inline)]
+pub use subscription::EventSubscriptionCreate;
 
 /// SSE payload that can contain either an `EventRecord` or a `MetricReport`.
 #[derive(Debug)]
@@ -52,6 +68,20 @@ pub enum EventStreamPayload {
     MetricReport(MetricReport),
 }
 
+impl EventStreamPayload {
+    /// Identifier of the payload.
+    ///
+    /// Services use it as SSE event id, so it is sent as `Last-Event-ID`
+    /// when a dropped stream is re-opened.
+    #[must_use]
+    pub fn id(&self) -> &str {
+        match self {
+            Self::Event(event) => &event.base.id,
+            Self::MetricReport(report) => &report.base.id,
+        }
+    }
+}
+
 impl<'de> Deserialize<'de> for EventStreamPayload {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
@@ -137,6 +167,19 @@ impl<B: Bmc> EventService<B> {
         self.data.clone()
     }
 
+    /// Event types that the service advertises for subscriptions.
+    ///
+    /// Reads deprecated `EventTypesForSubscription`. Returns an empty
+    /// vector when the service does not report it.
+    #[must_use]
+    #[allow(deprecated)]
+    pub fn supported_event_types(&self) -> Vec<EventType> {
+        self.data
+            .event_types_for_subscription
+            .clone()
+            .unwrap_or_default()
+    }
+
     /// Open an SSE stream of Redfish event payloads.
     ///
     /// Payload kind is selected by `@odata.type`:
@@ -168,18 +211,195 @@ impl<B: Bmc> EventService<B> {
             .map_err(Error::Bmc)?;
 
         let sse_read_patches = self.sse_read_patches.clone();
-        let stream = stream.map_err(Error::Bmc).and_then(move |payload| {
-            let patched = sse_read_patches
-                .iter()
-                .fold(payload, |acc, patch| patch(acc));
-
-            future::ready(
-                serde_json::from_value::<EventStreamPayload>(patched).map_err(Error::Json),
-            )
-        });
+        let stream = stream
+            .map_err(Error::Bmc)
+            .and_then(move |payload| future::ready(parse_payload(&sse_read_patches, payload)));
+
+        Ok(Box::pin(stream))
+    }
+
+    /// Open an SSE stream of Redfish event payloads that is re-opened
+    /// when it drops.
+    ///
+    /// Unlike [`Self::events`], transport errors and end of stream do
+    /// not end the returned stream. After `policy` backoff the stream is
+    /// re-opened with `Last-Event-ID` set to the id of the last received
+    /// payload, so the service can replay missed events. The stream ends
+    /// with the last error once `policy.max_attempts` consecutive
+    /// reconnect attempts fail.
+    ///
+    /// `sleep` is used to wait between reconnect attempts, which keeps
+    /// this crate independent of the async runtime (for example, pass
+    /// `tokio::time::sleep`).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - `ServerSentEventUri` is not present in `EventService`
+    /// - opening the initial SSE stream fails
+    pub async fn events_reconnecting<S, F>(
+        &self,
+        policy: ReconnectPolicy,
+        sleep: S,
+    ) -> Result<BoxTryStream<EventStreamPayload, Error<B>>, Error<B>>
+    where
+        B: 'static,
+        B::Error: 'static,
+        S: Fn(Duration) -> F + Send + 'static,
+        F: Future<Output = ()> + Send,
+    {
+        let stream_uri = self
+            .data
+            .server_sent_event_uri
+            .as_ref()
+            .ok_or(Error::EventServiceServerSentEventUriNotAvailable)?;
 
+        let stream = self
+            .bmc
+            .as_ref()
+            .stream::<JsonValue>(stream_uri)
+            .await
+            .map_err(Error::Bmc)?;
+
+        Ok(reconnect::reconnecting(
+            self.bmc.clone(),
+            stream_uri.clone(),
+            self.sse_read_patches.clone(),
+            policy,
+            sleep,
+            stream,
+        ))
+    }
+
+    /// Open an SSE stream of Redfish event payloads that match `predicate`.
+    ///
+    /// Payloads for which `predicate` returns `false` are dropped. Errors
+    /// are always passed through.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if opening the stream fails (see [`Self::events`]).
+    pub async fn events_filtered<P>(
+        &self,
+        predicate: P,
+    ) -> Result<BoxTryStream<EventStreamPayload, Error<B>>, Error<B>>
+    where
+        B: 'static,
+        B::Error: 'static,
+        P: Fn(&EventStreamPayload) -> bool + Send + 'static,
+    {
+        let stream = self
+            .events()
+            .await?
+            .filter(move |item| future::ready(item.as_ref().map_or(true, &predicate)));
         Ok(Box::pin(stream))
     }
+
+    /// Open an SSE stream that yields only [`EventStreamPayload::Event`]
+    /// payloads.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if opening the stream fails (see [`Self::events`]).
+    pub async fn only_events(&self) -> Result<BoxTryStream<EventStreamPayload, Error<B>>, Error<B>>
+    where
+        B: 'static,
+        B::Error: 'static,
+    {
+        self.events_filtered(|payload| matches!(payload, EventStreamPayload::Event(_)))
+            .await
+    }
+
+    /// Open an SSE stream that yields only
+    /// [`EventStreamPayload::MetricReport`] payloads.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if opening the stream fails (see [`Self::events`]).
+    pub async fn only_metric_reports(
+        &self,
+    ) -> Result<BoxTryStream<EventStreamPayload, Error<B>>, Error<B>>
+    where
+        B: 'static,
+        B::Error: 'static,
+    {
+        self.events_filtered(|payload| matches!(payload, EventStreamPayload::MetricReport(_)))
+            .await
+    }
+
+    /// Subscribe `destination` to events of `event_types`.
+    ///
+    /// `context` is returned by BMC in every delivered event.
+    ///
+    /// Returns one of the following modification outcomes:
+    ///
+    /// - `ModificationResponse::Entity` contains the created subscription.
+    /// - `ModificationResponse::Task` identifies an asynchronous operation.
+    /// - `ModificationResponse::Empty` reports synchronous success without a
+    ///   response body.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - the event service does not expose a `Subscriptions` collection
+    /// - creating the subscription fails
+    pub async fn subscribe(
+        &self,
+        destination: String,
+        event_types: Vec<EventType>,
+        context: Option<String>,
+    ) -> Result<ModificationResponse<EventSubscription<B>>, Error<B>> {
+        let subscriptions = self
+            .data
+            .subscriptions
+            .as_ref()
+            .ok_or(Error::EventServiceSubscriptionsNotAvailable)?;
+        let create = EventSubscriptionCreate::new(destination, event_types, context);
+
+        self.bmc
+            .as_ref()
+            .create::<_, EventDestination>(&subscriptions.odata_id, &create)
+            .await
+            .map_err(Error::Bmc)
+            .map(|response| {
+                response.map_entity(|data| EventSubscription::from_data(self.bmc.clone(), data))
+            })
+    }
+
+    /// List event subscriptions of this service.
+    ///
+    /// `Members@odata.nextLink` of paginated collection is followed.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - the event service does not expose a `Subscriptions` collection
+    /// - fetching the collection or any subscription fails
+    pub async fn subscriptions(&self) -> Result<Vec<EventSubscription<B>>, Error<B>> {
+        let subscriptions = self
+            .data
+            .subscriptions
+            .as_ref()
+            .ok_or(Error::EventServiceSubscriptionsNotAvailable)?;
+        let refs = self
+            .bmc
+            .collection_members::<EventDestination>(&subscriptions.odata_id)
+            .await?;
+        let fetches = refs
+            .iter()
+            .map(|m| EventSubscription::new(&self.bmc, m))
+            .collect::<Vec<_>>();
+        self.bmc.fetch_members(fetches).await
+    }
+}
+
+/// Apply SSE read patches to `payload` and deserialize it.
+fn parse_payload<B: Bmc>(
+    patches: &[ReadPatchFn],
+    payload: JsonValue,
+) -> Result<EventStreamPayload, Error<B>> {
+    let patched = patches.iter().fold(payload, |acc, patch| patch(acc));
+    serde_json::from_value(patched).map_err(Error::Json)
 }
 
 impl<B: Bmc> Resource for EventService<B> {
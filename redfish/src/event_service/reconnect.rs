@@ -0,0 +1,172 @@
+// SPDX-FileCopyrightText: Copyright (c) 2026 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Reconnecting SSE event stream.
+
+use super::parse_payload;
+use super::EventStreamPayload;
+use crate::patch_support::ReadPatchFn;
+use crate::Error;
+use crate::NvBmc;
+use futures_util::stream;
+use futures_util::StreamExt as _;
+use nv_redfish_core::Bmc;
+use nv_redfish_core::BoxTryStream;
+use serde_json::Value as JsonValue;
+use std::future::Future;
+use std::time::Duration;
+
+/// Reconnection policy of
+/// [`EventService::events_reconnecting`](crate::event_service::EventService::events_reconnecting).
+#[derive(Debug, Clone, Copy)]
+pub struct ReconnectPolicy {
+    /// Delay before the first reconnect attempt after the stream drops.
+    pub initial_backoff: Duration,
+    /// Upper bound of the delay. The delay doubles after every failed
+    /// reconnect attempt.
+    pub max_backoff: Duration,
+    /// Number of consecutive failed reconnect attempts after which the
+    /// stream ends with the last error. An attempt fails if the stream
+    /// cannot be re-opened or if the re-opened stream ends before
+    /// delivering any payload. `None` retries forever.
+    pub max_attempts: Option<u32>,
+}
+
+impl Default for ReconnectPolicy {
+    fn default() -> Self {
+        Self {
+            initial_backoff: Duration::from_secs(1),
+            max_backoff: Duration::from_secs(60),
+            max_attempts: None,
+        }
+    }
+}
+
+impl ReconnectPolicy {
+    fn backoff(&self, failures: u32) -> Duration {
+        self.initial_backoff
+            .saturating_mul(2_u32.saturating_pow(failures))
+            .min(self.max_backoff)
+    }
+}
+
+struct State<B: Bmc, S> {
+    bmc: NvBmc<B>,
+    uri: String,
+    patches: Vec<ReadPatchFn>,
+    policy: ReconnectPolicy,
+    sleep: S,
+    stream: Option<BoxTryStream<JsonValue, B::Error>>,
+    // Current stream is re-opened and has not delivered any payload yet.
+    reopened: bool,
+    last_error: Option<B::Error>,
+    last_event_id: Option<String>,
+    failures: u32,
+}
+
+impl<B: Bmc, S> State<B, S> {
+    fn failed(&mut self) -> bool {
+        self.failures = self.failures.saturating_add(1);
+        self.policy
+            .max_attempts
+            .is_some_and(|max| self.failures >= max)
+    }
+}
+
+/// Wrap already opened `stream` of `uri` so that it is re-opened when
+/// it drops.
+///
+/// Both transport errors and end of stream are treated as a dropped
+/// connection. The stream is re-opened with the id of the last parsed
+/// payload as `Last-Event-ID` after `policy` backoff. A re-opened
+/// stream that ends before delivering any payload counts as a failed
+/// attempt; the last transport error is returned when the attempts are
+/// exhausted.
+pub(super) fn reconnecting<B, S, F>(
+    bmc: NvBmc<B>,
+    uri: String,
+    patches: Vec<ReadPatchFn>,
+    policy: ReconnectPolicy,
+    sleep: S,
+    stream: BoxTryStream<JsonValue, B::Error>,
+) -> BoxTryStream<EventStreamPayload, Error<B>>
+where
+    B: Bmc + 'static,
+    B::Error: 'static,
+    S: Fn(Duration) -> F + Send + 'static,
+    F: Future<Output = ()> + Send,
+{
+    let state = State {
+        bmc,
+        uri,
+        patches,
+        policy,
+        sleep,
+        stream: Some(stream),
+        reopened: false,
+        last_error: None,
+        last_event_id: None,
+        failures: 0,
+    };
+    Box::pin(stream::unfold(Some(state), |state| async move {
+        let mut state = state?;
+        loop {
+            if let Some(inner) = state.stream.as_mut() {
+                match inner.next().await {
+                    Some(Ok(payload)) => {
+                        state.failures = 0;
+                        state.reopened = false;
+                        state.last_error = None;
+                        let item = parse_payload(&state.patches, payload);
+                        if let Ok(payload) = &item {
+                            state.last_event_id = Some(payload.id().to_string());
+                        }
+                        return Some((item, Some(state)));
+                    }
+                    Some(Err(err)) => state.last_error = Some(err),
+                    None => (),
+                }
+                state.stream = None;
+                if state.reopened {
+                    state.reopened = false;
+                    if state.failed() {
+                        return state
+                            .last_error
+                            .take()
+                            .map(|err| (Err(Error::Bmc(err)), None));
+                    }
+                }
+            }
+            (state.sleep)(state.policy.backoff(state.failures)).await;
+            match state
+                .bmc
+                .as_ref()
+                .stream_resume::<JsonValue>(&state.uri, state.last_event_id.as_deref())
+                .await
+            {
+                Ok(inner) => {
+                    state.stream = Some(inner);
+                    state.reopened = true;
+                }
+                Err(err) => {
+                    if state.failed() {
+                        return Some((Err(Error::Bmc(err)), None));
+                    }
+                    state.last_error = Some(err);
+                }
+            }
+        }
+    }))
+}
@@ -0,0 +1,156 @@
+// SPDX-FileCopyrightText: Copyright (c) 2026 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Event subscriptions (`EventDestination` resources).
+//!
+//! Only the properties needed to create, identify and delete a
+//! subscription are modeled here.
+
+use crate::schema::event::EventType;
+use crate::Error;
+use crate::NvBmc;
+use nv_redfish_core::Bmc;
+use nv_redfish_core::EntityTypeRef;
+use nv_redfish_core::ModificationResponse;
+use nv_redfish_core::NavProperty;
+use nv_redfish_core::ODataETag;
+use nv_redfish_core::ODataId;
+use serde::Deserialize;
+use serde::Serialize;
+use std::sync::Arc;
+
+/// Protocol used by subscriptions created with
+/// [`EventService::subscribe`](crate::event_service::EventService::subscribe).
+const REDFISH_PROTOCOL: &str = "Redfish";
+
+/// Request body of a new event subscription.
+#[derive(Debug, Serialize)]
+pub struct EventSubscriptionCreate {
+    /// URI events are delivered to.
+    #[serde(rename = "Destination")]
+    pub destination: String,
+    /// Types of events to deliver.
+    #[serde(rename = "EventTypes")]
+    pub event_types: Vec<EventType>,
+    /// Opaque string returned in every delivered event.
+    #[serde(rename = "Context", skip_serializing_if = "Option::is_none")]
+    pub context: Option<String>,
+    /// Event delivery protocol.
+    #[serde(rename = "Protocol")]
+    pub protocol: String,
+}
+
+impl EventSubscriptionCreate {
+    /// Subscription delivering `event_types` to `destination` using
+    /// Redfish protocol.
+    #[must_use]
+    pub fn new(destination: String, event_types: Vec<EventType>, context: Option<String>) -> Self {
+        Self {
+            destination,
+            event_types,
+            context,
+            protocol: REDFISH_PROTOCOL.into(),
+        }
+    }
+}
+
+/// Event subscription data as returned by BMC.
+#[derive(Debug, Deserialize)]
+pub struct EventDestination {
+    /// `OData` identifier of the subscription.
+    #[serde(rename = "@odata.id")]
+    pub odata_id: ODataId,
+    /// `ETag` of the subscription.
+    #[serde(rename = "@odata.etag", default)]
+    pub odata_etag: Option<ODataETag>,
+    /// Identifier of the subscription.
+    #[serde(rename = "Id")]
+    pub id: String,
+    /// URI events are delivered to.
+    #[serde(rename = "Destination", default)]
+    pub destination: Option<String>,
+    /// Types of events delivered.
+    #[serde(rename = "EventTypes", default)]
+    pub event_types: Vec<EventType>,
+    /// Opaque string returned in every delivered event.
+    #[serde(rename = "Context", default)]
+    pub context: Option<String>,
+}
+
+impl EntityTypeRef for EventDestination {
+    fn odata_id(&self) -> &ODataId {
+        &self.odata_id
+    }
+
+    fn etag(&self) -> Option<&ODataETag> {
+        self.odata_etag.as_ref()
+    }
+}
+
+/// Event subscription.
+pub struct EventSubscription<B: Bmc> {
+    bmc: NvBmc<B>,
+    data: Arc<EventDestination>,
+}
+
+impl<B: Bmc> EventSubscription<B> {
+    pub(crate) async fn new(
+        bmc: &NvBmc<B>,
+        nav: &NavProperty<EventDestination>,
+    ) -> Result<Self, Error<B>> {
+        nav.get(bmc.as_ref())
+            .await
+            .map_err(Error::Bmc)
+            .map(|data| Self {
+                bmc: bmc.clone(),
+                data,
+            })
+    }
+
+    pub(crate) fn from_data(bmc: NvBmc<B>, data: EventDestination) -> Self {
+        Self {
+            bmc,
+            data: Arc::new(data),
+        }
+    }
+
+    /// Get raw subscription data.
+    #[must_use]
+    pub fn raw(&self) -> Arc<EventDestination> {
+        self.data.clone()
+    }
+
+    /// Delete this subscription.
+    ///
+    /// Returns one of the following modification outcomes:
+    ///
+    /// - `ModificationResponse::Entity` contains the subscription returned
+    ///   by the server.
+    /// - `ModificationResponse::Task` identifies an asynchronous operation.
+    /// - `ModificationResponse::Empty` reports synchronous success without a
+    ///   response body.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if deleting the subscription fails.
+    pub async fn delete(&self) -> Result<ModificationResponse<Self>, Error<B>> {
+        self.bmc
+            .as_ref()
+            .delete::<EventDestination>(self.data.odata_id())
+            .await
+            .map_err(Error::Bmc)
+            .map(|response| response.map_entity(|data| Self::from_data(self.bmc.clone(), data)))
+    }
+}
@@ -88,6 +88,9 @@ pub mod mac_address;
 /// Accounts Service.
 #[cfg(feature = "accounts")]
 pub mod account;
+/// Certificate Service.
+#[cfg(feature = "certificate-service")]
+pub mod certificate_service;
 /// Chassis.
 #[cfg(feature = "chassis")]
 pub mod chassis;
@@ -126,6 +129,9 @@ pub mod pcie_device;
 /// Power equipment.
 #[cfg(feature = "power-equipment")]
 pub mod power_equipment;
+/// Message registries.
+#[cfg(feature = "message-registries")]
+pub mod registry;
 /// Metrics and sensor abstraction.
 #[cfg(feature = "sensors")]
 pub mod sensor;
@@ -138,6 +144,9 @@ pub mod task_service;
 /// Telemetry Service.
 #[cfg(feature = "telemetry-service")]
 pub mod telemetry_service;
+/// Virtual media.
+#[cfg(feature = "virtual-media")]
+pub mod virtual_media;
 
 /// Individual OEM extensions support.
 #[cfg(feature = "oem")]
@@ -25,10 +25,15 @@ use crate::NvBmc;
 use crate::Resource;
 use crate::ResourceSchema;
 use nv_redfish_core::Bmc;
+use nv_redfish_core::EdmDateTimeOffset;
+use nv_redfish_core::FilterQuery;
 use nv_redfish_core::ModificationResponse;
 use nv_redfish_core::NavProperty;
 use std::sync::Arc;
 
+/// Log entry property used for time-range filtering.
+const CREATED: &str = "Created";
+
 /// Log service.
 ///
 /// Provides functions to access log entries and perform log operations.
@@ -88,7 +93,7 @@ impl<B: Bmc> LogService<B> {
     /// - Filtering log entries data fails
     pub async fn filter_entries(
         &self,
-        filter: nv_redfish_core::FilterQuery,
+        filter: FilterQuery,
     ) -> Result<Option<Vec<Arc<LogEntry>>>, Error<B>> {
         if let Some(entries_ref) = &self.data.entries {
             let entries_collection = entries_ref
@@ -104,6 +109,40 @@ impl<B: Bmc> LogService<B> {
         }
     }
 
+    /// List log entries created at or after `since`.
+    ///
+    /// Filtering is performed by the BMC using `$filter` on `Created`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - The log service does not have a log entries collection
+    /// - Filtering log entries data fails
+    pub async fn list_entries_since(
+        &self,
+        since: EdmDateTimeOffset,
+    ) -> Result<Option<Vec<Arc<LogEntry>>>, Error<B>> {
+        self.filter_entries(FilterQuery::ge(&CREATED, since)).await
+    }
+
+    /// List log entries created between `from` and `to` (both inclusive).
+    ///
+    /// Filtering is performed by the BMC using `$filter` on `Created`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - The log service does not have a log entries collection
+    /// - Filtering log entries data fails
+    pub async fn list_entries_range(
+        &self,
+        from: EdmDateTimeOffset,
+        to: EdmDateTimeOffset,
+    ) -> Result<Option<Vec<Arc<LogEntry>>>, Error<B>> {
+        self.filter_entries(FilterQuery::ge(&CREATED, from).and().le_then(&CREATED, to))
+            .await
+    }
+
     /// Clear all log entries.
     ///
     /// # Arguments
@@ -15,6 +15,7 @@
 
 use crate::resource::ResetType;
 use crate::schema::manager::Manager as ManagerSchema;
+use crate::schema::manager::ManagerType;
 use crate::schema::manager::ResetToDefaultsType as ManagerResetToDefaultsType;
 use crate::Error;
 use crate::NvBmc;
@@ -23,6 +24,7 @@ use crate::ResourceSchema;
 use nv_redfish_core::Bmc;
 use nv_redfish_core::ModificationResponse;
 use nv_redfish_core::NavProperty;
+use serde_json::to_value;
 use std::sync::Arc;
 
 #[cfg(feature = "manager-network-protocol")]
@@ -43,6 +45,8 @@ use crate::oem::hpe::manager::HpeManager;
 use crate::oem::lenovo::manager::LenovoManager;
 #[cfg(feature = "oem-supermicro")]
 use crate::oem::supermicro::manager::SupermicroManager;
+#[cfg(feature = "virtual-media")]
+use crate::virtual_media::VirtualMedia;
 
 /// Represents a manager (BMC) in the system.
 ///
@@ -77,6 +81,12 @@ impl<B: Bmc> Manager<B> {
         self.data.clone()
     }
 
+    /// Type of this manager.
+    #[must_use]
+    pub fn manager_type(&self) -> Option<ManagerType> {
+        self.data.manager_type
+    }
+
     /// Get the network protocol resource associated with this manager.
     ///
     /// Returns `Ok(None)` when the network protocol link is absent.
@@ -97,10 +107,14 @@ impl<B: Bmc> Manager<B> {
 
     /// Reset this manager.
     ///
+    /// When the action advertises `ResetType@Redfish.AllowableValues`,
+    /// `reset_type` is checked against it before the action is invoked.
+    ///
     /// # Errors
     ///
-    /// Returns an error if the manager does not support the `Reset` action or
-    /// if invoking the action fails.
+    /// Returns an error if the manager does not support the `Reset` action,
+    /// if `reset_type` is not among the advertised allowable values
+    /// ([`Error::ResetTypeNotAllowed`]) or if invoking the action fails.
     pub async fn reset(
         &self,
         reset_type: Option<ResetType>,
@@ -114,8 +128,13 @@ impl<B: Bmc> Manager<B> {
             .as_ref()
             .ok_or(Error::ActionNotAvailable)?;
 
-        if actions.reset.is_none() {
-            return Err(Error::ActionNotAvailable);
+        let reset = actions.reset.as_ref().ok_or(Error::ActionNotAvailable)?;
+        if let (Some(reset_type), Some(allowed)) = (reset_type, reset.allowable_values("ResetType"))
+        {
+            let value = to_value(reset_type).map_err(Error::Json)?;
+            if !allowed.iter().any(|v| value.as_str() == Some(v.as_str())) {
+                return Err(Error::ResetTypeNotAllowed(reset_type));
+            }
         }
 
         actions
@@ -217,6 +236,22 @@ impl<B: Bmc> Manager<B> {
         }
     }
 
+    /// Get virtual media devices for this manager.
+    ///
+    /// Returns `Ok(None)` when the virtual media link is absent.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if fetching virtual media data fails.
+    #[cfg(feature = "virtual-media")]
+    pub async fn virtual_media(&self) -> Result<Option<Vec<VirtualMedia<B>>>, Error<B>> {
+        if let Some(p) = &self.data.virtual_media {
+            VirtualMedia::list(&self.bmc, p).await.map(Some)
+        } else {
+            Ok(None)
+        }
+    }
+
     /// Get Dell Manager attributes for this manager.
     ///
     /// Returns `Ok(None)` when the manager does not include `Oem.Dell`.
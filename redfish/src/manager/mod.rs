@@ -40,6 +40,8 @@ pub use item::Manager;
 #[cfg(feature = "manager-network-protocol")]
 pub use network_protocol::ManagerNetworkProtocol;
 
+#[doc(inline)]
+pub use crate::schema::manager::ManagerType;
 #[doc(inline)]
 pub use crate::schema::manager::ResetToDefaultsType as ManagerResetToDefaultsType;
 
@@ -96,11 +98,33 @@ impl<B: Bmc> ManagerCollection<B> {
     ///
     /// Returns an error if fetching manager data fails.
     pub async fn members(&self) -> Result<Vec<Manager<B>>, Error<B>> {
-        let mut members = Vec::new();
-        for m in &self.collection.members {
-            members.push(Manager::new(&self.bmc, m).await?);
-        }
-        Ok(members)
+        let fetches = self
+            .collection
+            .members
+            .iter()
+            .map(|m| Manager::new(&self.bmc, m))
+            .collect::<Vec<_>>();
+        self.bmc.fetch_members(fetches).await
+    }
+
+    /// List managers of the given `manager_type`.
+    ///
+    /// Managers that do not report `ManagerType` are skipped. Returns an
+    /// empty vector when no manager matches.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if fetching manager data fails.
+    pub async fn by_manager_type(
+        &self,
+        manager_type: ManagerType,
+    ) -> Result<Vec<Manager<B>>, Error<B>> {
+        Ok(self
+            .members()
+            .await?
+            .into_iter()
+            .filter(|m| m.manager_type() == Some(manager_type))
+            .collect())
     }
 }
 
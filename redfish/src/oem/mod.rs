@@ -16,6 +16,7 @@
 //! Different vendor OEM externsions to Resdish.
 
 mod identifier;
+mod vendor;
 
 #[cfg(feature = "oem-ami")]
 pub mod ami;
@@ -43,3 +44,5 @@ pub mod delta;
 
 #[doc(inline)]
 pub use identifier::Identifier as OemIdentifier;
+#[doc(inline)]
+pub use vendor::Vendor;
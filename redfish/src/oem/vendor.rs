@@ -0,0 +1,78 @@
+// SPDX-FileCopyrightText: Copyright (c) 2025 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::Bmc;
+use crate::ServiceRoot;
+
+/// BMC vendor that has OEM extensions support in this crate.
+///
+/// Unlike [`crate::service_root::Vendor`], which is the raw `Vendor`
+/// string reported by the service, this is a classification of the
+/// service into one of the known vendors.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Vendor {
+    /// American Megatrends (AMI) MegaRAC based BMC.
+    Ami,
+    /// Dell iDRAC.
+    Dell,
+    /// HPE iLO.
+    Hpe,
+    /// Lenovo XClarity Controller.
+    Lenovo,
+    /// NVIDIA BMC.
+    Nvidia,
+    /// Supermicro BMC.
+    Supermicro,
+}
+
+impl Vendor {
+    /// Guess the vendor of the BMC using standard fields of the service root.
+    ///
+    /// Only `Vendor` and `Product` properties are inspected, so no OEM
+    /// extension data is required. Returns `None` when no known vendor is
+    /// recognized or when the fields point to different vendors.
+    #[must_use]
+    pub fn detect_from_service_root<B: Bmc>(root: &ServiceRoot<B>) -> Option<Self> {
+        let vendor = root.root.vendor.as_ref().and_then(Option::as_deref);
+        let product = root.root.product.as_ref().and_then(Option::as_deref);
+        let mut detected = vendor
+            .into_iter()
+            .chain(product)
+            .flat_map(|v| v.split(|c: char| !c.is_ascii_alphanumeric()))
+            .filter_map(Self::from_token);
+        let first = detected.next()?;
+        detected.all(|v| v == first).then_some(first)
+    }
+
+    fn from_token(token: &str) -> Option<Self> {
+        let token = token.to_ascii_lowercase();
+        let versioned = |name: &str| {
+            token
+                .strip_prefix(name)
+                .is_some_and(|v| v.chars().all(|c| c.is_ascii_digit()))
+        };
+        match token.as_str() {
+            "ami" | "megarac" | "megatrends" => Some(Self::Ami),
+            "dell" => Some(Self::Dell),
+            "hpe" | "proliant" => Some(Self::Hpe),
+            "lenovo" | "xclarity" => Some(Self::Lenovo),
+            "nvidia" => Some(Self::Nvidia),
+            "supermicro" => Some(Self::Supermicro),
+            _ if versioned("idrac") => Some(Self::Dell),
+            _ if versioned("ilo") => Some(Self::Hpe),
+            _ => None,
+        }
+    }
+}
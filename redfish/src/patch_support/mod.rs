@@ -45,7 +45,41 @@ use std::sync::Arc;
 /// structure to a Redfish-compatible structure.
 pub type ReadPatchFn = Arc<dyn Fn(JsonValue) -> JsonValue + Sync + Send>;
 
+/// Compose `patches` into a single patch function that applies them in
+/// order.
+#[allow(dead_code)] // feature-enabled func
+pub fn compose_patches<F>(patches: Vec<F>) -> ReadPatchFn
+where
+    F: Fn(JsonValue) -> JsonValue + Sync + Send + 'static,
+{
+    Arc::new(move |v| patches.iter().fold(v, |acc, f| f(acc)))
+}
+
 /// Reference to a filter function. This function should filters a JSON
 /// structure.
 #[cfg(feature = "patch-collection")]
 pub type FilterFn = Arc<dyn Fn(&JsonValue) -> bool + Sync + Send>;
+
+#[cfg(test)]
+mod tests {
+    use super::compose_patches;
+    use super::JsonValue;
+    use serde_json::json;
+
+    #[test]
+    fn composed_patches_are_applied_in_order() {
+        let patches: Vec<fn(JsonValue) -> JsonValue> = vec![
+            |mut v| {
+                v["Trace"] = json!("first");
+                v
+            },
+            |mut v| {
+                let prev = v["Trace"].as_str().unwrap_or_default().to_owned();
+                v["Trace"] = json!(format!("{prev},second"));
+                v
+            },
+        ];
+        let patch = compose_patches(patches);
+        assert_eq!(patch(json!({}))["Trace"], json!("first,second"));
+    }
+}
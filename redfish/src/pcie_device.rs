@@ -13,7 +13,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-//! PCIe devices
+//! PCIe devices and functions
 //!
 
 use crate::hardware_id::HardwareIdRef;
@@ -24,21 +24,24 @@ use crate::hardware_id::SerialNumber as HardwareIdSerialNumber;
 use crate::schema::pcie_device::PcieDevice as PcieDeviceSchema;
 #[cfg(feature = "chassis")]
 use crate::schema::pcie_device_collection::PcieDeviceCollection as PcieDeviceCollectionSchema;
-#[cfg(feature = "chassis")]
+use crate::schema::pcie_function::PcieFunction as PcieFunctionSchema;
 use crate::Error;
-#[cfg(feature = "chassis")]
 use crate::NvBmc;
 use crate::Resource;
 use crate::ResourceProvidesStatus;
 use crate::ResourceSchema;
 use crate::ResourceStatusSchema;
 use nv_redfish_core::Bmc;
-#[cfg(feature = "chassis")]
 use nv_redfish_core::NavProperty;
 use std::marker::PhantomData;
 use std::sync::Arc;
 use tagged_types::TaggedType;
 
+#[doc(inline)]
+pub use crate::schema::pcie_function::DeviceClass;
+#[doc(inline)]
+pub use crate::schema::pcie_function::FunctionType;
+
 /// PCIe devices collection.
 ///
 /// Provides functions to access collection members.
@@ -100,12 +103,30 @@ pub type FirmwareVersion<T> = TaggedType<T, FirmwareVersionTag>;
 #[capability(inner_access, cloned)]
 pub enum FirmwareVersionTag {}
 
+/// PCI vendor identifier of the PCIe function (for example, `0x8086`).
+pub type VendorId<T> = TaggedType<T, VendorIdTag>;
+#[doc(hidden)]
+#[derive(tagged_types::Tag)]
+#[implement(Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+#[transparent(Debug, Display, Serialize, Deserialize)]
+#[capability(inner_access, cloned)]
+pub enum VendorIdTag {}
+
+/// PCI device identifier of the PCIe function (for example, `0x1bf2`).
+pub type DeviceId<T> = TaggedType<T, DeviceIdTag>;
+#[doc(hidden)]
+#[derive(tagged_types::Tag)]
+#[implement(Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+#[transparent(Debug, Display, Serialize, Deserialize)]
+#[capability(inner_access, cloned)]
+pub enum DeviceIdTag {}
+
 /// PCIe device.
 ///
 /// Provides functions to access PCIe device data.
 pub struct PcieDevice<B: Bmc> {
+    bmc: NvBmc<B>,
     data: Arc<PcieDeviceSchema>,
-    _marker: PhantomData<B>,
 }
 
 impl<B: Bmc> PcieDevice<B> {
@@ -119,8 +140,8 @@ impl<B: Bmc> PcieDevice<B> {
             .await
             .map_err(crate::Error::Bmc)
             .map(|data| Self {
+                bmc: bmc.clone(),
                 data,
-                _marker: PhantomData,
             })
     }
 
@@ -171,6 +192,26 @@ impl<B: Bmc> PcieDevice<B> {
             .map(String::as_str)
             .map(FirmwareVersion::new)
     }
+
+    /// Get PCIe functions of this PCIe device.
+    ///
+    /// Returns `Ok(None)` when the `PCIeFunctions` link is absent.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if fetching PCIe functions data fails.
+    pub async fn functions(&self) -> Result<Option<Vec<PcieFunction<B>>>, Error<B>> {
+        if let Some(functions) = &self.data.pcie_functions {
+            let functions = &self.bmc.expand_property(functions).await?.members;
+            let mut result = Vec::with_capacity(functions.len());
+            for f in functions {
+                result.push(PcieFunction::new(&self.bmc, f).await?);
+            }
+            Ok(Some(result))
+        } else {
+            Ok(None)
+        }
+    }
 }
 
 impl<B: Bmc> Resource for PcieDevice<B> {
@@ -184,3 +225,77 @@ impl<B: Bmc> ResourceProvidesStatus for PcieDevice<B> {
         self.data.status.as_ref()
     }
 }
+
+/// PCIe function of a PCIe device.
+///
+/// Provides functions to access PCIe function data.
+pub struct PcieFunction<B: Bmc> {
+    data: Arc<PcieFunctionSchema>,
+    _marker: PhantomData<B>,
+}
+
+impl<B: Bmc> PcieFunction<B> {
+    /// Create a new PCIe function handle.
+    pub(crate) async fn new(
+        bmc: &NvBmc<B>,
+        nav: &NavProperty<PcieFunctionSchema>,
+    ) -> Result<Self, Error<B>> {
+        nav.get(bmc.as_ref())
+            .await
+            .map_err(crate::Error::Bmc)
+            .map(|data| Self {
+                data,
+                _marker: PhantomData,
+            })
+    }
+
+    /// Get the raw schema data for this PCIe function.
+    #[must_use]
+    pub fn raw(&self) -> Arc<PcieFunctionSchema> {
+        self.data.clone()
+    }
+
+    /// PCI vendor identifier of this function.
+    #[must_use]
+    pub fn vendor_id(&self) -> Option<VendorId<&str>> {
+        self.data
+            .vendor_id
+            .as_ref()
+            .and_then(Option::as_deref)
+            .map(VendorId::new)
+    }
+
+    /// PCI device identifier of this function.
+    #[must_use]
+    pub fn device_id(&self) -> Option<DeviceId<&str>> {
+        self.data
+            .device_id
+            .as_ref()
+            .and_then(Option::as_deref)
+            .map(DeviceId::new)
+    }
+
+    /// Class of the device that this function exposes.
+    #[must_use]
+    pub fn device_class(&self) -> Option<DeviceClass> {
+        self.data.device_class
+    }
+
+    /// Type of this function (physical or virtual).
+    #[must_use]
+    pub fn function_type(&self) -> Option<FunctionType> {
+        self.data.function_type
+    }
+}
+
+impl<B: Bmc> Resource for PcieFunction<B> {
+    fn resource_ref(&self) -> &ResourceSchema {
+        &self.data.as_ref().base
+    }
+}
+
+impl<B: Bmc> ResourceProvidesStatus for PcieFunction<B> {
+    fn resource_status_ref(&self) -> Option<&ResourceStatusSchema> {
+        self.data.status.as_ref()
+    }
+}
@@ -0,0 +1,323 @@
+// SPDX-FileCopyrightText: Copyright (c) 2026 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Message registries.
+//!
+//! Redfish messages (in error responses, log entries and events) carry
+//! a `MessageId` such as `ResourceEvent.1.2.ResourceRemoved` and a list of
+//! `MessageArgs`. This module fetches the registries advertised by the
+//! service through `ServiceRoot.Registries` and resolves message ids to
+//! human-readable text.
+
+use crate::schema::message_registry_file::Location;
+use crate::schema::message_registry_file::MessageRegistryFile;
+use crate::schema::message_registry_file_collection::MessageRegistryFileCollection;
+use crate::Error;
+use crate::NvBmc;
+use crate::ServiceRoot;
+use nv_redfish_core::Bmc;
+use nv_redfish_core::EntityTypeRef;
+use nv_redfish_core::NavProperty;
+use nv_redfish_core::ODataETag;
+use nv_redfish_core::ODataId;
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::sync::Mutex;
+
+/// Message definition in a message registry.
+#[derive(Debug, Deserialize)]
+pub struct MessageDefinition {
+    /// Message text with `%1`, `%2`, ... argument placeholders.
+    #[serde(rename = "Message")]
+    pub message: String,
+    /// Number of arguments substituted into the message.
+    #[serde(rename = "NumberOfArgs", default)]
+    pub number_of_args: usize,
+    /// Severity of the message.
+    #[serde(rename = "MessageSeverity", default)]
+    pub message_severity: Option<String>,
+    /// Suggested resolution of the condition.
+    #[serde(rename = "Resolution", default)]
+    pub resolution: Option<String>,
+}
+
+/// Message registry as published by the service.
+///
+/// Registries are plain JSON documents that often do not carry
+/// `@odata.id`, so they are not modeled by generated schema types.
+#[derive(Debug, Deserialize)]
+pub struct MessageRegistry {
+    #[serde(rename = "@odata.id", default = "empty_odata_id")]
+    odata_id: ODataId,
+    /// Prefix used in message ids, for example `Base`.
+    #[serde(rename = "RegistryPrefix")]
+    pub registry_prefix: String,
+    /// Version of the registry, for example `1.8.0`.
+    #[serde(rename = "RegistryVersion")]
+    pub registry_version: String,
+    /// Language of the messages.
+    #[serde(rename = "Language", default)]
+    pub language: Option<String>,
+    /// Messages keyed by message key.
+    #[serde(rename = "Messages", default)]
+    pub messages: HashMap<String, MessageDefinition>,
+}
+
+fn empty_odata_id() -> ODataId {
+    String::new().into()
+}
+
+impl EntityTypeRef for MessageRegistry {
+    fn odata_id(&self) -> &ODataId {
+        &self.odata_id
+    }
+
+    fn etag(&self) -> Option<&ODataETag> {
+        None
+    }
+}
+
+impl MessageRegistry {
+    /// Resolve `message_id` to text, substituting `%1`, `%2`, ...
+    /// placeholders with `args`.
+    ///
+    /// Returns `None` if the message id does not belong to this registry
+    /// or the registry does not define the message.
+    #[must_use]
+    pub fn resolve<S: AsRef<str>>(&self, message_id: &str, args: &[S]) -> Option<String> {
+        let id = MessageId::parse(message_id)?;
+        if id.prefix != self.registry_prefix {
+            return None;
+        }
+        self.messages
+            .get(id.key)
+            .map(|definition| substitute_args(&definition.message, args))
+    }
+}
+
+/// Message registries advertised by the service.
+///
+/// Registries are fetched on first use and cached.
+pub struct MessageRegistries<B: Bmc> {
+    bmc: NvBmc<B>,
+    files: Vec<Arc<MessageRegistryFile>>,
+    cache: Mutex<HashMap<String, Arc<MessageRegistry>>>,
+}
+
+impl<B: Bmc> MessageRegistries<B> {
+    /// Create a new message registries handle.
+    pub(crate) async fn new(
+        bmc: &NvBmc<B>,
+        root: &ServiceRoot<B>,
+    ) -> Result<Option<Self>, Error<B>> {
+        let Some(collection_ref) = &root.root.registries else {
+            return Ok(None);
+        };
+        Self::from_collection(bmc, collection_ref).await.map(Some)
+    }
+
+    async fn from_collection(
+        bmc: &NvBmc<B>,
+        nav: &NavProperty<MessageRegistryFileCollection>,
+    ) -> Result<Self, Error<B>> {
+        let collection = nav.get(bmc.as_ref()).await.map_err(Error::Bmc)?;
+        let fetches = collection
+            .members
+            .iter()
+            .map(|m| async move { m.get(bmc.as_ref()).await.map_err(Error::Bmc) })
+            .collect::<Vec<_>>();
+        let files = bmc.fetch_members(fetches).await?;
+        Ok(Self {
+            bmc: bmc.clone(),
+            files,
+            cache: Mutex::new(HashMap::new()),
+        })
+    }
+
+    /// Registries advertised by the service, for example `Base.1.8`.
+    #[must_use]
+    pub fn registries(&self) -> Vec<&str> {
+        self.files.iter().map(|f| f.registry.as_str()).collect()
+    }
+
+    /// Get the registry that defines messages of `message_id`.
+    ///
+    /// Returns `Ok(None)` when the service does not publish a matching
+    /// registry locally.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if fetching the registry fails.
+    pub async fn registry(
+        &self,
+        message_id: &str,
+    ) -> Result<Option<Arc<MessageRegistry>>, Error<B>> {
+        let Some(id) = MessageId::parse(message_id) else {
+            return Ok(None);
+        };
+        let Some(file) = self.find_file(&id) else {
+            return Ok(None);
+        };
+        if let Some(registry) = self.cached(&file.registry) {
+            return Ok(Some(registry));
+        }
+        let Some(uri) = Self::location_uri(file) else {
+            return Ok(None);
+        };
+        let registry = self
+            .bmc
+            .as_ref()
+            .get::<MessageRegistry>(&ODataId::from(uri.to_string()))
+            .await
+            .map_err(Error::Bmc)?;
+        if let Ok(mut cache) = self.cache.lock() {
+            cache.insert(file.registry.clone(), registry.clone());
+        }
+        Ok(Some(registry))
+    }
+
+    /// Resolve `message_id` to text, substituting `%1`, `%2`, ...
+    /// placeholders with `args`.
+    ///
+    /// Returns `Ok(None)` when no registry defines the message.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if fetching the registry fails.
+    pub async fn resolve<S: AsRef<str>>(
+        &self,
+        message_id: &str,
+        args: &[S],
+    ) -> Result<Option<String>, Error<B>> {
+        Ok(self
+            .registry(message_id)
+            .await?
+            .and_then(|registry| registry.resolve(message_id, args)))
+    }
+
+    fn cached(&self, registry: &str) -> Option<Arc<MessageRegistry>> {
+        self.cache
+            .lock()
+            .ok()
+            .and_then(|cache| cache.get(registry).cloned())
+    }
+
+    // Prefer the exact `Prefix.Major.Minor` registry, then any registry
+    // with the same prefix and major version.
+    fn find_file(&self, id: &MessageId<'_>) -> Option<&MessageRegistryFile> {
+        let exact = format!("{}.{}", id.prefix, id.version);
+        let major = id.version.split('.').next().unwrap_or(id.version);
+        let same_major = format!("{}.{major}.", id.prefix);
+        self.files
+            .iter()
+            .find(|f| f.registry == exact)
+            .or_else(|| {
+                self.files
+                    .iter()
+                    .find(|f| f.registry.starts_with(&same_major))
+            })
+            .map(Arc::as_ref)
+    }
+
+    // Prefer English, then any location that is hosted by the service.
+    fn location_uri(file: &MessageRegistryFile) -> Option<&str> {
+        let uri = |l: &Location| l.uri.as_deref();
+        file.location
+            .iter()
+            .filter(|l| l.language.as_deref() == Some("en"))
+            .find_map(uri)
+            .or_else(|| file.location.iter().find_map(uri))
+    }
+}
+
+/// Parsed `MessageId` in `Prefix.Major.Minor.Key` form.
+struct MessageId<'a> {
+    prefix: &'a str,
+    version: &'a str,
+    key: &'a str,
+}
+
+impl<'a> MessageId<'a> {
+    fn parse(message_id: &'a str) -> Option<Self> {
+        let (prefix, rest) = message_id.split_once('.')?;
+        let (version, key) = rest.rsplit_once('.')?;
+        (!prefix.is_empty() && !version.is_empty() && !key.is_empty()).then_some(Self {
+            prefix,
+            version,
+            key,
+        })
+    }
+}
+
+/// Substitute `%N` placeholders (1-based) with `args`. Placeholders
+/// without a matching argument are kept as is.
+fn substitute_args<S: AsRef<str>>(message: &str, args: &[S]) -> String {
+    let mut result = String::with_capacity(message.len());
+    let mut rest = message;
+    while let Some(pos) = rest.find('%') {
+        result.push_str(&rest[..pos]);
+        let after = &rest[pos + 1..];
+        let digits = after.bytes().take_while(u8::is_ascii_digit).count();
+        let arg = after[..digits]
+            .parse::<usize>()
+            .ok()
+            .and_then(|n| n.checked_sub(1))
+            .and_then(|n| args.get(n));
+        if let Some(arg) = arg {
+            result.push_str(arg.as_ref());
+            rest = &after[digits..];
+        } else {
+            result.push('%');
+            rest = after;
+        }
+    }
+    result.push_str(rest);
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::substitute_args;
+    use super::MessageId;
+
+    #[test]
+    fn substitutes_numbered_args() {
+        assert_eq!(
+            substitute_args("The value %1 for %2 is invalid.", &["foo", "AssetTag"]),
+            "The value foo for AssetTag is invalid."
+        );
+        assert_eq!(substitute_args("%2 before %1", &["a", "b"]), "b before a");
+    }
+
+    #[test]
+    fn keeps_unmatched_placeholders() {
+        assert_eq!(substitute_args("%1 and %3, 100%", &["x"]), "x and %3, 100%");
+        assert_eq!(
+            substitute_args("%10", &["1", "2", "3", "4", "5", "6", "7", "8", "9", "ten"]),
+            "ten"
+        );
+    }
+
+    #[test]
+    fn parses_message_id() {
+        let id = MessageId::parse("ResourceEvent.1.2.ResourceRemoved").expect("valid id");
+        assert_eq!(id.prefix, "ResourceEvent");
+        assert_eq!(id.version, "1.2");
+        assert_eq!(id.key, "ResourceRemoved");
+        assert!(MessageId::parse("ResourceRemoved").is_none());
+        assert!(MessageId::parse("Base.GeneralError").is_none());
+    }
+}
@@ -18,6 +18,9 @@
 use crate::core::EntityTypeRef as _;
 use crate::core::ODataId;
 use crate::ResourceSchema;
+use serde::de::DeserializeOwned;
+use serde::Deserialize as _;
+use serde_json::Value as JsonValue;
 use tagged_types::TaggedType;
 
 #[cfg(feature = "oem")]
@@ -114,6 +117,23 @@ pub trait Resource {
     fn odata_id(&self) -> &ODataId {
         self.resource_ref().odata_id()
     }
+
+    /// Raw content of the `Oem` property if present in the resource.
+    fn oem_raw(&self) -> Option<&JsonValue> {
+        self.resource_ref()
+            .base
+            .oem
+            .as_ref()
+            .map(|oem| &oem.additional_properties)
+    }
+
+    /// Content of the `Oem` property deserialized into `T`.
+    ///
+    /// Returns `None` if the resource has no `Oem` property or it
+    /// cannot be deserialized into `T`.
+    fn oem_as<T: DeserializeOwned>(&self) -> Option<T> {
+        self.oem_raw().and_then(|oem| T::deserialize(oem).ok())
+    }
 }
 
 #[cfg(feature = "oem")]
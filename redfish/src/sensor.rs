@@ -27,12 +27,19 @@
 //! `Chassis/Power` and `Chassis/Thermal`, use those explicit endpoints instead.
 
 use crate::entity_link::EntityLink;
+use crate::entity_link::FromLink;
 use crate::schema::environment_metrics::EnvironmentMetrics;
 use crate::schema::sensor::Sensor as SchemaSensor;
 use crate::Error;
+use crate::NvBmc;
+use crate::Resource;
+use crate::ResourceSchema;
 use nv_redfish_core::Bmc;
 use nv_redfish_core::NavProperty;
 use nv_redfish_core::ODataId;
+use std::future::Future;
+use std::marker::PhantomData;
+use std::sync::Arc;
 
 /// Extracts sensor URIs from metric fields and creates sensor navigation properties.
 ///
@@ -75,6 +82,69 @@ macro_rules! extract_sensor_uris {
 /// Link for accessing sensor.
 pub type SensorLink<B> = EntityLink<B, SchemaSensor>;
 
+/// Sensor resource wrapper.
+///
+/// Obtained by upgrading a [`SensorLink`]. Provides access to the sensor
+/// reading and its units.
+pub struct Sensor<B: Bmc> {
+    data: Arc<SchemaSensor>,
+    _marker: PhantomData<B>,
+}
+
+impl<B: Bmc> Sensor<B> {
+    /// Create a new sensor handle.
+    pub(crate) async fn new(
+        bmc: &NvBmc<B>,
+        nav: &NavProperty<SchemaSensor>,
+    ) -> Result<Self, Error<B>> {
+        nav.get(bmc.as_ref())
+            .await
+            .map_err(Error::Bmc)
+            .map(|data| Self {
+                data,
+                _marker: PhantomData,
+            })
+    }
+
+    /// Get the raw schema data for this sensor.
+    ///
+    /// Returns an `Arc` to the underlying schema, allowing cheap cloning
+    /// and sharing of the data.
+    #[must_use]
+    pub fn raw(&self) -> Arc<SchemaSensor> {
+        self.data.clone()
+    }
+
+    /// Sensor reading value.
+    #[must_use]
+    pub fn reading(&self) -> Option<f64> {
+        self.data.reading.flatten()
+    }
+
+    /// Units of the sensor reading (for example, `Cel`, `W` or `%`).
+    #[must_use]
+    pub fn reading_units(&self) -> Option<&str> {
+        self.data.reading_units.as_ref().and_then(Option::as_deref)
+    }
+}
+
+impl<B: Bmc> Resource for Sensor<B> {
+    fn resource_ref(&self) -> &ResourceSchema {
+        &self.data.as_ref().base
+    }
+}
+
+impl<B: Bmc> FromLink<B> for Sensor<B> {
+    type Schema = SchemaSensor;
+
+    fn from_link(
+        bmc: &NvBmc<B>,
+        nav: &NavProperty<Self::Schema>,
+    ) -> impl Future<Output = Result<Self, Error<B>>> + Send {
+        Self::new(bmc, nav)
+    }
+}
+
 /// Collect sensor refs from URIs
 pub(crate) fn collect_sensors(
     uris: impl IntoIterator<Item = String>,
@@ -30,6 +30,8 @@ use tagged_types::TaggedType;
 
 #[cfg(feature = "accounts")]
 use crate::account::AccountService;
+#[cfg(feature = "certificate-service")]
+use crate::certificate_service::CertificateService;
 #[cfg(feature = "chassis")]
 use crate::chassis::ChassisCollection;
 #[cfg(feature = "chassis")]
@@ -46,6 +48,8 @@ use crate::oem::ami::AmiServiceRoot;
 use crate::oem::hpe::HpeiLoServiceExt;
 #[cfg(feature = "power-equipment")]
 use crate::power_equipment::PowerEquipment;
+#[cfg(feature = "message-registries")]
+use crate::registry::MessageRegistries;
 #[cfg(feature = "session-service")]
 use crate::session_service::SessionService;
 #[cfg(feature = "task-service")]
@@ -144,6 +148,28 @@ impl<B: Bmc> ServiceRoot<B> {
         Self { root, bmc }
     }
 
+    /// Send expand queries without the `$levels` qualifier.
+    ///
+    /// Workaround for servers that reject `$expand=*($levels=1)` but
+    /// accept `$expand=*`.
+    #[must_use]
+    pub fn expand_without_levels(self) -> Self {
+        let root = self.root;
+        let bmc = self.bmc.expand_without_levels();
+        Self { root, bmc }
+    }
+
+    /// Limit number of collection members fetched concurrently.
+    ///
+    /// Default limit is 8. Value 1 makes fetching sequential, which may
+    /// be required for fragile BMCs.
+    #[must_use]
+    pub fn limit_fetch_concurrency(self, limit: usize) -> Self {
+        let root = self.root;
+        let bmc = self.bmc.limit_fetch_concurrency(limit);
+        Self { root, bmc }
+    }
+
     /// The vendor or manufacturer associated with this Redfish service.
     pub fn vendor(&self) -> Option<Vendor<&str>> {
         self.root
@@ -296,6 +322,30 @@ impl<B: Bmc> ServiceRoot<B> {
         SessionService::new(&self.bmc, self).await
     }
 
+    /// Get certificate service in BMC
+    ///
+    /// Returns `Ok(None)` when the BMC does not expose CertificateService.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if retrieving certificate service data fails.
+    #[cfg(feature = "certificate-service")]
+    pub async fn certificate_service(&self) -> Result<Option<CertificateService<B>>, Error<B>> {
+        CertificateService::new(&self.bmc, self).await
+    }
+
+    /// Get message registries in BMC
+    ///
+    /// Returns `Ok(None)` when the BMC does not expose Registries.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if retrieving registry files fails.
+    #[cfg(feature = "message-registries")]
+    pub async fn message_registries(&self) -> Result<Option<MessageRegistries<B>>, Error<B>> {
+        MessageRegistries::new(&self.bmc, self).await
+    }
+
     /// Get manager collection in BMC
     ///
     /// Returns `Ok(None)` when the BMC does not expose Managers.
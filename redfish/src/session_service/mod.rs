@@ -85,4 +85,23 @@ impl<B: Bmc> SessionService<B> {
             Ok(None)
         }
     }
+
+    /// Create a new session for `username` with `password`.
+    ///
+    /// Convenience wrapper around [`SessionCollection::create_session`].
+    /// The returned session carries the `X-Auth-Token` and `Location`
+    /// from the creation response; use [`Session::delete`] to log out.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the service does not provide a `Sessions`
+    /// collection or if creating the session fails.
+    pub async fn create(&self, username: &str, password: &str) -> Result<Session<B>, Error<B>> {
+        let sessions = self
+            .sessions()
+            .await?
+            .ok_or(Error::SessionServiceSessionsNotAvailable)?;
+        let create = SessionCreate::builder(username.into(), password.into()).build();
+        sessions.create_session(&create).await
+    }
 }
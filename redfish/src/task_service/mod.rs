@@ -19,15 +19,21 @@
 //! A `TaskService` value is a lightweight handle to the service schema and BMC
 //! transport. It validates task locations returned by asynchronous operations
 //! against this service's Tasks collection and returns lazy task links that can
-//! be fetched when polling is needed.
+//! be fetched when polling is needed. [`Task`] polls such a link until the task
+//! reaches a terminal state. The Tasks collection itself can be listed,
+//! queried by task identifier and cleaned up from finished tasks.
 
+use std::future::Future;
 use std::sync::Arc;
+use std::time::Duration;
 
 use crate::core::Bmc;
 use crate::core::EntityTypeRef as _;
 use crate::core::NavProperty;
+use crate::core::ODataId;
 use crate::entity_link::EntityLink;
 use crate::schema::task::Task as TaskSchema;
+pub use crate::schema::task::TaskState;
 use crate::schema::task_service::TaskService as TaskServiceSchema;
 use crate::Error;
 use crate::NvBmc;
@@ -91,6 +97,66 @@ impl<B: Bmc> TaskService<B> {
         self.data.clone()
     }
 
+    /// List tasks of this service's Tasks collection.
+    ///
+    /// The collection is requested with `$expand`, so fetching the returned
+    /// links does not require additional requests when the BMC supports it.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if retrieving the Tasks collection fails.
+    pub async fn tasks(&self) -> Result<Vec<TaskLink<B>>, Error<B>> {
+        let Some(tasks) = self.data.tasks.as_ref() else {
+            return Err(Error::TaskServiceTasksUnavailable);
+        };
+
+        let collection = self.bmc.expand_property(tasks).await?;
+        Ok(collection
+            .members
+            .iter()
+            .map(|member| TaskLink::new(&self.bmc, member.clone()))
+            .collect())
+    }
+
+    /// Get a task of this service's Tasks collection by its identifier.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if retrieving the task fails.
+    pub async fn task_by_id(&self, id: &str) -> Result<Arc<TaskSchema>, Error<B>> {
+        let Some(tasks) = self.data.tasks.as_ref() else {
+            return Err(Error::TaskServiceTasksUnavailable);
+        };
+
+        let task_ref = NavProperty::new_reference(tasks.odata_id().child(id));
+        TaskLink::new(&self.bmc, task_ref).fetch().await
+    }
+
+    /// Delete tasks that reached a terminal state (`Completed`,
+    /// `Exception`, `Killed` or `Cancelled`).
+    ///
+    /// Tasks that are still pending or running are left untouched.
+    /// Returns identifiers of the deleted tasks.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if retrieving the tasks or deleting any of them fails.
+    pub async fn delete_completed(&self) -> Result<Vec<ODataId>, Error<B>> {
+        let mut deleted = Vec::new();
+        for task_link in self.tasks().await? {
+            let task = task_link.fetch().await?;
+            if task.task_state.as_ref().is_some_and(is_terminal) {
+                self.bmc
+                    .as_ref()
+                    .delete::<TaskSchema>(task_link.odata_id())
+                    .await
+                    .map_err(Error::Bmc)?;
+                deleted.push(task_link.odata_id().clone());
+            }
+        }
+        Ok(deleted)
+    }
+
     /// Create a task link from an asynchronous operation result.
     ///
     /// The task location must be a child of this service's Tasks collection,
@@ -118,6 +184,102 @@ impl<B: Bmc> TaskService<B> {
         let task_ref = NavProperty::new_reference(task_location);
         Ok(TaskLink::new(&self.bmc, task_ref))
     }
+
+    /// Create a task handle from an asynchronous operation result.
+    ///
+    /// Same as [`Self::task_link`] but keeps the `Retry-After` hint of the
+    /// operation to delay the first poll.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the task location is not a child of this service's Tasks
+    /// collection.
+    pub fn task(&self, task: AsyncTask) -> Result<Task<B>, Error<B>> {
+        let retry_after = task.retry_after;
+        Ok(Task {
+            link: self.task_link(task)?,
+            retry_after,
+        })
+    }
+}
+
+/// Task started by an asynchronous operation.
+///
+/// # Example
+///
+/// ```ignore
+/// let ModificationResponse::Task(async_task) = update_service.simple_update(...).await? else {
+///     return Ok(());
+/// };
+/// let task = task_service.task(async_task)?;
+/// let task = task
+///     .poll_until_complete(Duration::from_secs(5), 120, tokio::time::sleep)
+///     .await?;
+///
+/// println!("{:?} {:?}", task.task_state, task.messages);
+/// ```
+pub struct Task<B: Bmc> {
+    link: TaskLink<B>,
+    retry_after: Option<Duration>,
+}
+
+impl<B: Bmc> Task<B> {
+    /// Link to the task resource.
+    #[must_use]
+    pub const fn link(&self) -> &TaskLink<B> {
+        &self.link
+    }
+
+    /// Poll the task until it reaches a terminal state (`Completed`,
+    /// `Exception`, `Killed` or `Cancelled`).
+    ///
+    /// The task is fetched at most `max_attempts` times. `sleep` is used
+    /// to wait `interval` between polls, which keeps this crate
+    /// independent of the async runtime (for example, pass
+    /// `tokio::time::sleep`). If the operation returned `Retry-After`,
+    /// the first poll is delayed by it.
+    ///
+    /// Returns the final task entity: its `task_state` is the terminal
+    /// state and its `messages` describe the outcome.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if fetching the task fails or if the task did
+    /// not reach a terminal state within `max_attempts` polls.
+    pub async fn poll_until_complete<S, F>(
+        &self,
+        interval: Duration,
+        max_attempts: u32,
+        sleep: S,
+    ) -> Result<Arc<TaskSchema>, Error<B>>
+    where
+        S: Fn(Duration) -> F,
+        F: Future<Output = ()>,
+    {
+        if let Some(retry_after) = self.retry_after {
+            sleep(retry_after).await;
+        }
+        for attempt in 1..=max_attempts {
+            let task = self.link.fetch().await?;
+            if task.task_state.as_ref().is_some_and(is_terminal) {
+                return Ok(task);
+            }
+            if attempt < max_attempts {
+                sleep(interval).await;
+            }
+        }
+        Err(Error::TaskNotCompleted {
+            task: self.link.odata_id().clone(),
+            attempts: max_attempts,
+        })
+    }
+}
+
+const fn is_terminal(state: &TaskState) -> bool {
+    matches!(
+        state,
+        TaskState::Completed | TaskState::Exception | TaskState::Killed | TaskState::Cancelled
+    )
 }
 
 impl<B: Bmc> Resource for TaskService<B> {
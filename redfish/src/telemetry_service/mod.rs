@@ -35,6 +35,8 @@ use nv_redfish_core::Bmc;
 use nv_redfish_core::EntityTypeRef as _;
 use nv_redfish_core::ModificationResponse;
 use nv_redfish_core::NavProperty;
+use nv_redfish_core::NotFoundError;
+use nv_redfish_core::ODataId;
 use std::sync::Arc;
 
 #[doc(inline)]
@@ -160,6 +162,40 @@ impl<B: Bmc> TelemetryService<B> {
         }
     }
 
+    /// Fetch the metric report `id` of the `MetricReports` collection
+    /// without loading the collection.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - the telemetry service does not expose a `MetricReports` collection
+    ///   or the BMC reports the metric report as not found
+    ///   ([`Error::MetricReportNotFound`])
+    /// - retrieving the metric report fails
+    pub async fn metric_report_by_id(&self, id: &str) -> Result<Arc<MetricReportSchema>, Error<B>>
+    where
+        B::Error: NotFoundError,
+    {
+        let collection_ref = self
+            .data
+            .metric_reports
+            .as_ref()
+            .ok_or_else(|| Error::MetricReportNotFound(id.to_string()))?;
+        let report_id = collection_ref.id().child(id);
+
+        self.bmc
+            .as_ref()
+            .get::<MetricReportSchema>(&report_id)
+            .await
+            .map_err(|err| {
+                if err.is_not_found() {
+                    Error::MetricReportNotFound(id.to_string())
+                } else {
+                    Error::Bmc(err)
+                }
+            })
+    }
+
     /// Get `Vec<MetricDefinition>` associated with this telemetry service.
     ///
     /// Fetches the metric definition collection and returns a list of [`MetricDefinition`] handles.
@@ -279,6 +315,89 @@ impl<B: Bmc> TelemetryService<B> {
             })
             .await
     }
+
+    /// Delete the metric definition identified by `id`.
+    ///
+    /// Returns one of the following modification outcomes:
+    ///
+    /// - `ModificationResponse::Entity` contains the metric definition returned
+    ///   by the server.
+    /// - `ModificationResponse::Task` identifies an asynchronous operation.
+    /// - `ModificationResponse::Empty` reports synchronous success without a
+    ///   response body.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - the telemetry service does not expose a `MetricDefinitions` collection
+    /// - `id` is not a member of the `MetricDefinitions` collection
+    /// - deleting the entity fails
+    pub async fn delete_metric_definition(
+        &self,
+        id: &ODataId,
+    ) -> Result<ModificationResponse<MetricDefinition<B>>, Error<B>> {
+        let collection_ref = self
+            .data
+            .metric_definitions
+            .as_ref()
+            .ok_or(Error::MetricDefinitionsNotAvailable)?;
+        if !is_member_id(collection_ref.id(), id) {
+            return Err(Error::MetricDefinitionNotAvailable(id.clone()));
+        }
+
+        self.bmc
+            .as_ref()
+            .delete::<NavProperty<MetricDefinitionSchema>>(id)
+            .await
+            .map_err(Error::Bmc)?
+            .try_map_entity_async(|nav| async move { MetricDefinition::new(&self.bmc, &nav).await })
+            .await
+    }
+
+    /// Delete the metric report definition identified by `id`.
+    ///
+    /// Returns one of the following modification outcomes:
+    ///
+    /// - `ModificationResponse::Entity` contains the metric report definition
+    ///   returned by the server.
+    /// - `ModificationResponse::Task` identifies an asynchronous operation.
+    /// - `ModificationResponse::Empty` reports synchronous success without a
+    ///   response body.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - the telemetry service does not expose a `MetricReportDefinitions` collection
+    /// - `id` is not a member of the `MetricReportDefinitions` collection
+    /// - deleting the entity fails
+    pub async fn delete_metric_report_definition(
+        &self,
+        id: &ODataId,
+    ) -> Result<ModificationResponse<MetricReportDefinition<B>>, Error<B>> {
+        let collection_ref = self
+            .data
+            .metric_report_definitions
+            .as_ref()
+            .ok_or(Error::MetricReportDefinitionsNotAvailable)?;
+        if !is_member_id(collection_ref.id(), id) {
+            return Err(Error::MetricReportDefinitionNotAvailable(id.clone()));
+        }
+
+        self.bmc
+            .as_ref()
+            .delete::<NavProperty<MetricReportDefinitionSchema>>(id)
+            .await
+            .map_err(Error::Bmc)?
+            .try_map_entity_async(|nav| async move {
+                MetricReportDefinition::new(&self.bmc, &nav).await
+            })
+            .await
+    }
+}
+
+/// `id` identifies a member of collection `collection_id`.
+fn is_member_id(collection_id: &ODataId, id: &ODataId) -> bool {
+    collection_id != id && collection_id.is_path_prefix(id)
 }
 
 impl<B: Bmc> Resource for TelemetryService<B> {
@@ -24,16 +24,22 @@ use std::sync::Arc;
 use std::time::Duration;
 
 use crate::core::NavProperty;
+use crate::patch_support::compose_patches;
 use crate::patch_support::Payload;
 use crate::patch_support::ReadPatchFn;
 use crate::schema::update_service::UpdateService as UpdateServiceSchema;
 use crate::schema::update_service::UpdateServiceSimpleUpdateAction;
+#[cfg(feature = "task-service")]
+use crate::task_service::Task;
+#[cfg(feature = "task-service")]
+use crate::task_service::TaskService;
 use crate::Error;
 use crate::NvBmc;
 use crate::Resource;
 use crate::ResourceSchema;
 use crate::ServiceRoot;
 
+use futures_util::io::Cursor;
 use nv_redfish_core::Bmc;
 use nv_redfish_core::DataStream;
 #[cfg(feature = "update-service-deprecated")]
@@ -81,17 +87,15 @@ impl<B: Bmc> UpdateService<B> {
         if bmc.quirks.bug_missing_update_service_name_field() {
             service_patches.push(add_default_update_service_name);
         }
-        let service_patch_fn = (!service_patches.is_empty()).then(|| {
-            Arc::new(move |v| service_patches.iter().fold(v, |acc, f| f(acc))) as ReadPatchFn
-        });
+        let service_patch_fn =
+            (!service_patches.is_empty()).then(|| compose_patches(service_patches));
 
         let mut fw_inventory_patches = Vec::new();
         if bmc.quirks.fw_inventory_wrong_release_date() {
             fw_inventory_patches.push(fw_inventory_patch_wrong_release_date);
         }
-        let fw_inventory_read_patch_fn = (!fw_inventory_patches.is_empty()).then(|| {
-            Arc::new(move |v| fw_inventory_patches.iter().fold(v, |acc, f| f(acc))) as ReadPatchFn
-        });
+        let fw_inventory_read_patch_fn =
+            (!fw_inventory_patches.is_empty()).then(|| compose_patches(fw_inventory_patches));
 
         if let Some(nav) = &root.root.update_service {
             if let Some(service_patch_fn) = service_patch_fn {
@@ -196,6 +200,10 @@ impl<B: Bmc> UpdateService<B> {
     ///   store
     /// * `exclude_targets` - An array of URIs that indicate where not to apply the update image
     ///
+    /// The update usually completes asynchronously and the returned
+    /// [`ModificationResponse::Task`] identifies its task. Use
+    /// `Self::simple_update_task` to get the task ready for polling.
+    ///
     /// # Errors
     ///
     /// Returns an error if:
@@ -242,6 +250,56 @@ impl<B: Bmc> UpdateService<B> {
             .map_err(Error::Bmc)
     }
 
+    /// Perform a simple update and return its task.
+    ///
+    /// Same as [`Self::simple_update`], but the task location returned by
+    /// the BMC is resolved with `task_service`. Await the update with
+    /// [`Task::poll_until_complete`]. Returns `None` if the BMC completed
+    /// the update synchronously.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - The update service does not support the `SimpleUpdate` action
+    /// - The action execution fails
+    /// - The task location is not in the Tasks collection of `task_service`
+    #[cfg(feature = "task-service")]
+    #[allow(clippy::too_many_arguments)]
+    pub async fn simple_update_task(
+        &self,
+        task_service: &TaskService<B>,
+        image_uri: String,
+        transfer_protocol: Option<TransferProtocolType>,
+        targets: Option<Vec<String>>,
+        username: Option<String>,
+        password: Option<String>,
+        force_update: Option<bool>,
+        stage: Option<bool>,
+        local_image: Option<bool>,
+        exclude_targets: Option<Vec<String>>,
+    ) -> Result<Option<Task<B>>, Error<B>>
+    where
+        B::Error: nv_redfish_core::ActionError,
+    {
+        match self
+            .simple_update(
+                image_uri,
+                transfer_protocol,
+                targets,
+                username,
+                password,
+                force_update,
+                stage,
+                local_image,
+                exclude_targets,
+            )
+            .await?
+        {
+            ModificationResponse::Task(task) => task_service.task(task).map(Some),
+            ModificationResponse::Entity(()) | ModificationResponse::Empty => Ok(None),
+        }
+    }
+
     /// Start updates that have been previously invoked with an `OperationApplyTime` of
     /// `OnStartUpdateRequest`.
     ///
@@ -408,6 +466,36 @@ impl<B: Bmc> UpdateService<B> {
             .await
             .map_err(Error::Bmc)
     }
+
+    /// Push an in-memory firmware image using this service's
+    /// `MultipartHttpPushUri`.
+    ///
+    /// The request body has two parts: `UpdateParameters` with the
+    /// JSON-encoded `update_parameters` and `UpdateFile` with `image`
+    /// sent as `file_name`. The image is moved into the request without
+    /// copying. Use [`Self::multipart_update_from_reader`] to stream
+    /// large images instead of holding them in memory.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `MultipartHttpPushUri` is absent or the upload fails.
+    pub async fn push_update<V, R>(
+        &self,
+        file_name: impl Into<String>,
+        image: Vec<u8>,
+        update_parameters: &V,
+        upload_timeout: Duration,
+    ) -> Result<ModificationResponse<R>, Error<B>>
+    where
+        V: Send + Sync + serde::Serialize,
+        R: Send + Sync + for<'de> serde::Deserialize<'de>,
+    {
+        let content_length = image.len() as u64;
+        let update_stream =
+            DataStream::new(file_name, Cursor::new(image)).with_content_length(content_length);
+        self.multipart_update_from_reader(update_parameters, update_stream, upload_timeout)
+            .await
+    }
 }
 
 impl<B: Bmc> Resource for UpdateService<B> {
@@ -0,0 +1,187 @@
+// SPDX-FileCopyrightText: Copyright (c) 2026 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Virtual media
+//!
+
+use crate::schema::virtual_media::VirtualMedia as VirtualMediaSchema;
+use crate::schema::virtual_media::VirtualMediaInsertMediaAction;
+use crate::schema::virtual_media_collection::VirtualMediaCollection as VirtualMediaCollectionSchema;
+use crate::Error;
+use crate::NvBmc;
+use crate::Resource;
+use crate::ResourceSchema;
+use nv_redfish_core::Bmc;
+use nv_redfish_core::ModificationResponse;
+use nv_redfish_core::NavProperty;
+use std::convert::identity;
+use std::sync::Arc;
+
+#[doc(inline)]
+pub use crate::schema::virtual_media::ConnectedVia;
+#[doc(inline)]
+pub use crate::schema::virtual_media::TransferMethod;
+#[doc(inline)]
+pub use crate::schema::virtual_media::TransferProtocolType;
+
+/// Optional parameters of the `InsertMedia` action.
+///
+/// Fields left as `None` are omitted from the request so the service
+/// applies its defaults.
+#[derive(Default)]
+pub struct InsertMediaOptions {
+    /// Whether the image is treated as inserted upon completion.
+    pub inserted: Option<bool>,
+    /// Whether the media is treated as write-protected.
+    pub write_protected: Option<bool>,
+    /// Network protocol used to fetch the image.
+    pub transfer_protocol_type: Option<TransferProtocolType>,
+    /// How the image is transferred (streamed or uploaded).
+    pub transfer_method: Option<TransferMethod>,
+    /// Username for accessing the image URI.
+    pub username: Option<String>,
+    /// Password for accessing the image URI.
+    pub password: Option<String>,
+}
+
+/// Virtual media device.
+///
+/// Provides functions to inspect and control virtual media.
+pub struct VirtualMedia<B: Bmc> {
+    bmc: NvBmc<B>,
+    data: Arc<VirtualMediaSchema>,
+}
+
+impl<B: Bmc> VirtualMedia<B> {
+    /// Fetch all virtual media devices of the collection.
+    pub(crate) async fn list(
+        bmc: &NvBmc<B>,
+        nav: &NavProperty<VirtualMediaCollectionSchema>,
+    ) -> Result<Vec<Self>, Error<B>> {
+        let collection = nav.get(bmc.as_ref()).await.map_err(Error::Bmc)?;
+        let fetches = collection
+            .members
+            .iter()
+            .map(|m| Self::new(bmc, m))
+            .collect::<Vec<_>>();
+        bmc.fetch_members(fetches).await
+    }
+
+    /// Create a new virtual media handle.
+    pub(crate) async fn new(
+        bmc: &NvBmc<B>,
+        nav: &NavProperty<VirtualMediaSchema>,
+    ) -> Result<Self, Error<B>> {
+        nav.get(bmc.as_ref())
+            .await
+            .map_err(Error::Bmc)
+            .map(|data| Self {
+                bmc: bmc.clone(),
+                data,
+            })
+    }
+
+    /// Get the raw schema data for this virtual media.
+    #[must_use]
+    pub fn raw(&self) -> Arc<VirtualMediaSchema> {
+        self.data.clone()
+    }
+
+    /// Whether media is currently inserted. `None` means that BMC
+    /// hasn't reported the state or reported null.
+    #[must_use]
+    pub fn inserted(&self) -> Option<bool> {
+        self.data.inserted.and_then(identity)
+    }
+
+    /// How the virtual media is connected to the server.
+    #[must_use]
+    pub fn connected_via(&self) -> Option<ConnectedVia> {
+        self.data.connected_via.and_then(identity)
+    }
+
+    /// Insert media from `image_uri` into this virtual media device.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the virtual media does not support the
+    /// `InsertMedia` action or if invoking the action fails.
+    pub async fn insert_media(
+        &self,
+        image_uri: String,
+        options: InsertMediaOptions,
+    ) -> Result<ModificationResponse<()>, Error<B>>
+    where
+        B::Error: nv_redfish_core::ActionError,
+    {
+        let actions = self
+            .data
+            .actions
+            .as_ref()
+            .ok_or(Error::ActionNotAvailable)?;
+
+        if actions.insert_media.is_none() {
+            return Err(Error::ActionNotAvailable);
+        }
+
+        actions
+            .insert_media(
+                self.bmc.as_ref(),
+                &VirtualMediaInsertMediaAction {
+                    image: image_uri,
+                    inserted: options.inserted,
+                    write_protected: options.write_protected,
+                    transfer_protocol_type: options.transfer_protocol_type,
+                    transfer_method: options.transfer_method,
+                    user_name: options.username,
+                    password: options.password,
+                },
+            )
+            .await
+            .map_err(Error::Bmc)
+    }
+
+    /// Eject media from this virtual media device.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the virtual media does not support the
+    /// `EjectMedia` action or if invoking the action fails.
+    pub async fn eject_media(&self) -> Result<ModificationResponse<()>, Error<B>>
+    where
+        B::Error: nv_redfish_core::ActionError,
+    {
+        let actions = self
+            .data
+            .actions
+            .as_ref()
+            .ok_or(Error::ActionNotAvailable)?;
+
+        if actions.eject_media.is_none() {
+            return Err(Error::ActionNotAvailable);
+        }
+
+        actions
+            .eject_media(self.bmc.as_ref())
+            .await
+            .map_err(Error::Bmc)
+    }
+}
+
+impl<B: Bmc> Resource for VirtualMedia<B> {
+    fn resource_ref(&self) -> &ResourceSchema {
+        &self.data.as_ref().base
+    }
+}
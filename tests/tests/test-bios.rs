@@ -14,16 +14,20 @@
 // limitations under the License.
 //! Integration tests of BIOS support.
 
+use nv_redfish::computer_system::bios::ApplyTime;
 use nv_redfish::computer_system::Bios;
 use nv_redfish::computer_system::ComputerSystem;
+use nv_redfish::Error;
 use nv_redfish::ServiceRoot;
 use nv_redfish_core::EdmPrimitiveType;
+use nv_redfish_core::ModificationResponse;
 use nv_redfish_core::ODataId;
 use nv_redfish_tests::Bmc;
 use nv_redfish_tests::Expect;
 use nv_redfish_tests::ODATA_ID;
 use nv_redfish_tests::ODATA_TYPE;
 use serde_json::json;
+use std::collections::HashMap;
 use std::error::Error as StdError;
 use std::sync::Arc;
 use tokio::test;
@@ -169,6 +173,139 @@ async fn bios_missing_or_empty_attributes() -> Result<(), Box<dyn StdError>> {
     Ok(())
 }
 
+// Test 4: set a single attribute through the settings object with OnReset apply time.
+#[test]
+async fn bios_set_attribute_on_reset() -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(Bmc::default());
+    let ids = bios_ids();
+    let system = get_computer_system(bmc.clone(), &ids, "Generic").await?;
+    let settings_id = format!("{}/Settings", ids.bios_id);
+
+    bmc.expect(Expect::get(
+        &ids.bios_id,
+        bios_with_settings(&ids, &settings_id),
+    ));
+    let bios: Bios<Bmc> = system.bios().await?.unwrap();
+    assert_eq!(
+        bios.supported_apply_times(),
+        Some([ApplyTime::Immediate, ApplyTime::OnReset].as_slice())
+    );
+
+    bmc.expect(Expect::update(
+        &settings_id,
+        json!({
+            "Attributes": { "BootMode": "Legacy" },
+            "@Redfish.SettingsApplyTime": { "ApplyTime": "OnReset" },
+        }),
+        json!({
+            ODATA_ID: &settings_id,
+            ODATA_TYPE: BIOS_DATA_TYPE,
+            "Id": "Settings",
+            "Name": "BIOS Pending Settings",
+            "Attributes": { "BootMode": "Legacy" }
+        }),
+    ));
+    let attributes = HashMap::from([(
+        "BootMode".to_string(),
+        EdmPrimitiveType::String("Legacy".into()),
+    )]);
+    let ModificationResponse::Entity(pending) = bios
+        .set_attributes(attributes, Some(ApplyTime::OnReset))
+        .await?
+    else {
+        return Err("expected an entity response".into());
+    };
+
+    // Current value is not changed until settings are applied.
+    assert_eq!(
+        bios.attribute("BootMode").and_then(|v| v.str_value()),
+        Some("Uefi")
+    );
+    assert_eq!(
+        pending.attribute("BootMode").and_then(|v| v.str_value()),
+        Some("Legacy")
+    );
+    Ok(())
+}
+
+// Test 5: apply time that is not advertised by the settings is rejected.
+#[test]
+async fn bios_set_attribute_unsupported_apply_time() -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(Bmc::default());
+    let ids = bios_ids();
+    let system = get_computer_system(bmc.clone(), &ids, "Generic").await?;
+    let settings_id = format!("{}/Settings", ids.bios_id);
+
+    bmc.expect(Expect::get(
+        &ids.bios_id,
+        bios_with_settings(&ids, &settings_id),
+    ));
+    let bios: Bios<Bmc> = system.bios().await?.unwrap();
+
+    let attributes =
+        HashMap::from([("WatchdogTimeout".to_string(), EdmPrimitiveType::Integer(10))]);
+    let result = bios
+        .set_attributes(attributes, Some(ApplyTime::AtMaintenanceWindowStart))
+        .await;
+    assert!(matches!(
+        result,
+        Err(Error::SettingsApplyTimeNotSupported(
+            ApplyTime::AtMaintenanceWindowStart
+        ))
+    ));
+    Ok(())
+}
+
+// Test 6: pending settings are fetched from the settings object.
+#[test]
+async fn bios_pending_settings() -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(Bmc::default());
+    let ids = bios_ids();
+    let system = get_computer_system(bmc.clone(), &ids, "Generic").await?;
+    let settings_id = format!("{}/Settings", ids.bios_id);
+
+    bmc.expect(Expect::get(
+        &ids.bios_id,
+        bios_with_settings(&ids, &settings_id),
+    ));
+    let bios: Bios<Bmc> = system.bios().await?.unwrap();
+
+    bmc.expect(Expect::get(
+        &settings_id,
+        json!({
+            ODATA_ID: &settings_id,
+            ODATA_TYPE: BIOS_DATA_TYPE,
+            "Id": "Settings",
+            "Name": "BIOS Pending Settings",
+            "Attributes": { "BootMode": "Legacy" }
+        }),
+    ));
+    let pending = bios.pending().await?.unwrap();
+    let pending_attributes = pending.attributes().collect::<Vec<_>>();
+    assert_eq!(pending_attributes.len(), 1);
+    assert_eq!(pending_attributes[0].0, "BootMode");
+    assert_eq!(pending_attributes[0].1.str_value(), Some("Legacy"));
+    assert_eq!(bios.attributes().count(), 2);
+    Ok(())
+}
+
+fn bios_with_settings(ids: &BiosIds, settings_id: &str) -> serde_json::Value {
+    json!({
+        ODATA_ID: &ids.bios_id,
+        ODATA_TYPE: BIOS_DATA_TYPE,
+        "Id": "Bios",
+        "Name": "BIOS Settings",
+        "@Redfish.Settings": {
+            "SettingsObject": { ODATA_ID: settings_id },
+            "SupportedApplyTimes": ["Immediate", "OnReset"]
+        },
+        "Attributes": {
+            "BootMode": "Uefi",
+            "WatchdogTimeout": 5
+        }
+    })
+}
+
 struct BiosIds {
     root_id: ODataId,
     systems_id: String,
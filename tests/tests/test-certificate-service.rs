@@ -0,0 +1,209 @@
+// SPDX-FileCopyrightText: Copyright (c) 2026 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Integration tests of Certificate Service.
+
+use std::error::Error as StdError;
+use std::sync::Arc;
+
+use nv_redfish::certificate_service::Certificate;
+use nv_redfish::certificate_service::CertificateService;
+use nv_redfish::certificate_service::CertificateType;
+use nv_redfish::Error;
+use nv_redfish::ServiceRoot;
+use nv_redfish_core::ModificationResponse;
+use nv_redfish_core::ODataId;
+use nv_redfish_tests::assert_empty;
+use nv_redfish_tests::json_merge;
+use nv_redfish_tests::redfish_action_payload;
+use nv_redfish_tests::redfish_empty_actions_payload;
+use nv_redfish_tests::Bmc;
+use nv_redfish_tests::Expect;
+use nv_redfish_tests::ODATA_ID;
+use nv_redfish_tests::ODATA_TYPE;
+
+use serde_json::json;
+use serde_json::Value;
+use tokio::test;
+
+const ROOT_DATA_TYPE: &str = "#ServiceRoot.v1_13_0.ServiceRoot";
+const CERTIFICATE_SERVICE_DATA_TYPE: &str = "#CertificateService.v1_0_5.CertificateService";
+const CERTIFICATE_LOCATIONS_DATA_TYPE: &str = "#CertificateLocations.v1_0_3.CertificateLocations";
+const CERTIFICATE_DATA_TYPE: &str = "#Certificate.v1_8_0.Certificate";
+
+const CERTIFICATE_ID: &str = "/redfish/v1/Managers/BMC/NetworkProtocol/HTTPS/Certificates/1";
+const REPLACE_TARGET: &str =
+    "/redfish/v1/CertificateService/Actions/CertificateService.ReplaceCertificate";
+
+#[test]
+async fn replace_certificate_sends_certificate_payload() -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(Bmc::default());
+    let service = get_certificate_service(
+        bmc.clone(),
+        redfish_action_payload("CertificateService.ReplaceCertificate", REPLACE_TARGET),
+    )
+    .await?;
+    let certificate = get_certificate(bmc.clone(), &service).await?;
+
+    bmc.expect(Expect::action(
+        REPLACE_TARGET,
+        json!({
+            "CertificateString": "-----BEGIN CERTIFICATE-----",
+            "CertificateType": "PEM",
+            "CertificateUri": { ODATA_ID: CERTIFICATE_ID },
+        }),
+        json!(null),
+    ));
+    assert!(matches!(
+        service
+            .replace_certificate(
+                &certificate,
+                "-----BEGIN CERTIFICATE-----".into(),
+                CertificateType::Pem,
+                None,
+            )
+            .await?,
+        ModificationResponse::Entity(())
+    ));
+
+    bmc.expect(Expect::action(
+        REPLACE_TARGET,
+        json!({
+            "CertificateString": "-----BEGIN PKCS7-----",
+            "CertificateType": "PKCS7",
+            "CertificateUri": { ODATA_ID: CERTIFICATE_ID },
+            "Password": "secret",
+        }),
+        json!(null),
+    ));
+    assert!(matches!(
+        service
+            .replace_certificate(
+                &certificate,
+                "-----BEGIN PKCS7-----".into(),
+                CertificateType::Pkcs7,
+                Some("secret".into()),
+            )
+            .await?,
+        ModificationResponse::Entity(())
+    ));
+
+    Ok(())
+}
+
+#[test]
+async fn replace_certificate_requires_action() -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(Bmc::default());
+    let service = get_certificate_service(bmc.clone(), json!({})).await?;
+    let certificate = get_certificate(bmc.clone(), &service).await?;
+
+    assert!(matches!(
+        service
+            .replace_certificate(&certificate, String::new(), CertificateType::Pem, None)
+            .await,
+        Err(Error::ActionNotAvailable)
+    ));
+
+    Ok(())
+}
+
+#[test]
+async fn delete_certificate() -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(Bmc::default());
+    let service = get_certificate_service(bmc.clone(), redfish_empty_actions_payload()).await?;
+    let certificate = get_certificate(bmc.clone(), &service).await?;
+
+    bmc.expect(Expect::delete(CERTIFICATE_ID));
+    assert_empty(certificate.delete().await?);
+
+    Ok(())
+}
+
+async fn get_certificate_service(
+    bmc: Arc<Bmc>,
+    fields: Value,
+) -> Result<CertificateService<Bmc>, Box<dyn StdError>> {
+    let root_id = ODataId::service_root();
+    let service_id = format!("{root_id}/CertificateService");
+    bmc.expect(Expect::get(
+        &root_id,
+        json!({
+            ODATA_ID: &root_id,
+            ODATA_TYPE: ROOT_DATA_TYPE,
+            "Id": "RootService",
+            "Name": "RootService",
+            "CertificateService": {
+                ODATA_ID: &service_id,
+            },
+            "Links": {
+                "Sessions": {
+                    ODATA_ID: format!("{root_id}/SessionService/Sessions"),
+                }
+            },
+        }),
+    ));
+    let service_root = ServiceRoot::new(bmc.clone()).await?;
+
+    bmc.expect(Expect::get(
+        &service_id,
+        json_merge([
+            &json!({
+                ODATA_ID: &service_id,
+                ODATA_TYPE: CERTIFICATE_SERVICE_DATA_TYPE,
+                "Id": "CertificateService",
+                "Name": "Certificate Service",
+                "CertificateLocations": {
+                    ODATA_ID: format!("{service_id}/CertificateLocations"),
+                },
+            }),
+            &fields,
+        ]),
+    ));
+    Ok(service_root.certificate_service().await?.unwrap())
+}
+
+async fn get_certificate(
+    bmc: Arc<Bmc>,
+    service: &CertificateService<Bmc>,
+) -> Result<Certificate<Bmc>, Box<dyn StdError>> {
+    let locations_id = "/redfish/v1/CertificateService/CertificateLocations";
+    bmc.expect(Expect::get(
+        locations_id,
+        json!({
+            ODATA_ID: locations_id,
+            ODATA_TYPE: CERTIFICATE_LOCATIONS_DATA_TYPE,
+            "Id": "CertificateLocations",
+            "Name": "Certificate Locations",
+            "Links": {
+                "Certificates": [
+                    { ODATA_ID: CERTIFICATE_ID },
+                ],
+            },
+        }),
+    ));
+    bmc.expect(Expect::get(
+        CERTIFICATE_ID,
+        json!({
+            ODATA_ID: CERTIFICATE_ID,
+            ODATA_TYPE: CERTIFICATE_DATA_TYPE,
+            "Id": "1",
+            "Name": "HTTPS Certificate",
+            "CertificateType": "PEM",
+        }),
+    ));
+    let mut certificates = service.certificates().await?.unwrap();
+    assert_eq!(certificates.len(), 1);
+    Ok(certificates.remove(0))
+}
@@ -20,8 +20,14 @@ use std::sync::Arc;
 use nv_redfish::chassis::Chassis;
 use nv_redfish::chassis::PowerSupply;
 use nv_redfish::control::ControlUpdate;
+use nv_redfish::pcie_device::DeviceClass;
+use nv_redfish::pcie_device::FunctionType;
 use nv_redfish::resource::ResetType;
+use nv_redfish::sensor::Sensor;
+use nv_redfish::Error;
+use nv_redfish::Resource as _;
 use nv_redfish::ServiceRoot;
+use nv_redfish_core::EntityTypeRef as _;
 use nv_redfish_core::ModificationResponse;
 use nv_redfish_core::ODataId;
 use nv_redfish_tests::ami_service_root;
@@ -36,6 +42,7 @@ use nv_redfish_tests::Expect;
 use nv_redfish_tests::ODATA_ID;
 use nv_redfish_tests::ODATA_TYPE;
 
+use serde::Deserialize;
 use serde_json::json;
 use serde_json::Value;
 use tokio::test;
@@ -45,6 +52,14 @@ const CHASSIS_DATA_TYPE: &str = "#Chassis.v1_23_0.Chassis";
 const POWER_SUBSYSTEM_DATA_TYPE: &str = "#PowerSubsystem.v1_1_0.PowerSubsystem";
 const POWER_SUPPLY_COLLECTION_DATA_TYPE: &str = "#PowerSupplyCollection.PowerSupplyCollection";
 const POWER_SUPPLY_DATA_TYPE: &str = "#PowerSupply.v1_5_0.PowerSupply";
+const THERMAL_SUBSYSTEM_DATA_TYPE: &str = "#ThermalSubsystem.v1_3_0.ThermalSubsystem";
+const FAN_COLLECTION_DATA_TYPE: &str = "#FanCollection.FanCollection";
+const FAN_DATA_TYPE: &str = "#Fan.v1_5_0.Fan";
+const SENSOR_DATA_TYPE: &str = "#Sensor.v1_9_0.Sensor";
+const PCIE_DEVICE_COLLECTION_DATA_TYPE: &str = "#PCIeDeviceCollection.PCIeDeviceCollection";
+const PCIE_DEVICE_DATA_TYPE: &str = "#PCIeDevice.v1_14_0.PCIeDevice";
+const PCIE_FUNCTION_COLLECTION_DATA_TYPE: &str = "#PCIeFunctionCollection.PCIeFunctionCollection";
+const PCIE_FUNCTION_DATA_TYPE: &str = "#PCIeFunction.v1_5_0.PCIeFunction";
 
 #[test]
 async fn reset_invokes_chassis_reset_action() -> Result<(), Box<dyn StdError>> {
@@ -146,6 +161,308 @@ async fn reset_returns_action_not_available_when_power_supply_reset_is_absent(
     Ok(())
 }
 
+#[test]
+async fn power_subsystem_lists_power_supplies() -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(Bmc::default());
+    let ids = ids();
+    let power_ids = power_supply_ids(&ids);
+    let chassis = get_chassis(
+        bmc.clone(),
+        &ids,
+        chassis_payload(
+            &ids,
+            json!({
+                "PowerSubsystem": {
+                    ODATA_ID: &power_ids.power_subsystem_id
+                }
+            }),
+        ),
+    )
+    .await?;
+
+    expect_power_supply(
+        bmc.clone(),
+        &power_ids,
+        power_supply_payload(&power_ids, json!({})),
+    );
+    let power_subsystem = chassis.power_subsystem().await?.unwrap();
+    let power_supplies = power_subsystem.power_supplies().await?.unwrap();
+
+    assert_eq!(power_supplies.len(), 1);
+    assert_eq!(power_supplies[0].raw().base.id, "1");
+
+    Ok(())
+}
+
+#[test]
+async fn thermal_subsystem_fan_speed_sensor_reading() -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(Bmc::default());
+    let ids = ids();
+    let thermal_subsystem_id = format!("{}/ThermalSubsystem", ids.chassis_id);
+    let fan_collection_id = format!("{thermal_subsystem_id}/Fans");
+    let fan_id = format!("{fan_collection_id}/1");
+    let sensor_id = format!("{}/Sensors/Fan1", ids.chassis_id);
+    let chassis = get_chassis(
+        bmc.clone(),
+        &ids,
+        chassis_payload(
+            &ids,
+            json!({
+                "ThermalSubsystem": {
+                    ODATA_ID: &thermal_subsystem_id
+                }
+            }),
+        ),
+    )
+    .await?;
+
+    bmc.expect(Expect::get(
+        &thermal_subsystem_id,
+        json!({
+            ODATA_ID: &thermal_subsystem_id,
+            ODATA_TYPE: THERMAL_SUBSYSTEM_DATA_TYPE,
+            "Id": "ThermalSubsystem",
+            "Name": "Thermal Subsystem",
+            "Fans": {
+                ODATA_ID: &fan_collection_id
+            }
+        }),
+    ));
+    let thermal_subsystem = chassis.thermal_subsystem().await?.unwrap();
+
+    bmc.expect(Expect::get(
+        &fan_collection_id,
+        json!({
+            ODATA_ID: &fan_collection_id,
+            ODATA_TYPE: FAN_COLLECTION_DATA_TYPE,
+            "Id": "Fans",
+            "Name": "Fan Collection",
+            "Members": [
+                {
+                    ODATA_ID: &fan_id
+                }
+            ]
+        }),
+    ));
+    bmc.expect(Expect::get(
+        &fan_id,
+        json!({
+            ODATA_ID: &fan_id,
+            ODATA_TYPE: FAN_DATA_TYPE,
+            "Id": "1",
+            "Name": "Fan 1",
+            "SpeedPercent": {
+                "DataSourceUri": &sensor_id,
+                "Reading": 45.0
+            }
+        }),
+    ));
+    let fans = thermal_subsystem.fans().await?.unwrap();
+    assert_eq!(fans.len(), 1);
+    assert_eq!(fans[0].speed_percent(), Some(45.0));
+
+    let sensor_link = fans[0].speed_sensor_link().unwrap();
+    assert_eq!(sensor_link.odata_id().to_string(), sensor_id);
+
+    bmc.expect(Expect::get(
+        &sensor_id,
+        json!({
+            ODATA_ID: &sensor_id,
+            ODATA_TYPE: SENSOR_DATA_TYPE,
+            "Id": "Fan1",
+            "Name": "Fan 1 Speed",
+            "Reading": 45.0,
+            "ReadingUnits": "%"
+        }),
+    ));
+    let sensor = sensor_link.upgrade::<Sensor<Bmc>>().await?;
+    assert_eq!(sensor.reading(), Some(45.0));
+    assert_eq!(sensor.reading_units(), Some("%"));
+
+    Ok(())
+}
+
+#[test]
+async fn pcie_device_functions_expose_typed_ids() -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(Bmc::default());
+    let ids = ids();
+    let pcie_devices_id = format!("{}/PCIeDevices", ids.chassis_id);
+    let pcie_device_id = format!("{pcie_devices_id}/0-24");
+    let pcie_functions_id = format!("{pcie_device_id}/PCIeFunctions");
+    let pcie_function_id = format!("{pcie_functions_id}/0-24-0");
+    let chassis = get_chassis(
+        bmc.clone(),
+        &ids,
+        chassis_payload(
+            &ids,
+            json!({
+                "PCIeDevices": {
+                    ODATA_ID: &pcie_devices_id
+                }
+            }),
+        ),
+    )
+    .await?;
+
+    bmc.expect(Expect::get(
+        &pcie_devices_id,
+        json!({
+            ODATA_ID: &pcie_devices_id,
+            ODATA_TYPE: PCIE_DEVICE_COLLECTION_DATA_TYPE,
+            "Name": "PCIeDevice Collection",
+            "Members": [
+                {
+                    ODATA_ID: &pcie_device_id
+                }
+            ]
+        }),
+    ));
+    let pcie_devices = chassis.pcie_devices().await?.unwrap();
+
+    bmc.expect(Expect::get(
+        &pcie_device_id,
+        json!({
+            ODATA_ID: &pcie_device_id,
+            ODATA_TYPE: PCIE_DEVICE_DATA_TYPE,
+            "Id": "0-24",
+            "Name": "Sapphire Rapids SATA AHCI Controller",
+            "DeviceType": "SingleFunction",
+            "FirmwareVersion": "",
+            "Manufacturer": "Intel Corporation",
+            "Model": null,
+            "Status": {
+                "State": "Enabled",
+                "Health": "OK",
+                "HealthRollup": "OK"
+            },
+            "PCIeFunctions": {
+                ODATA_ID: &pcie_functions_id
+            }
+        }),
+    ));
+    let mut devices = pcie_devices.members().await?;
+    let device = devices.pop().unwrap();
+
+    bmc.expect(Expect::get(
+        &pcie_functions_id,
+        json!({
+            ODATA_ID: &pcie_functions_id,
+            ODATA_TYPE: PCIE_FUNCTION_COLLECTION_DATA_TYPE,
+            "Name": "PCIeFunction Collection",
+            "Members": [
+                {
+                    ODATA_ID: &pcie_function_id
+                }
+            ]
+        }),
+    ));
+    bmc.expect(Expect::get(
+        &pcie_function_id,
+        json!({
+            ODATA_ID: &pcie_function_id,
+            ODATA_TYPE: PCIE_FUNCTION_DATA_TYPE,
+            "Id": "0-24-0",
+            "Name": "Sapphire Rapids SATA AHCI Controller",
+            "ClassCode": "0x010601",
+            "DeviceClass": "MassStorageController",
+            "DeviceId": "0x1bf2",
+            "Enabled": true,
+            "FunctionId": 0,
+            "FunctionType": "Physical",
+            "RevisionId": "0x11",
+            "Status": {
+                "State": "Enabled",
+                "Health": "OK",
+                "HealthRollup": "OK"
+            },
+            "SubsystemId": "0x0a6b",
+            "SubsystemVendorId": "0x1028",
+            "VendorId": "0x8086"
+        }),
+    ));
+    let functions = device.functions().await?.unwrap();
+
+    assert_eq!(functions.len(), 1);
+    let function = &functions[0];
+    assert_eq!(function.vendor_id().map(|v| *v.inner()), Some("0x8086"));
+    assert_eq!(function.device_id().map(|v| *v.inner()), Some("0x1bf2"));
+    assert_eq!(
+        function.device_class(),
+        Some(DeviceClass::MassStorageController)
+    );
+    assert_eq!(function.function_type(), Some(FunctionType::Physical));
+
+    Ok(())
+}
+
+#[derive(Deserialize, Debug, PartialEq)]
+struct ContosoChassisOem {
+    #[serde(rename = "Contoso")]
+    contoso: ContosoChassis,
+}
+
+#[derive(Deserialize, Debug, PartialEq)]
+struct ContosoChassis {
+    #[serde(rename = "RackUnit")]
+    rack_unit: u32,
+    #[serde(rename = "AssetTag")]
+    asset_tag: String,
+}
+
+#[test]
+async fn chassis_oem_deserializes_into_custom_type() -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(Bmc::default());
+    let ids = ids();
+    let chassis = get_chassis(
+        bmc.clone(),
+        &ids,
+        chassis_payload(
+            &ids,
+            json!({
+                "Oem": {
+                    "Contoso": {
+                        "RackUnit": 12,
+                        "AssetTag": "CT-0042"
+                    }
+                }
+            }),
+        ),
+    )
+    .await?;
+
+    assert_eq!(
+        chassis
+            .oem_raw()
+            .and_then(|oem| oem.pointer("/Contoso/RackUnit")),
+        Some(&json!(12))
+    );
+    assert_eq!(
+        chassis.oem_as::<ContosoChassisOem>(),
+        Some(ContosoChassisOem {
+            contoso: ContosoChassis {
+                rack_unit: 12,
+                asset_tag: "CT-0042".into(),
+            },
+        })
+    );
+    // Payload doesn't match the requested type.
+    assert_eq!(chassis.oem_as::<u32>(), None);
+
+    Ok(())
+}
+
+#[test]
+async fn chassis_without_oem_has_no_oem_extension() -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(Bmc::default());
+    let ids = ids();
+    let chassis = get_chassis(bmc.clone(), &ids, valid_chassis_payload(&ids)).await?;
+
+    assert!(chassis.oem_raw().is_none());
+    assert_eq!(chassis.oem_as::<ContosoChassisOem>(), None);
+
+    Ok(())
+}
+
 #[test]
 async fn ami_viking_missing_root_chassis_nav_workaround() -> Result<(), Box<dyn StdError>> {
     let bmc = Arc::new(Bmc::default());
@@ -599,6 +916,160 @@ async fn nvswitch_wrong_location_part_location_type_workaround() -> Result<(), B
     Ok(())
 }
 
+#[test]
+async fn all_members_follows_collection_next_link() -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(Bmc::default());
+    let ids = ids();
+    let root = expect_anonymous_1_9_service_root(
+        bmc.clone(),
+        &ids,
+        json!({
+            "Chassis": { ODATA_ID: &ids.chassis_collection_id }
+        }),
+    )
+    .await?;
+    expect_chassis_collection(bmc.clone(), &ids);
+    let collection = root.chassis().await?.unwrap();
+
+    let next_page_id = format!("{}?$skiptoken=1", ids.chassis_collection_id);
+    expect_chassis_collection_page(
+        bmc.clone(),
+        &ids.chassis_collection_id,
+        &ids.container_chassis_id,
+        Some(&next_page_id),
+    );
+    expect_chassis_collection_page(bmc.clone(), &next_page_id, &ids.chassis_id, None);
+    bmc.expect(Expect::get(
+        &ids.container_chassis_id,
+        json_merge([
+            &valid_chassis_payload(&ids),
+            &json!({ ODATA_ID: &ids.container_chassis_id, "Id": "0" }),
+        ]),
+    ));
+    expect_chassis_get(bmc.clone(), &ids, valid_chassis_payload(&ids));
+
+    let members = collection.all_members().await?;
+    let member_ids = members
+        .iter()
+        .map(|c| c.raw().odata_id().to_string())
+        .collect::<Vec<_>>();
+    assert_eq!(member_ids, vec![ids.container_chassis_id, ids.chassis_id]);
+    bmc.assert_all_consumed()?;
+
+    Ok(())
+}
+
+#[test]
+async fn all_members_detects_collection_next_link_loop() -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(Bmc::default());
+    let ids = ids();
+    let root = expect_anonymous_1_9_service_root(
+        bmc.clone(),
+        &ids,
+        json!({
+            "Chassis": { ODATA_ID: &ids.chassis_collection_id }
+        }),
+    )
+    .await?;
+    expect_chassis_collection(bmc.clone(), &ids);
+    let collection = root.chassis().await?.unwrap();
+
+    let next_page_id = format!("{}?$skiptoken=1", ids.chassis_collection_id);
+    expect_chassis_collection_page(
+        bmc.clone(),
+        &ids.chassis_collection_id,
+        &ids.container_chassis_id,
+        Some(&next_page_id),
+    );
+    expect_chassis_collection_page(
+        bmc.clone(),
+        &next_page_id,
+        &ids.chassis_id,
+        Some(&ids.chassis_collection_id),
+    );
+
+    assert!(matches!(
+        collection.all_members().await,
+        Err(Error::CollectionNextLinkLoop(id)) if id.to_string() == ids.chassis_collection_id
+    ));
+
+    Ok(())
+}
+
+#[test]
+async fn members_are_fetched_concurrently_in_order() -> Result<(), Box<dyn StdError>> {
+    let fetched = fetch_chassis_members(|root| root).await?;
+    assert_eq!(fetched.max_in_flight, 3);
+    assert_eq!(fetched.ids, fetched.expected);
+    Ok(())
+}
+
+#[test]
+async fn members_fetch_respects_concurrency_limit() -> Result<(), Box<dyn StdError>> {
+    let fetched = fetch_chassis_members(|root| root.limit_fetch_concurrency(1)).await?;
+    assert_eq!(fetched.max_in_flight, 1);
+    assert_eq!(fetched.ids, fetched.expected);
+    Ok(())
+}
+
+struct FetchedMembers {
+    ids: Vec<String>,
+    expected: Vec<String>,
+    max_in_flight: usize,
+}
+
+async fn fetch_chassis_members(
+    configure: impl FnOnce(ServiceRoot<Bmc>) -> ServiceRoot<Bmc>,
+) -> Result<FetchedMembers, Box<dyn StdError>> {
+    let bmc = Arc::new(Bmc::default());
+    let ids = ids();
+    let root = expect_anonymous_1_9_service_root(
+        bmc.clone(),
+        &ids,
+        json!({
+            "Chassis": { ODATA_ID: &ids.chassis_collection_id }
+        }),
+    )
+    .await?;
+    let root = configure(root);
+    let expected = (1..=3)
+        .map(|n| format!("{}/{n}", ids.chassis_collection_id))
+        .collect::<Vec<_>>();
+    bmc.expect(Expect::get(
+        &ids.chassis_collection_id,
+        json!({
+            ODATA_ID: &ids.chassis_collection_id,
+            ODATA_TYPE: CHASSIS_COLLECTION_DATA_TYPE,
+            "Id": "Chassis",
+            "Name": "Chassis Collection",
+            "Members": expected
+                .iter()
+                .map(|id| json!({ ODATA_ID: id }))
+                .collect::<Vec<_>>()
+        }),
+    ));
+    let collection = root.chassis().await?.unwrap();
+    bmc.expect_sequence(expected.iter().map(|id| {
+        Expect::get(
+            id,
+            json_merge([&valid_chassis_payload(&ids), &json!({ ODATA_ID: id })]),
+        )
+    }));
+
+    let ids = collection
+        .members()
+        .await?
+        .iter()
+        .map(|c| c.raw().odata_id().to_string())
+        .collect();
+    bmc.assert_all_consumed()?;
+    Ok(FetchedMembers {
+        ids,
+        expected,
+        max_in_flight: bmc.max_in_flight(),
+    })
+}
+
 async fn expect_viking_service_root(
     bmc: Arc<Bmc>,
     ids: &Ids,
@@ -728,6 +1199,29 @@ fn expect_chassis_collection(bmc: Arc<Bmc>, ids: &Ids) {
     ));
 }
 
+fn expect_chassis_collection_page(
+    bmc: Arc<Bmc>,
+    page_id: &str,
+    member_id: &str,
+    next_link: Option<&str>,
+) {
+    let mut page = json!({
+        ODATA_ID: page_id,
+        ODATA_TYPE: CHASSIS_COLLECTION_DATA_TYPE,
+        "Id": "Chassis",
+        "Name": "Chassis Collection",
+        "Members": [
+            {
+                ODATA_ID: member_id
+            }
+        ]
+    });
+    if let Some(next_link) = next_link {
+        page["Members@odata.nextLink"] = json!(next_link);
+    }
+    bmc.expect(Expect::get(page_id, page));
+}
+
 fn expect_chassis_get(bmc: Arc<Bmc>, ids: &Ids, payload: Value) {
     bmc.expect(Expect::get(&ids.chassis_id, payload));
 }
@@ -19,10 +19,17 @@ use std::sync::Arc;
 
 use nv_redfish::computer_system::BootOptionReference;
 use nv_redfish::computer_system::ComputerSystem;
+use nv_redfish::computer_system::SecureBootDatabase;
+use nv_redfish::computer_system::Storage;
 use nv_redfish::computer_system::SystemCollection;
+use nv_redfish::computer_system::VolumeCreate;
 use nv_redfish::resource::ResetType;
 use nv_redfish::Resource;
 use nv_redfish::ServiceRoot;
+use nv_redfish_core::EdmGuid;
+use nv_redfish_core::EntityTypeRef as _;
+use nv_redfish_core::ExpandQuery;
+use nv_redfish_core::Expandable as _;
 use nv_redfish_core::ModificationResponse;
 use nv_redfish_core::ODataId;
 use nv_redfish_tests::ami_viking_service_root;
@@ -39,6 +46,7 @@ use nv_redfish_tests::Expect;
 use nv_redfish_tests::ODATA_ID;
 use nv_redfish_tests::ODATA_TYPE;
 
+use serde::Deserialize;
 use serde_json::json;
 use serde_json::Value;
 use tokio::test;
@@ -121,6 +129,44 @@ async fn set_boot_order_preserves_task_and_empty_responses() -> Result<(), Box<d
     Ok(())
 }
 
+#[test]
+async fn reset_validates_advertised_allowable_reset_types() -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(Bmc::default());
+    let ids = computer_system_ids();
+    let action_target = format!("{}/Actions/ComputerSystem.Reset", ids.system_id);
+    let system = get_system(
+        bmc.clone(),
+        &ids,
+        computer_system(
+            &ids,
+            json!({
+                "Actions": {
+                    "#ComputerSystem.Reset": {
+                        "target": &action_target,
+                        "ResetType@Redfish.AllowableValues": ["On", "ForceOff"]
+                    }
+                }
+            }),
+        ),
+    )
+    .await?;
+
+    expect_redfish_reset_action(&bmc, &action_target, Some("ForceOff"));
+    assert!(matches!(
+        system.reset(Some(ResetType::ForceOff)).await?,
+        ModificationResponse::Entity(())
+    ));
+
+    assert!(matches!(
+        system.reset(Some(ResetType::GracefulRestart)).await,
+        Err(nv_redfish::Error::ResetTypeNotAllowed(
+            ResetType::GracefulRestart
+        ))
+    ));
+
+    Ok(())
+}
+
 #[test]
 async fn reset_returns_action_not_available_when_computer_system_reset_is_absent(
 ) -> Result<(), Box<dyn StdError>> {
@@ -141,6 +187,168 @@ async fn reset_returns_action_not_available_when_computer_system_reset_is_absent
     Ok(())
 }
 
+#[test]
+async fn oem_is_deserialized_into_custom_type() -> Result<(), Box<dyn StdError>> {
+    #[derive(Deserialize)]
+    struct VendorOem {
+        #[serde(rename = "Vendor")]
+        vendor: VendorSystem,
+    }
+
+    #[derive(Deserialize)]
+    struct VendorSystem {
+        #[serde(rename = "BoardRevision")]
+        board_revision: String,
+    }
+
+    let bmc = Arc::new(Bmc::default());
+    let ids = computer_system_ids();
+    let system = get_system(
+        bmc.clone(),
+        &ids,
+        computer_system(
+            &ids,
+            json!({
+                "Oem": {
+                    "Vendor": {
+                        "BoardRevision": "B2"
+                    }
+                }
+            }),
+        ),
+    )
+    .await?;
+
+    assert_eq!(
+        system.oem()?,
+        Some(json!({ "Vendor": { "BoardRevision": "B2" } }))
+    );
+
+    let system = system.with_oem::<VendorOem>();
+    let oem = system.oem()?.ok_or("missing OEM data")?;
+    assert_eq!(oem.vendor.board_revision, "B2");
+
+    Ok(())
+}
+
+#[test]
+async fn secure_boot_enroll_certificate_posts_pem_to_database() -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(Bmc::default());
+    let ids = computer_system_ids();
+    let secure_boot_id = format!("{}/SecureBoot", ids.system_id);
+    let system = get_system(
+        bmc.clone(),
+        &ids,
+        computer_system(&ids, json!({ "SecureBoot": { ODATA_ID: &secure_boot_id } })),
+    )
+    .await?;
+
+    bmc.expect(Expect::get(
+        &secure_boot_id,
+        json!({
+            ODATA_ID: &secure_boot_id,
+            ODATA_TYPE: "#SecureBoot.v1_1_0.SecureBoot",
+            "Id": "SecureBoot",
+            "Name": "UEFI Secure Boot",
+            "SecureBootEnable": true,
+            "SecureBootDatabases": { ODATA_ID: format!("{secure_boot_id}/SecureBootDatabases") }
+        }),
+    ));
+    let secure_boot = system.secure_boot().await?.ok_or("missing secure boot")?;
+
+    let certificates_id = format!("{secure_boot_id}/SecureBootDatabases/db/Certificates");
+    bmc.expect(Expect::create(
+        &certificates_id,
+        json!({
+            "CertificateString": "-----BEGIN CERTIFICATE-----\nZm9vYmFy\n-----END CERTIFICATE-----\n",
+            "CertificateType": "PEM"
+        }),
+        json!({
+            ODATA_ID: format!("{certificates_id}/1"),
+            ODATA_TYPE: "#Certificate.v1_8_0.Certificate",
+            "Id": "1",
+            "Name": "db certificate"
+        }),
+    ));
+    assert!(matches!(
+        secure_boot
+            .enroll_certificate(SecureBootDatabase::Db, b"foobar".to_vec())
+            .await?,
+        ModificationResponse::Entity(())
+    ));
+    bmc.assert_all_consumed()?;
+
+    Ok(())
+}
+
+#[test]
+async fn storage_lists_volumes() -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(Bmc::default());
+    let ids = computer_system_ids();
+    let storage = get_storage(bmc.clone(), &ids).await?;
+    let volumes_id = format!("{}/Storage/1/Volumes", ids.system_id);
+
+    bmc.expect(Expect::expand(
+        &volumes_id,
+        json!({
+            ODATA_ID: &volumes_id,
+            ODATA_TYPE: "#VolumeCollection.VolumeCollection",
+            "Name": "Volume Collection",
+            "Members": [volume(&volumes_id, "1"), volume(&volumes_id, "2")]
+        }),
+    ));
+    let volumes = storage.volumes().await?.ok_or("missing volumes")?;
+
+    assert_eq!(
+        volumes
+            .iter()
+            .map(|v| v.raw().base.id.clone())
+            .collect::<Vec<_>>(),
+        ["1", "2"]
+    );
+    bmc.assert_all_consumed()?;
+
+    Ok(())
+}
+
+#[test]
+async fn storage_creates_and_deletes_volume() -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(Bmc::default());
+    let ids = computer_system_ids();
+    let storage = get_storage(bmc.clone(), &ids).await?;
+    let volumes_id = format!("{}/Storage/1/Volumes", ids.system_id);
+
+    bmc.expect(Expect::create(
+        &volumes_id,
+        json!({ "CapacityBytes": 1_073_741_824 }),
+        volume(&volumes_id, "3"),
+    ));
+    let ModificationResponse::Entity(created) = storage
+        .create_volume(
+            &VolumeCreate::builder()
+                .with_capacity_bytes(1_073_741_824)
+                .build(),
+        )
+        .await?
+    else {
+        return Err("expected created volume".into());
+    };
+    assert_eq!(created.raw().base.id, "3");
+
+    bmc.expect(Expect::delete_task(
+        format!("{volumes_id}/3"),
+        async_task("/redfish/v1/TaskService/Tasks/9", 5),
+    ));
+    assert_task(
+        created.delete().await?,
+        "/redfish/v1/TaskService/Tasks/9",
+        5,
+    );
+    bmc.assert_all_consumed()?;
+
+    Ok(())
+}
+
 #[test]
 async fn dell_wrong_last_reset_time_workaround() -> Result<(), Box<dyn StdError>> {
     let bmc = Arc::new(Bmc::default());
@@ -159,6 +367,77 @@ async fn dell_wrong_last_reset_time_workaround() -> Result<(), Box<dyn StdError>
     Ok(())
 }
 
+#[test]
+async fn find_system_by_serial_number_and_uuid() -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(Bmc::default());
+    let ids = computer_system_ids();
+    let first_uuid = "7b1f5c9e-3a4d-4c2b-9f1e-2d3c4b5a6978";
+    let second_uuid = "0e8d7c6b-5a49-4f3e-8d2c-1b0a99887766";
+    let members = vec![
+        computer_system(
+            &ids,
+            json!({
+                ODATA_ID: format!("{}/System-1", ids.systems_id),
+                "SerialNumber": "SN-0001",
+                "UUID": first_uuid,
+            }),
+        ),
+        computer_system(
+            &ids,
+            json!({
+                ODATA_ID: format!("{}/System-2", ids.systems_id),
+                "SerialNumber": "SN-0002",
+                "UUID": second_uuid,
+            }),
+        ),
+    ];
+    let systems = get_systems(bmc.clone(), &ids, "Contoso", members).await?;
+
+    let system = systems
+        .find_by_serial_number("SN-0002")
+        .await?
+        .ok_or("expected system with serial number")?;
+    assert_eq!(system.id().to_string(), "System-2");
+    assert!(systems.find_by_serial_number("SN-9999").await?.is_none());
+
+    let system = systems
+        .find_by_uuid(EdmGuid::parse_str(first_uuid)?)
+        .await?
+        .ok_or("expected system with uuid")?;
+    assert_eq!(system.id().to_string(), "System-1");
+    assert!(systems
+        .find_by_uuid(EdmGuid::parse_str("00000000-0000-0000-0000-000000000000")?)
+        .await?
+        .is_none());
+
+    Ok(())
+}
+
+#[test]
+async fn expand_refetches_system_by_its_own_id() -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(Bmc::default());
+    let ids = computer_system_ids();
+    let system = get_system(bmc.clone(), &ids, computer_system(&ids, json!({}))).await?;
+
+    bmc.expect(Expect::expand(
+        &ids.system_id,
+        computer_system(&ids, json!({ "SerialNumber": "SN-0001" })),
+    ));
+
+    let refreshed = system
+        .raw()
+        .expand(bmc.as_ref(), ExpandQuery::default())
+        .await?;
+    assert_eq!(refreshed.odata_id().to_string(), ids.system_id);
+    assert_eq!(
+        refreshed.serial_number.as_ref().and_then(Option::as_deref),
+        Some("SN-0001")
+    );
+    bmc.assert_all_consumed()?;
+
+    Ok(())
+}
+
 #[test]
 async fn ami_viking_missing_root_systems_nav_workaround() -> Result<(), Box<dyn StdError>> {
     let bmc = Arc::new(Bmc::default());
@@ -485,6 +764,53 @@ fn computer_system(ids: &ComputerSystemIds, fields: Value) -> Value {
     json_merge([&base, &fields])
 }
 
+async fn get_storage(
+    bmc: Arc<Bmc>,
+    ids: &ComputerSystemIds,
+) -> Result<Storage<Bmc>, Box<dyn StdError>> {
+    let storages_id = format!("{}/Storage", ids.system_id);
+    let storage_id = format!("{storages_id}/1");
+    let system = get_system(
+        bmc.clone(),
+        ids,
+        computer_system(ids, json!({ "Storage": { ODATA_ID: &storages_id } })),
+    )
+    .await?;
+
+    bmc.expect(Expect::expand(
+        &storages_id,
+        json!({
+            ODATA_ID: &storages_id,
+            ODATA_TYPE: "#StorageCollection.StorageCollection",
+            "Name": "Storage Collection",
+            "Members": [{
+                ODATA_ID: &storage_id,
+                ODATA_TYPE: "#Storage.v1_15_0.Storage",
+                "Id": "1",
+                "Name": "Storage Controller",
+                "Volumes": { ODATA_ID: format!("{storage_id}/Volumes") }
+            }]
+        }),
+    ));
+    let mut storages = system
+        .storage_controllers()
+        .await?
+        .ok_or("missing storage")?;
+    storages
+        .pop()
+        .ok_or_else(|| "missing storage controller".into())
+}
+
+fn volume(volumes_id: &str, id: &str) -> Value {
+    json!({
+        ODATA_ID: format!("{volumes_id}/{id}"),
+        ODATA_TYPE: "#Volume.v1_9_0.Volume",
+        "Id": id,
+        "Name": format!("Volume {id}"),
+        "CapacityBytes": 1_073_741_824
+    })
+}
+
 async fn get_system(
     bmc: Arc<Bmc>,
     ids: &ComputerSystemIds,
@@ -0,0 +1,419 @@
+// SPDX-FileCopyrightText: Copyright (c) 2026 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//! Integration tests for Event Service subscriptions.
+
+use std::error::Error as StdError;
+use std::future;
+use std::sync::Arc;
+use std::sync::Mutex;
+use std::time::Duration;
+
+use nv_redfish::event_service::EventService;
+use nv_redfish::event_service::EventStreamPayload;
+use nv_redfish::event_service::EventSubscription;
+use nv_redfish::event_service::EventType;
+use nv_redfish::event_service::ReconnectPolicy;
+use nv_redfish::Error;
+use nv_redfish::ServiceRoot;
+use nv_redfish_core::BoxTryStream;
+use nv_redfish_core::ModificationResponse;
+use nv_redfish_core::ODataId;
+use nv_redfish_tests::anonymous_1_9_service_root;
+use nv_redfish_tests::assert_empty;
+use nv_redfish_tests::json_merge;
+use nv_redfish_tests::Bmc;
+use nv_redfish_tests::Expect;
+use nv_redfish_tests::ODATA_ID;
+use nv_redfish_tests::ODATA_TYPE;
+
+use futures_util::StreamExt as _;
+use serde_json::json;
+use serde_json::Value;
+use tokio::test;
+
+const EVENT_SERVICE_DATA_TYPE: &str = "#EventService.v1_7_0.EventService";
+const EVENT_DESTINATION_DATA_TYPE: &str = "#EventDestination.v1_10_0.EventDestination";
+
+#[test]
+async fn subscribe_creates_event_destination() -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(Bmc::default());
+    let ids = ids();
+    let event_service = get_event_service(bmc.clone(), &ids).await?;
+
+    let subscription = subscribe(&bmc, &ids, &event_service).await?;
+    let raw = subscription.raw();
+    assert_eq!(raw.odata_id.to_string(), ids.subscription_id);
+    assert_eq!(raw.id, "1");
+    assert_eq!(
+        raw.destination.as_deref(),
+        Some("https://listener.example.com/events")
+    );
+    assert_eq!(raw.event_types, [EventType::Alert, EventType::StatusChange]);
+    assert_eq!(raw.context.as_deref(), Some("rack-1"));
+    bmc.assert_all_consumed()?;
+
+    Ok(())
+}
+
+#[test]
+async fn supported_event_types_reads_event_types_for_subscription() -> Result<(), Box<dyn StdError>>
+{
+    let bmc = Arc::new(Bmc::default());
+    let ids = ids();
+    let event_service = get_event_service_with_fields(
+        bmc.clone(),
+        &ids,
+        json!({ "EventTypesForSubscription": ["Alert", "MetricReport", "Other"] }),
+    )
+    .await?;
+    assert_eq!(
+        event_service.supported_event_types(),
+        [EventType::Alert, EventType::MetricReport, EventType::Other]
+    );
+
+    let event_service = get_event_service(bmc.clone(), &ids).await?;
+    assert!(event_service.supported_event_types().is_empty());
+    bmc.assert_all_consumed()?;
+
+    Ok(())
+}
+
+#[test]
+async fn subscription_delete_removes_event_destination() -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(Bmc::default());
+    let ids = ids();
+    let event_service = get_event_service(bmc.clone(), &ids).await?;
+    let subscription = subscribe(&bmc, &ids, &event_service).await?;
+
+    bmc.expect(Expect::delete(&ids.subscription_id));
+    assert_empty(subscription.delete().await?);
+    bmc.assert_all_consumed()?;
+
+    Ok(())
+}
+
+#[test]
+async fn subscriptions_lists_event_destinations() -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(Bmc::default());
+    let ids = ids();
+    let event_service = get_event_service(bmc.clone(), &ids).await?;
+    let second_id = format!("{}/2", ids.subscriptions_id);
+
+    bmc.expect(Expect::get(
+        &ids.subscriptions_id,
+        json!({
+            ODATA_ID: &ids.subscriptions_id,
+            ODATA_TYPE: "#EventDestinationCollection.EventDestinationCollection",
+            "Name": "Event Subscriptions Collection",
+            "Members": [
+                { ODATA_ID: &ids.subscription_id },
+                { ODATA_ID: &second_id },
+            ],
+        }),
+    ));
+    bmc.expect(Expect::get(
+        &ids.subscription_id,
+        json!({
+            ODATA_ID: &ids.subscription_id,
+            ODATA_TYPE: EVENT_DESTINATION_DATA_TYPE,
+            "Id": "1",
+            "Name": "Event Subscription",
+            "Destination": "https://listener.example.com/events",
+            "EventTypes": ["Alert"],
+            "Protocol": "Redfish"
+        }),
+    ));
+    bmc.expect(Expect::get(
+        &second_id,
+        json!({
+            ODATA_ID: &second_id,
+            ODATA_TYPE: EVENT_DESTINATION_DATA_TYPE,
+            "Id": "2",
+            "Name": "Event Subscription",
+            "Destination": "https://other.example.com/events",
+            "Context": "rack-2",
+            "Protocol": "Redfish"
+        }),
+    ));
+
+    let subscriptions = event_service.subscriptions().await?;
+    let ids = subscriptions
+        .iter()
+        .map(|s| s.raw().id.clone())
+        .collect::<Vec<_>>();
+    assert_eq!(ids, ["1", "2"]);
+    assert_eq!(subscriptions[1].raw().context.as_deref(), Some("rack-2"));
+    bmc.assert_all_consumed()?;
+
+    Ok(())
+}
+
+#[test]
+async fn events_reconnecting_reopens_dropped_stream_with_last_event_id(
+) -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(Bmc::default());
+    let ids = ids();
+    let event_service = get_event_service(bmc.clone(), &ids).await?;
+
+    bmc.expect(Expect::stream(&ids.sse_id, json!([event_payload("1")])));
+    bmc.expect(Expect::stream_resume(
+        &ids.sse_id,
+        Some("1"),
+        json!([event_payload("2")]),
+    ));
+
+    let policy = ReconnectPolicy {
+        initial_backoff: Duration::ZERO,
+        max_backoff: Duration::ZERO,
+        max_attempts: Some(1),
+    };
+    let mut events = event_service
+        .events_reconnecting(policy, |_| future::ready(()))
+        .await?;
+
+    assert!(matches!(
+        events.next().await,
+        Some(Ok(EventStreamPayload::Event(event))) if event.base.id == "1"
+    ));
+    assert!(matches!(
+        events.next().await,
+        Some(Ok(EventStreamPayload::Event(event))) if event.base.id == "2"
+    ));
+    // Nothing more is expected by the mock: the third attempt to open the
+    // stream fails and exhausts the policy.
+    assert!(matches!(events.next().await, Some(Err(Error::Bmc(_)))));
+    assert!(events.next().await.is_none());
+    bmc.assert_all_consumed()?;
+
+    Ok(())
+}
+
+#[test]
+async fn events_reconnecting_counts_empty_reopened_streams_as_failures(
+) -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(Bmc::default());
+    let ids = ids();
+    let event_service = get_event_service(bmc.clone(), &ids).await?;
+
+    bmc.expect(Expect::stream(&ids.sse_id, json!([event_payload("1")])));
+    for _ in 0..3 {
+        bmc.expect(Expect::stream_resume(&ids.sse_id, Some("1"), json!([])));
+    }
+
+    let policy = ReconnectPolicy {
+        initial_backoff: Duration::from_secs(1),
+        max_backoff: Duration::from_secs(10),
+        max_attempts: Some(3),
+    };
+    let sleeps = Arc::new(Mutex::new(Vec::new()));
+    let recorded = sleeps.clone();
+    let mut events = event_service
+        .events_reconnecting(policy, move |duration| {
+            recorded.lock().expect("not poisoned").push(duration);
+            future::ready(())
+        })
+        .await?;
+
+    assert!(matches!(
+        events.next().await,
+        Some(Ok(EventStreamPayload::Event(event))) if event.base.id == "1"
+    ));
+    // Every re-opened stream ends without payload: the stream stops
+    // after `max_attempts` with growing backoff.
+    assert!(events.next().await.is_none());
+    assert_eq!(
+        *sleeps.lock().expect("not poisoned"),
+        [1, 2, 4].map(Duration::from_secs)
+    );
+    bmc.assert_all_consumed()?;
+
+    Ok(())
+}
+
+#[test]
+async fn events_filtered_drops_non_matching_payloads() -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(Bmc::default());
+    let ids = ids();
+    let event_service = get_event_service(bmc.clone(), &ids).await?;
+    let mixed = json!([
+        event_payload("1"),
+        metric_report_payload("PowerUsage"),
+        event_payload("2"),
+        metric_report_payload("Thermal"),
+    ]);
+
+    bmc.expect(Expect::stream(&ids.sse_id, mixed.clone()));
+    let events = payload_ids(event_service.only_events().await?).await?;
+    assert_eq!(events, ["1", "2"]);
+
+    bmc.expect(Expect::stream(&ids.sse_id, mixed.clone()));
+    let reports = payload_ids(event_service.only_metric_reports().await?).await?;
+    assert_eq!(reports, ["PowerUsage", "Thermal"]);
+
+    bmc.expect(Expect::stream(&ids.sse_id, mixed));
+    let filtered = payload_ids(
+        event_service
+            .events_filtered(|payload| payload.id() != "PowerUsage")
+            .await?,
+    )
+    .await?;
+    assert_eq!(filtered, ["1", "2", "Thermal"]);
+    bmc.assert_all_consumed()?;
+
+    Ok(())
+}
+
+async fn payload_ids(
+    stream: BoxTryStream<EventStreamPayload, Error<Bmc>>,
+) -> Result<Vec<String>, Error<Bmc>> {
+    stream
+        .map(|payload| payload.map(|payload| payload.id().to_string()))
+        .collect::<Vec<_>>()
+        .await
+        .into_iter()
+        .collect()
+}
+
+fn event_payload(id: &str) -> Value {
+    json!({
+        ODATA_ID: format!("/redfish/v1/EventService/SSE#/Event{id}"),
+        ODATA_TYPE: "#Event.v1_6_0.Event",
+        "Id": id,
+        "Name": "Event Array",
+        "Events": [{
+            ODATA_ID: format!("/redfish/v1/EventService/SSE#/Events/{id}"),
+            "MemberId": id,
+            "EventId": id,
+            "EventType": "Alert",
+            "MessageId": "ResourceEvent.1.2.ResourceRemoved",
+            "MessageSeverity": "OK"
+        }]
+    })
+}
+
+fn metric_report_payload(id: &str) -> Value {
+    json!({
+        ODATA_ID: format!("/redfish/v1/TelemetryService/MetricReports/{id}"),
+        ODATA_TYPE: "#MetricReport.v1_3_0.MetricReport",
+        "Id": id,
+        "Name": "Metric report",
+        "MetricValues": [{
+            "MetricId": "AverageConsumedWatts",
+            "MetricValue": "100",
+            "Timestamp": "2016-11-08T12:25:00-05:00"
+        }]
+    })
+}
+
+struct Ids {
+    root_id: ODataId,
+    event_service_id: String,
+    subscriptions_id: String,
+    subscription_id: String,
+    sse_id: String,
+}
+
+fn ids() -> Ids {
+    let root_id = ODataId::service_root();
+    let event_service_id = format!("{root_id}/EventService");
+    let subscriptions_id = format!("{event_service_id}/Subscriptions");
+    let subscription_id = format!("{subscriptions_id}/1");
+    let sse_id = format!("{event_service_id}/SSE");
+    Ids {
+        root_id,
+        event_service_id,
+        subscriptions_id,
+        subscription_id,
+        sse_id,
+    }
+}
+
+async fn subscribe(
+    bmc: &Bmc,
+    ids: &Ids,
+    event_service: &EventService<Bmc>,
+) -> Result<EventSubscription<Bmc>, Box<dyn StdError>> {
+    bmc.expect(Expect::create(
+        &ids.subscriptions_id,
+        json!({
+            "Destination": "https://listener.example.com/events",
+            "EventTypes": ["Alert", "StatusChange"],
+            "Context": "rack-1",
+            "Protocol": "Redfish"
+        }),
+        json!({
+            ODATA_ID: &ids.subscription_id,
+            ODATA_TYPE: EVENT_DESTINATION_DATA_TYPE,
+            "Id": "1",
+            "Name": "Event Subscription",
+            "Destination": "https://listener.example.com/events",
+            "EventTypes": ["Alert", "StatusChange"],
+            "Context": "rack-1",
+            "Protocol": "Redfish"
+        }),
+    ));
+
+    let response = event_service
+        .subscribe(
+            "https://listener.example.com/events".into(),
+            vec![EventType::Alert, EventType::StatusChange],
+            Some("rack-1".into()),
+        )
+        .await?;
+    let ModificationResponse::Entity(subscription) = response else {
+        return Err("expected created subscription".into());
+    };
+    Ok(subscription)
+}
+
+async fn get_event_service(
+    bmc: Arc<Bmc>,
+    ids: &Ids,
+) -> Result<EventService<Bmc>, Box<dyn StdError>> {
+    get_event_service_with_fields(bmc, ids, json!({})).await
+}
+
+async fn get_event_service_with_fields(
+    bmc: Arc<Bmc>,
+    ids: &Ids,
+    fields: Value,
+) -> Result<EventService<Bmc>, Box<dyn StdError>> {
+    bmc.expect(Expect::get(
+        &ids.root_id,
+        anonymous_1_9_service_root(
+            &ids.root_id,
+            json!({ "EventService": { ODATA_ID: &ids.event_service_id } }),
+        ),
+    ));
+    let root = ServiceRoot::new(bmc.clone()).await?;
+
+    let base = json!({
+        ODATA_ID: &ids.event_service_id,
+        ODATA_TYPE: EVENT_SERVICE_DATA_TYPE,
+        "Id": "EventService",
+        "Name": "Event Service",
+        "ServiceEnabled": true,
+        "Subscriptions": { ODATA_ID: &ids.subscriptions_id },
+        "ServerSentEventUri": &ids.sse_id
+    });
+    bmc.expect(Expect::get(
+        &ids.event_service_id,
+        json_merge([&base, &fields]),
+    ));
+
+    root.event_service()
+        .await?
+        .ok_or_else(|| std::io::Error::other("missing event service").into())
+}
@@ -0,0 +1,191 @@
+// SPDX-FileCopyrightText: Copyright (c) 2026 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//! Integration tests for Log Service entries filtering.
+
+use std::error::Error as StdError;
+use std::sync::Arc;
+
+use nv_redfish::log_service::LogService;
+use nv_redfish::ServiceRoot;
+use nv_redfish_core::EdmDateTimeOffset;
+use nv_redfish_core::FilterQuery;
+use nv_redfish_core::ODataId;
+use nv_redfish_tests::anonymous_1_9_service_root;
+use nv_redfish_tests::Bmc;
+use nv_redfish_tests::Expect;
+use nv_redfish_tests::ODATA_ID;
+use nv_redfish_tests::ODATA_TYPE;
+
+use serde_json::json;
+use serde_json::Value;
+use tokio::test;
+
+const MANAGER_COLLECTION_DATA_TYPE: &str = "#ManagerCollection.ManagerCollection";
+const MANAGER_DATA_TYPE: &str = "#Manager.v1_16_0.Manager";
+const LOG_SERVICE_COLLECTION_DATA_TYPE: &str = "#LogServiceCollection.LogServiceCollection";
+const LOG_SERVICE_DATA_TYPE: &str = "#LogService.v1_5_0.LogService";
+const LOG_ENTRY_COLLECTION_DATA_TYPE: &str = "#LogEntryCollection.LogEntryCollection";
+const LOG_ENTRY_DATA_TYPE: &str = "#LogEntry.v1_15_0.LogEntry";
+
+#[test]
+async fn list_entries_since_filters_on_created() -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(Bmc::default());
+    let ids = ids();
+    let log_service = get_log_service(bmc.clone(), &ids).await?;
+
+    let since: EdmDateTimeOffset = "2024-05-01T00:00:00Z".parse()?;
+    bmc.expect(Expect::filter(
+        &ids.entries_id,
+        &FilterQuery::ge(&"Created", since),
+        entries_payload(&ids, &["2", "3"]),
+    ));
+
+    let entries = log_service.list_entries_since(since).await?.unwrap();
+    let entry_ids: Vec<_> = entries.iter().map(|e| e.base.id.as_str()).collect();
+    assert_eq!(entry_ids, ["2", "3"]);
+    bmc.assert_all_consumed()?;
+
+    Ok(())
+}
+
+#[test]
+async fn list_entries_range_filters_on_both_bounds() -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(Bmc::default());
+    let ids = ids();
+    let log_service = get_log_service(bmc.clone(), &ids).await?;
+
+    let from: EdmDateTimeOffset = "2024-05-01T00:00:00Z".parse()?;
+    let to: EdmDateTimeOffset = "2024-05-02T00:00:00Z".parse()?;
+    bmc.expect(Expect::filter(
+        &ids.entries_id,
+        &FilterQuery::ge(&"Created", from)
+            .and()
+            .le_then(&"Created", to),
+        entries_payload(&ids, &["2"]),
+    ));
+
+    let entries = log_service.list_entries_range(from, to).await?.unwrap();
+    assert_eq!(entries.len(), 1);
+    assert_eq!(entries[0].base.id, "2");
+    bmc.assert_all_consumed()?;
+
+    Ok(())
+}
+
+struct Ids {
+    root_id: ODataId,
+    managers_id: String,
+    manager_id: String,
+    log_services_id: String,
+    log_service_id: String,
+    entries_id: String,
+}
+
+fn ids() -> Ids {
+    let root_id = ODataId::service_root();
+    let managers_id = format!("{root_id}/Managers");
+    let manager_id = format!("{managers_id}/1");
+    let log_services_id = format!("{manager_id}/LogServices");
+    let log_service_id = format!("{log_services_id}/SEL");
+    let entries_id = format!("{log_service_id}/Entries");
+    Ids {
+        root_id,
+        managers_id,
+        manager_id,
+        log_services_id,
+        log_service_id,
+        entries_id,
+    }
+}
+
+fn entries_payload(ids: &Ids, entry_ids: &[&str]) -> Value {
+    let members: Vec<_> = entry_ids
+        .iter()
+        .map(|id| {
+            json!({
+                ODATA_ID: format!("{}/{id}", ids.entries_id),
+                ODATA_TYPE: LOG_ENTRY_DATA_TYPE,
+                "Id": id,
+                "Name": format!("Log Entry {id}"),
+                "EntryType": "Event",
+                "Created": "2024-05-01T12:00:00Z"
+            })
+        })
+        .collect();
+    json!({
+        ODATA_ID: &ids.entries_id,
+        ODATA_TYPE: LOG_ENTRY_COLLECTION_DATA_TYPE,
+        "Name": "Log Entries",
+        "Members": members
+    })
+}
+
+async fn get_log_service(bmc: Arc<Bmc>, ids: &Ids) -> Result<LogService<Bmc>, Box<dyn StdError>> {
+    bmc.expect(Expect::get(
+        &ids.root_id,
+        anonymous_1_9_service_root(
+            &ids.root_id,
+            json!({ "Managers": { ODATA_ID: &ids.managers_id } }),
+        ),
+    ));
+    let root = ServiceRoot::new(bmc.clone()).await?;
+
+    bmc.expect(Expect::get(
+        &ids.managers_id,
+        json!({
+            ODATA_ID: &ids.managers_id,
+            ODATA_TYPE: MANAGER_COLLECTION_DATA_TYPE,
+            "Id": "Managers",
+            "Name": "Manager Collection",
+            "Members": [{
+                ODATA_ID: &ids.manager_id,
+                ODATA_TYPE: MANAGER_DATA_TYPE,
+                "Id": "1",
+                "Name": "Manager",
+                "LogServices": { ODATA_ID: &ids.log_services_id }
+            }]
+        }),
+    ));
+    let manager = root
+        .managers()
+        .await?
+        .unwrap()
+        .members()
+        .await?
+        .pop()
+        .unwrap();
+
+    bmc.expect(Expect::get(
+        &ids.log_services_id,
+        json!({
+            ODATA_ID: &ids.log_services_id,
+            ODATA_TYPE: LOG_SERVICE_COLLECTION_DATA_TYPE,
+            "Name": "Log Service Collection",
+            "Members": [{ ODATA_ID: &ids.log_service_id }]
+        }),
+    ));
+    bmc.expect(Expect::get(
+        &ids.log_service_id,
+        json!({
+            ODATA_ID: &ids.log_service_id,
+            ODATA_TYPE: LOG_SERVICE_DATA_TYPE,
+            "Id": "SEL",
+            "Name": "System Event Log",
+            "Entries": { ODATA_ID: &ids.entries_id }
+        }),
+    ));
+
+    Ok(manager.log_services().await?.unwrap().pop().unwrap())
+}
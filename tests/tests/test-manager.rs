@@ -17,8 +17,11 @@
 use std::error::Error as StdError;
 use std::sync::Arc;
 
+use nv_redfish::ethernet_interface::EthernetInterface;
+use nv_redfish::ethernet_interface::Ipv4addressUpdate;
 use nv_redfish::manager::Manager;
 use nv_redfish::manager::ManagerResetToDefaultsType;
+use nv_redfish::manager::ManagerType;
 use nv_redfish::resource::ResetType;
 use nv_redfish::Resource;
 use nv_redfish::ServiceRoot;
@@ -43,6 +46,73 @@ const MANAGER_COLLECTION_DATA_TYPE: &str = "#ManagerCollection.ManagerCollection
 const MANAGER_DATA_TYPE: &str = "#Manager.v1_16_0.Manager";
 const MANAGER_NETWORK_PROTOCOL_DATA_TYPE: &str =
     "#ManagerNetworkProtocol.v1_5_0.ManagerNetworkProtocol";
+const ETHERNET_INTERFACE_COLLECTION_DATA_TYPE: &str =
+    "#EthernetInterfaceCollection.EthernetInterfaceCollection";
+const ETHERNET_INTERFACE_DATA_TYPE: &str = "#EthernetInterface.v1_9_0.EthernetInterface";
+
+#[test]
+async fn ethernet_interface_reads_addresses() -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(Bmc::default());
+    let ids = ids();
+    let interface = get_ethernet_interface(bmc, &ids).await?;
+
+    assert_eq!(
+        interface.mac_address().map(|v| v.to_string()),
+        Some("aa:bb:cc:dd:ee:ff".to_string())
+    );
+    let ipv4 = interface.ipv4_addresses();
+    assert_eq!(ipv4.len(), 1);
+    assert_eq!(ipv4[0].address, Some(Some("192.168.0.10".to_string())));
+    assert_eq!(ipv4[0].subnet_mask, Some(Some("255.255.255.0".to_string())));
+    let ipv6 = interface.ipv6_addresses();
+    assert_eq!(ipv6.len(), 1);
+    assert_eq!(ipv6[0].address, Some(Some("fe80::1".to_string())));
+    assert!(interface.vlan().is_some());
+
+    Ok(())
+}
+
+#[test]
+async fn ethernet_interface_updates_static_ipv4() -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(Bmc::default());
+    let ids = ids();
+    let interface = get_ethernet_interface(bmc.clone(), &ids).await?;
+
+    bmc.expect(Expect::update(
+        &ids.ethernet_interface_id,
+        json!({
+            "DHCPv4": { "DHCPEnabled": false },
+            "IPv4StaticAddresses": [
+                {
+                    "Address": "10.0.0.5",
+                    "SubnetMask": "255.255.0.0",
+                    "Gateway": "10.0.0.1"
+                }
+            ]
+        }),
+        ethernet_interface_payload(&ids),
+    ));
+    let config = Ipv4addressUpdate::builder()
+        .with_address("10.0.0.5".into())
+        .with_subnet_mask("255.255.0.0".into())
+        .with_gateway("10.0.0.1".into())
+        .build();
+    assert!(matches!(
+        interface.update_ipv4(config).await?,
+        ModificationResponse::Entity(_)
+    ));
+
+    bmc.expect(Expect::update_empty(
+        &ids.ethernet_interface_id,
+        json!({ "VLAN": { "VLANEnable": false } }),
+    ));
+    assert!(matches!(
+        interface.set_vlan_enabled(false).await?,
+        ModificationResponse::Empty
+    ));
+
+    Ok(())
+}
 
 #[test]
 async fn network_protocol_returns_none_when_link_is_absent() -> Result<(), Box<dyn StdError>> {
@@ -131,6 +201,44 @@ async fn reset_invokes_manager_reset_action() -> Result<(), Box<dyn StdError>> {
     Ok(())
 }
 
+#[test]
+async fn reset_validates_advertised_allowable_reset_types() -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(Bmc::default());
+    let ids = ids();
+    let action_target = format!("{}/Actions/Manager.Reset", ids.manager_id);
+    let manager = get_manager(
+        bmc.clone(),
+        &ids,
+        manager_payload_with_fields(
+            &ids,
+            json!({
+                "Actions": {
+                    "#Manager.Reset": {
+                        "target": &action_target,
+                        "ResetType@Redfish.AllowableValues": ["GracefulRestart", "ForceRestart"]
+                    }
+                }
+            }),
+        ),
+    )
+    .await?;
+
+    expect_redfish_reset_action(&bmc, &action_target, Some("GracefulRestart"));
+    assert!(matches!(
+        manager.reset(Some(ResetType::GracefulRestart)).await?,
+        ModificationResponse::Entity(())
+    ));
+
+    assert!(matches!(
+        manager.reset(Some(ResetType::PowerCycle)).await,
+        Err(nv_redfish::Error::ResetTypeNotAllowed(
+            ResetType::PowerCycle
+        ))
+    ));
+
+    Ok(())
+}
+
 #[test]
 async fn reset_to_defaults_invokes_manager_reset_to_defaults_action(
 ) -> Result<(), Box<dyn StdError>> {
@@ -185,6 +293,61 @@ async fn reset_helpers_return_action_not_available_when_manager_actions_are_abse
     Ok(())
 }
 
+#[test]
+async fn by_manager_type_returns_only_matching_managers() -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(Bmc::default());
+    let ids = ids();
+    let root = expect_anonymous_1_9_service_root(
+        bmc.clone(),
+        &ids,
+        json!({
+            "Managers": { ODATA_ID: &ids.managers_id }
+        }),
+    )
+    .await?;
+    let idrac_id = format!("{}/iDRAC.Embedded.1", ids.managers_id);
+    let ism_id = format!("{}/iSM", ids.managers_id);
+    let untyped_id = format!("{}/Untyped", ids.managers_id);
+    bmc.expect(Expect::get(
+        &ids.managers_id,
+        json!({
+            ODATA_ID: &ids.managers_id,
+            ODATA_TYPE: MANAGER_COLLECTION_DATA_TYPE,
+            "Id": "Managers",
+            "Name": "Manager Collection",
+            "Members": [
+                json_merge([&manager_payload_with_id(&idrac_id), &json!({ "ManagerType": "BMC" })]),
+                json_merge([
+                    &manager_payload_with_id(&ism_id),
+                    &json!({ "ManagerType": "ManagementController" }),
+                ]),
+                manager_payload_with_id(&untyped_id),
+            ]
+        }),
+    ));
+    let collection = root.managers().await?.ok_or("missing managers")?;
+
+    let bmcs = collection.by_manager_type(ManagerType::Bmc).await?;
+    let bmc_ids = bmcs
+        .iter()
+        .map(|m| m.odata_id().to_string())
+        .collect::<Vec<_>>();
+    assert_eq!(bmc_ids, [idrac_id]);
+
+    let controllers = collection
+        .by_manager_type(ManagerType::ManagementController)
+        .await?;
+    assert_eq!(controllers.len(), 1);
+    assert_eq!(controllers[0].odata_id().to_string(), ism_id);
+
+    assert!(collection
+        .by_manager_type(ManagerType::EnclosureManager)
+        .await?
+        .is_empty());
+
+    Ok(())
+}
+
 #[test]
 async fn ami_viking_missing_root_managers_nav_workaround() -> Result<(), Box<dyn StdError>> {
     let bmc = Arc::new(Bmc::default());
@@ -332,6 +495,8 @@ struct Ids {
     managers_id: String,
     manager_id: String,
     manager_network_protocol_id: String,
+    ethernet_interfaces_id: String,
+    ethernet_interface_id: String,
 }
 
 fn ids() -> Ids {
@@ -339,11 +504,15 @@ fn ids() -> Ids {
     let managers_id = format!("{root_id}/Managers");
     let manager_id = format!("{managers_id}/1");
     let manager_network_protocol_id = format!("{manager_id}/NetworkProtocol");
+    let ethernet_interfaces_id = format!("{manager_id}/EthernetInterfaces");
+    let ethernet_interface_id = format!("{ethernet_interfaces_id}/eth0");
     Ids {
         root_id,
         managers_id,
         manager_id,
         manager_network_protocol_id,
+        ethernet_interfaces_id,
+        ethernet_interface_id,
     }
 }
 
@@ -419,3 +588,69 @@ async fn get_manager(
         std::io::Error::new(std::io::ErrorKind::InvalidData, "missing manager").into()
     })
 }
+
+async fn get_ethernet_interface(
+    bmc: Arc<Bmc>,
+    ids: &Ids,
+) -> Result<EthernetInterface<Bmc>, Box<dyn StdError>> {
+    let manager = get_manager(
+        bmc.clone(),
+        ids,
+        manager_payload_with_fields(
+            ids,
+            json!({ "EthernetInterfaces": { ODATA_ID: &ids.ethernet_interfaces_id } }),
+        ),
+    )
+    .await?;
+    bmc.expect(Expect::get(
+        &ids.ethernet_interfaces_id,
+        json!({
+            ODATA_ID: &ids.ethernet_interfaces_id,
+            ODATA_TYPE: ETHERNET_INTERFACE_COLLECTION_DATA_TYPE,
+            "Name": "Ethernet Interface Collection",
+            "Members": [{ ODATA_ID: &ids.ethernet_interface_id }]
+        }),
+    ));
+    let collection = manager.ethernet_interfaces().await?.unwrap();
+    bmc.expect(Expect::get(
+        &ids.ethernet_interface_id,
+        ethernet_interface_payload(ids),
+    ));
+    let mut members = collection.members().await?;
+    members.pop().ok_or_else(|| {
+        std::io::Error::new(
+            std::io::ErrorKind::InvalidData,
+            "missing ethernet interface",
+        )
+        .into()
+    })
+}
+
+fn ethernet_interface_payload(ids: &Ids) -> Value {
+    json!({
+        ODATA_ID: &ids.ethernet_interface_id,
+        ODATA_TYPE: ETHERNET_INTERFACE_DATA_TYPE,
+        "Id": "eth0",
+        "Name": "Manager Ethernet Interface",
+        "MACAddress": "aa:bb:cc:dd:ee:ff",
+        "IPv4Addresses": [
+            {
+                "Address": "192.168.0.10",
+                "SubnetMask": "255.255.255.0",
+                "Gateway": "192.168.0.1",
+                "AddressOrigin": "DHCP"
+            }
+        ],
+        "IPv6Addresses": [
+            {
+                "Address": "fe80::1",
+                "PrefixLength": 64,
+                "AddressOrigin": "SLAAC"
+            }
+        ],
+        "VLAN": {
+            "VLANEnable": true,
+            "VLANId": 100
+        }
+    })
+}
@@ -0,0 +1,179 @@
+// SPDX-FileCopyrightText: Copyright (c) 2026 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Integration tests of message registries.
+
+use std::error::Error as StdError;
+use std::sync::Arc;
+
+use nv_redfish::registry::MessageRegistries;
+use nv_redfish::ServiceRoot;
+use nv_redfish_core::ODataId;
+use nv_redfish_tests::Bmc;
+use nv_redfish_tests::Expect;
+use nv_redfish_tests::ODATA_ID;
+use nv_redfish_tests::ODATA_TYPE;
+
+use serde_json::json;
+use tokio::test;
+
+const ROOT_DATA_TYPE: &str = "#ServiceRoot.v1_13_0.ServiceRoot";
+const REGISTRY_FILE_COLLECTION_DATA_TYPE: &str =
+    "#MessageRegistryFileCollection.MessageRegistryFileCollection";
+const REGISTRY_FILE_DATA_TYPE: &str = "#MessageRegistryFile.v1_1_3.MessageRegistryFile";
+
+const REGISTRY_URI: &str = "/redfish/v1/Registries/ResourceEvent/ResourceEvent.1.2.json";
+
+#[test]
+async fn resolve_substitutes_message_args() -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(Bmc::default());
+    let registries = get_registries(bmc.clone()).await?;
+    assert_eq!(registries.registries(), ["ResourceEvent.1.2"]);
+
+    bmc.expect(Expect::get(REGISTRY_URI, registry_payload()));
+    assert_eq!(
+        registries
+            .resolve(
+                "ResourceEvent.1.2.ResourceRemoved",
+                &["/redfish/v1/Chassis/1", "Chassis"]
+            )
+            .await?
+            .as_deref(),
+        Some("The resource /redfish/v1/Chassis/1 of type Chassis has been removed.")
+    );
+
+    // Registry is cached: no further requests are expected. Minor
+    // version mismatch falls back to the same major version.
+    assert_eq!(
+        registries
+            .resolve("ResourceEvent.1.0.ResourceCreated", &[] as &[&str])
+            .await?
+            .as_deref(),
+        Some("The resource has been created successfully.")
+    );
+    assert_eq!(
+        registries
+            .resolve("ResourceEvent.1.2.Unknown", &[] as &[&str])
+            .await?,
+        None
+    );
+    assert_eq!(
+        registries
+            .resolve("Base.1.8.GeneralError", &[] as &[&str])
+            .await?,
+        None
+    );
+
+    Ok(())
+}
+
+#[test]
+async fn message_registries_absent() -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(Bmc::default());
+    let root_id = ODataId::service_root();
+    bmc.expect(Expect::get(&root_id, root_payload(&root_id, None)));
+    let service_root = ServiceRoot::new(bmc.clone()).await?;
+    assert!(service_root.message_registries().await?.is_none());
+    Ok(())
+}
+
+async fn get_registries(bmc: Arc<Bmc>) -> Result<MessageRegistries<Bmc>, Box<dyn StdError>> {
+    let root_id = ODataId::service_root();
+    let collection_id = format!("{root_id}/Registries");
+    let file_id = format!("{collection_id}/ResourceEvent.1.2");
+    bmc.expect(Expect::get(
+        &root_id,
+        root_payload(&root_id, Some(&collection_id)),
+    ));
+    let service_root = ServiceRoot::new(bmc.clone()).await?;
+
+    bmc.expect(Expect::get(
+        &collection_id,
+        json!({
+            ODATA_ID: &collection_id,
+            ODATA_TYPE: REGISTRY_FILE_COLLECTION_DATA_TYPE,
+            "Name": "Registry File Collection",
+            "Members": [
+                { ODATA_ID: &file_id },
+            ],
+        }),
+    ));
+    bmc.expect(Expect::get(
+        &file_id,
+        json!({
+            ODATA_ID: &file_id,
+            ODATA_TYPE: REGISTRY_FILE_DATA_TYPE,
+            "Id": "ResourceEvent.1.2",
+            "Name": "Resource Event Message Registry File",
+            "Registry": "ResourceEvent.1.2",
+            "Languages": ["en"],
+            "Location": [
+                {
+                    "Language": "en",
+                    "Uri": REGISTRY_URI,
+                    "PublicationUri": "https://redfish.dmtf.org/registries/ResourceEvent.1.2.0.json",
+                },
+            ],
+        }),
+    ));
+    Ok(service_root.message_registries().await?.unwrap())
+}
+
+fn root_payload(root_id: &ODataId, registries: Option<&str>) -> serde_json::Value {
+    let mut root = json!({
+        ODATA_ID: root_id,
+        ODATA_TYPE: ROOT_DATA_TYPE,
+        "Id": "RootService",
+        "Name": "RootService",
+        "Links": {
+            "Sessions": {
+                ODATA_ID: format!("{root_id}/SessionService/Sessions"),
+            }
+        },
+    });
+    if let Some(registries) = registries {
+        root["Registries"] = json!({ ODATA_ID: registries });
+    }
+    root
+}
+
+fn registry_payload() -> serde_json::Value {
+    json!({
+        ODATA_TYPE: "#MessageRegistry.v1_6_0.MessageRegistry",
+        "Id": "ResourceEvent.1.2.0",
+        "Name": "Resource Event Message Registry",
+        "Language": "en",
+        "RegistryPrefix": "ResourceEvent",
+        "RegistryVersion": "1.2.0",
+        "OwningEntity": "DMTF",
+        "Messages": {
+            "ResourceCreated": {
+                "Description": "Indicates that all conditions of a successful creation operation have been met.",
+                "Message": "The resource has been created successfully.",
+                "NumberOfArgs": 0,
+                "Resolution": "None.",
+                "MessageSeverity": "OK",
+            },
+            "ResourceRemoved": {
+                "Description": "Indicates that a resource has been removed.",
+                "Message": "The resource %1 of type %2 has been removed.",
+                "NumberOfArgs": 2,
+                "ParamTypes": ["string", "string"],
+                "Resolution": "None.",
+                "MessageSeverity": "OK",
+            },
+        },
+    })
+}
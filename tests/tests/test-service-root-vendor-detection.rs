@@ -0,0 +1,152 @@
+// SPDX-FileCopyrightText: Copyright (c) 2026 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//! Integration tests for vendor detection from standard ServiceRoot fields.
+
+use nv_redfish::oem::Vendor;
+use nv_redfish::ServiceRoot;
+use nv_redfish_core::ODataId;
+use nv_redfish_tests::json_merge;
+use nv_redfish_tests::Bmc;
+use nv_redfish_tests::Expect;
+use nv_redfish_tests::ODATA_ID;
+use nv_redfish_tests::ODATA_TYPE;
+use serde_json::json;
+use serde_json::Value;
+use std::error::Error as StdError;
+use std::sync::Arc;
+use tokio::test;
+
+const SERVICE_ROOT_DATA_TYPE: &str = "#ServiceRoot.v1_13_0.ServiceRoot";
+
+#[test]
+async fn detects_dell_from_idrac_product() -> Result<(), Box<dyn StdError>> {
+    let root = get_root(json!({
+        "Vendor": "Dell",
+        "Product": "Integrated Dell Remote Access Controller"
+    }))
+    .await?;
+    assert_eq!(Vendor::detect_from_service_root(&root), Some(Vendor::Dell));
+
+    let root = get_root(json!({ "Product": "iDRAC9" })).await?;
+    assert_eq!(Vendor::detect_from_service_root(&root), Some(Vendor::Dell));
+
+    Ok(())
+}
+
+#[test]
+async fn detects_ami() -> Result<(), Box<dyn StdError>> {
+    let root = get_root(json!({
+        "Vendor": "AMI",
+        "Product": "AMI Redfish Server"
+    }))
+    .await?;
+    assert_eq!(Vendor::detect_from_service_root(&root), Some(Vendor::Ami));
+
+    Ok(())
+}
+
+#[test]
+async fn detects_hpe_from_ilo_product() -> Result<(), Box<dyn StdError>> {
+    let root = get_root(json!({
+        "Vendor": "HPE",
+        "Product": "ProLiant DL380 Gen11"
+    }))
+    .await?;
+    assert_eq!(Vendor::detect_from_service_root(&root), Some(Vendor::Hpe));
+
+    let root = get_root(json!({ "Product": "iLO 6" })).await?;
+    assert_eq!(Vendor::detect_from_service_root(&root), Some(Vendor::Hpe));
+
+    Ok(())
+}
+
+#[test]
+async fn detects_supermicro() -> Result<(), Box<dyn StdError>> {
+    let root = get_root(json!({
+        "Vendor": "Supermicro",
+        "Product": "X13DEM"
+    }))
+    .await?;
+    assert_eq!(
+        Vendor::detect_from_service_root(&root),
+        Some(Vendor::Supermicro)
+    );
+
+    Ok(())
+}
+
+#[test]
+async fn detects_nvidia() -> Result<(), Box<dyn StdError>> {
+    let root = get_root(json!({
+        "Vendor": "Nvidia",
+        "Product": "Nvidia-BMCMezz"
+    }))
+    .await?;
+    assert_eq!(
+        Vendor::detect_from_service_root(&root),
+        Some(Vendor::Nvidia)
+    );
+
+    Ok(())
+}
+
+#[test]
+async fn unknown_vendor_is_not_detected() -> Result<(), Box<dyn StdError>> {
+    let root = get_root(json!({
+        "Vendor": "Contoso",
+        "Product": "Pilot BMC"
+    }))
+    .await?;
+    assert_eq!(Vendor::detect_from_service_root(&root), None);
+
+    let root = get_root(json!({})).await?;
+    assert_eq!(Vendor::detect_from_service_root(&root), None);
+
+    Ok(())
+}
+
+#[test]
+async fn ambiguous_vendor_is_not_detected() -> Result<(), Box<dyn StdError>> {
+    let root = get_root(json!({
+        "Vendor": "AMI",
+        "Product": "NVIDIA GB300"
+    }))
+    .await?;
+    assert_eq!(Vendor::detect_from_service_root(&root), None);
+
+    Ok(())
+}
+
+async fn get_root(fields: Value) -> Result<ServiceRoot<Bmc>, Box<dyn StdError>> {
+    let bmc = Arc::new(Bmc::default());
+    bmc.expect(Expect::get(ODataId::service_root(), root_payload(fields)));
+    ServiceRoot::new(bmc).await.map_err(Into::into)
+}
+
+fn root_payload(fields: Value) -> Value {
+    let root_id = ODataId::service_root();
+    let base = json!({
+        ODATA_ID: &root_id,
+        ODATA_TYPE: SERVICE_ROOT_DATA_TYPE,
+        "Id": "RootService",
+        "Name": "RootService",
+        "Links": {
+            "Sessions": {
+                ODATA_ID: format!("{root_id}/SessionService/Sessions"),
+            }
+        },
+    });
+    json_merge([&base, &fields])
+}
@@ -121,6 +121,48 @@ async fn create_session() -> Result<(), Box<dyn StdError>> {
     Ok(())
 }
 
+#[test]
+async fn create_with_credentials_and_log_out() -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(Bmc::default());
+    let root_id = ODataId::service_root();
+    let session_service = get_session_service(bmc.clone(), &root_id).await?;
+    let sessions_id = format!("{}/Sessions", session_service.raw().odata_id());
+    let session_id = format!("{sessions_id}/1234567890ABCDEF");
+    let create = SessionCreate::builder("Administrator".into(), "password".into()).build();
+
+    bmc.expect(Expect::expand(
+        &sessions_id,
+        json!({
+            ODATA_ID: &sessions_id,
+            ODATA_TYPE: SESSIONS_DATA_TYPE,
+            "Name": "User Sessions",
+            "Members": [],
+        }),
+    ));
+    bmc.expect(Expect::create_session(
+        &sessions_id,
+        serde_json::to_value(&create)?,
+        json!({
+            ODATA_ID: &session_id,
+            ODATA_TYPE: SESSION_DATA_TYPE,
+            "Id": "1234567890ABCDEF",
+            "Name": "User Session",
+            "UserName": "Administrator"
+        }),
+        "session-token-123",
+        &session_id,
+    ));
+
+    let session = session_service.create("Administrator", "password").await?;
+    assert_eq!(session.auth_token(), Some("session-token-123"));
+    assert_eq!(session.raw().user_name, Some(Some("Administrator".into())));
+
+    bmc.expect(Expect::delete(&session_id));
+    assert_empty(session.delete().await?);
+
+    Ok(())
+}
+
 #[test]
 async fn delete_created_session_uses_location() -> Result<(), Box<dyn StdError>> {
     let bmc = Arc::new(Bmc::default());
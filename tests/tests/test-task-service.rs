@@ -19,11 +19,14 @@ use std::error::Error as StdError;
 use std::io::Error as IoError;
 use std::io::ErrorKind;
 use std::sync::Arc;
+use std::sync::Mutex;
+use std::time::Duration;
 
 use nv_redfish::core::AsyncTask;
 use nv_redfish::core::ODataId;
 use nv_redfish::schema::resource::Health as TaskStatus;
 use nv_redfish::schema::task::TaskState;
+use nv_redfish::Error;
 use nv_redfish::ServiceRoot;
 use nv_redfish_tests::Bmc;
 use nv_redfish_tests::Expect;
@@ -149,3 +152,264 @@ async fn task_link_fetch_exposes_schema_fields() -> Result<(), Box<dyn StdError>
 
     Ok(())
 }
+
+#[test]
+async fn task_poll_until_complete_waits_for_terminal_state() -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(Bmc::default());
+
+    bmc.expect(Expect::get(
+        "/redfish/v1",
+        json!({
+            ODATA_ID: "/redfish/v1",
+            ODATA_TYPE: "#ServiceRoot.v1_13_0.ServiceRoot",
+            "Id": "RootService",
+            "Name": "Root Service",
+            "Tasks": {
+                ODATA_ID: TASK_SERVICE_PATH
+            },
+            "Links": {
+                "Sessions": {
+                    ODATA_ID: "/redfish/v1/SessionService/Sessions"
+                }
+            }
+        }),
+    ));
+
+    bmc.expect(Expect::get(
+        TASK_SERVICE_PATH,
+        json!({
+            ODATA_ID: TASK_SERVICE_PATH,
+            ODATA_TYPE: "#TaskService.v1_1_4.TaskService",
+            "Id": "TaskService",
+            "Name": "Task Service",
+            "Tasks": {
+                ODATA_ID: "/redfish/v1/TaskService/Tasks"
+            }
+        }),
+    ));
+
+    let task_json = |state: &str, percent: u32| {
+        json!({
+            ODATA_ID: TASK_PATH,
+            ODATA_TYPE: "#Task.v1_4_3.Task",
+            "Id": "42",
+            "Name": "Task 42",
+            "TaskState": state,
+            "TaskStatus": "OK",
+            "PercentComplete": percent,
+            "Messages": [{
+                "MessageId": "Base.1.0.TaskMessage",
+                "Message": format!("Task is {state}.")
+            }]
+        })
+    };
+    bmc.expect_sequence([
+        Expect::get(TASK_PATH, task_json("Running", 10)),
+        Expect::get(TASK_PATH, task_json("Running", 60)),
+        Expect::get(TASK_PATH, task_json("Completed", 100)),
+    ]);
+
+    let root = ServiceRoot::new(Arc::clone(&bmc)).await?;
+    let task_service = root
+        .task_service()
+        .await?
+        .ok_or_else(|| IoError::new(ErrorKind::NotFound, "expected task service"))?;
+
+    let task = task_service.task(AsyncTask {
+        location: ODataId::from(TASK_PATH.to_string()).into(),
+        retry_after: Some(Duration::from_secs(3)),
+    })?;
+
+    let sleeps = Mutex::new(Vec::new());
+    let task = task
+        .poll_until_complete(Duration::from_secs(1), 5, |duration| {
+            sleeps.lock().expect("not poisoned").push(duration);
+            async {}
+        })
+        .await?;
+
+    assert_eq!(task.task_state, Some(TaskState::Completed));
+    assert_eq!(task.percent_complete.flatten(), Some(100));
+    let messages = task
+        .messages
+        .iter()
+        .flatten()
+        .filter_map(|message| message.message.as_deref())
+        .collect::<Vec<_>>();
+    assert_eq!(messages, vec!["Task is Completed."]);
+    assert_eq!(
+        sleeps.into_inner().expect("not poisoned"),
+        [3, 1, 1].map(Duration::from_secs)
+    );
+    bmc.assert_all_consumed()?;
+
+    Ok(())
+}
+
+#[test]
+async fn task_poll_until_complete_gives_up_after_max_attempts() -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(Bmc::default());
+    expect_task_service(&bmc);
+
+    let root = ServiceRoot::new(bmc.clone()).await?;
+    let task_service = root
+        .task_service()
+        .await?
+        .ok_or_else(|| IoError::new(ErrorKind::NotFound, "expected task service"))?;
+
+    let task_path = format!("{TASKS_PATH}/7");
+    bmc.expect_sequence([
+        Expect::get(&task_path, task_json("7", "Running")),
+        Expect::get(&task_path, task_json("7", "Running")),
+    ]);
+
+    let task = task_service.task(AsyncTask {
+        location: ODataId::from(task_path.clone()).into(),
+        retry_after: None,
+    })?;
+    let sleeps = Mutex::new(Vec::new());
+    let result = task
+        .poll_until_complete(Duration::from_secs(1), 2, |duration| {
+            sleeps.lock().expect("not poisoned").push(duration);
+            async {}
+        })
+        .await;
+
+    assert!(matches!(
+        result,
+        Err(Error::TaskNotCompleted { ref task, attempts: 2 }) if task.to_string() == task_path
+    ));
+    assert_eq!(
+        sleeps.into_inner().expect("not poisoned"),
+        [Duration::from_secs(1)]
+    );
+    bmc.assert_all_consumed()?;
+
+    Ok(())
+}
+
+const TASKS_PATH: &str = "/redfish/v1/TaskService/Tasks";
+
+fn expect_task_service(bmc: &Bmc) {
+    bmc.expect(Expect::get(
+        "/redfish/v1",
+        json!({
+            ODATA_ID: "/redfish/v1",
+            ODATA_TYPE: "#ServiceRoot.v1_13_0.ServiceRoot",
+            "Id": "RootService",
+            "Name": "Root Service",
+            "ProtocolFeaturesSupported": {
+                "ExpandQuery": {
+                    "NoLinks": true
+                }
+            },
+            "Tasks": {
+                ODATA_ID: TASK_SERVICE_PATH
+            },
+            "Links": {
+                "Sessions": {
+                    ODATA_ID: "/redfish/v1/SessionService/Sessions"
+                }
+            }
+        }),
+    ));
+
+    bmc.expect(Expect::get(
+        TASK_SERVICE_PATH,
+        json!({
+            ODATA_ID: TASK_SERVICE_PATH,
+            ODATA_TYPE: "#TaskService.v1_1_4.TaskService",
+            "Id": "TaskService",
+            "Name": "Task Service",
+            "Tasks": {
+                ODATA_ID: TASKS_PATH
+            }
+        }),
+    ));
+}
+
+fn task_json(id: &str, state: &str) -> serde_json::Value {
+    json!({
+        ODATA_ID: format!("{TASKS_PATH}/{id}"),
+        ODATA_TYPE: "#Task.v1_4_3.Task",
+        "Id": id,
+        "Name": format!("Task {id}"),
+        "TaskState": state,
+    })
+}
+
+#[test]
+async fn list_tasks_and_get_task_by_id() -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(Bmc::default());
+    expect_task_service(&bmc);
+
+    let root = ServiceRoot::new(bmc.clone()).await?;
+    let task_service = root
+        .task_service()
+        .await?
+        .ok_or_else(|| IoError::new(ErrorKind::NotFound, "expected task service"))?;
+
+    bmc.expect(Expect::expand(
+        TASKS_PATH,
+        json!({
+            ODATA_ID: TASKS_PATH,
+            ODATA_TYPE: "#TaskCollection.TaskCollection",
+            "Name": "Task Collection",
+            "Members": [task_json("1", "Completed"), task_json("2", "Running")],
+        }),
+    ));
+
+    let tasks = task_service.tasks().await?;
+    let mut states = Vec::new();
+    for task in &tasks {
+        states.push(task.fetch().await?.task_state);
+    }
+    assert_eq!(
+        states,
+        vec![Some(TaskState::Completed), Some(TaskState::Running)]
+    );
+
+    bmc.expect(Expect::get(TASK_PATH, task_json("42", "Pending")));
+    let task = task_service.task_by_id("42").await?;
+    assert_eq!(task.task_state, Some(TaskState::Pending));
+    bmc.assert_all_consumed()?;
+
+    Ok(())
+}
+
+#[test]
+async fn delete_completed_keeps_running_tasks() -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(Bmc::default());
+    expect_task_service(&bmc);
+
+    let root = ServiceRoot::new(bmc.clone()).await?;
+    let task_service = root
+        .task_service()
+        .await?
+        .ok_or_else(|| IoError::new(ErrorKind::NotFound, "expected task service"))?;
+
+    bmc.expect(Expect::expand(
+        TASKS_PATH,
+        json!({
+            ODATA_ID: TASKS_PATH,
+            ODATA_TYPE: "#TaskCollection.TaskCollection",
+            "Name": "Task Collection",
+            "Members": [
+                task_json("1", "Completed"),
+                task_json("2", "Running"),
+                task_json("3", "Exception"),
+            ],
+        }),
+    ));
+    bmc.expect(Expect::delete(format!("{TASKS_PATH}/1")));
+    bmc.expect(Expect::delete(format!("{TASKS_PATH}/3")));
+
+    let deleted = task_service.delete_completed().await?;
+    assert_eq!(
+        deleted.iter().map(ToString::to_string).collect::<Vec<_>>(),
+        vec![format!("{TASKS_PATH}/1"), format!("{TASKS_PATH}/3")]
+    );
+    bmc.assert_all_consumed()?;
+
+    Ok(())
+}
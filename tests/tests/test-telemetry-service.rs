@@ -25,6 +25,7 @@ use nv_redfish::telemetry_service::MetricReportDefinition;
 use nv_redfish::telemetry_service::MetricReportDefinitionCreate;
 use nv_redfish::telemetry_service::MetricReportDefinitionUpdate;
 use nv_redfish::telemetry_service::TelemetryService;
+use nv_redfish::Error;
 use nv_redfish::ServiceRoot;
 use nv_redfish_core::ODataId;
 use nv_redfish_tests::assert_empty;
@@ -50,6 +51,7 @@ const METRIC_REPORT_DEFINITION_COLLECTION_DATA_TYPE: &str =
 
 const METRIC_REPORT_DEFINITION_DATA_TYPE: &str =
     "#MetricReportDefinition.v1_4_7.MetricReportDefinition";
+const METRIC_REPORT_DATA_TYPE: &str = "#MetricReport.v1_5_0.MetricReport";
 
 struct TelemetryIds {
     root: ODataId,
@@ -58,6 +60,7 @@ struct TelemetryIds {
     metric_definition: String,
     metric_report_definitions: String,
     metric_report_definition: String,
+    metric_reports: String,
 }
 
 fn telemetry_ids() -> TelemetryIds {
@@ -67,6 +70,7 @@ fn telemetry_ids() -> TelemetryIds {
     let metric_definition = format!("{metric_definitions}/Temperature");
     let metric_report_definitions = format!("{service}/MetricReportDefinitions");
     let metric_report_definition = format!("{metric_report_definitions}/ThermalReport");
+    let metric_reports = format!("{service}/MetricReports");
 
     TelemetryIds {
         root,
@@ -75,6 +79,7 @@ fn telemetry_ids() -> TelemetryIds {
         metric_definition,
         metric_report_definitions,
         metric_report_definition,
+        metric_reports,
     }
 }
 
@@ -240,6 +245,89 @@ async fn update_and_delete_definitions_preserve_task_and_empty_responses(
     Ok(())
 }
 
+#[test]
+async fn delete_definitions_by_id() -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(Bmc::default());
+    let ids = telemetry_ids();
+    let service = get_telemetry_service(bmc.clone(), &ids).await?;
+    let metric_delete_task_id = "/redfish/v1/TaskService/Tasks/67";
+
+    bmc.expect(Expect::delete_task(
+        &ids.metric_definition,
+        async_task(metric_delete_task_id, 3),
+    ));
+    assert_task(
+        service
+            .delete_metric_definition(&ids.metric_definition.clone().into())
+            .await?,
+        metric_delete_task_id,
+        3,
+    );
+
+    bmc.expect(Expect::delete(&ids.metric_report_definition));
+    assert_empty(
+        service
+            .delete_metric_report_definition(&ids.metric_report_definition.clone().into())
+            .await?,
+    );
+    bmc.assert_all_consumed()?;
+
+    Ok(())
+}
+
+#[test]
+async fn delete_definitions_outside_collection_is_rejected() -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(Bmc::default());
+    let ids = telemetry_ids();
+    let service = get_telemetry_service(bmc.clone(), &ids).await?;
+
+    let report_id: ODataId = ids.metric_report_definition.clone().into();
+    assert!(matches!(
+        service.delete_metric_definition(&report_id).await,
+        Err(Error::MetricDefinitionNotAvailable(id)) if id == report_id
+    ));
+
+    let collection_id: ODataId = ids.metric_report_definitions.clone().into();
+    assert!(matches!(
+        service.delete_metric_report_definition(&collection_id).await,
+        Err(Error::MetricReportDefinitionNotAvailable(id)) if id == collection_id
+    ));
+    bmc.assert_all_consumed()?;
+
+    Ok(())
+}
+
+#[test]
+async fn metric_report_by_id_fetches_report_directly() -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(Bmc::default());
+    let ids = telemetry_ids();
+    let service = get_telemetry_service(bmc.clone(), &ids).await?;
+    let report_id = format!("{}/ThermalReport", ids.metric_reports);
+
+    bmc.expect(Expect::get(
+        &report_id,
+        json!({
+            ODATA_ID: &report_id,
+            ODATA_TYPE: METRIC_REPORT_DATA_TYPE,
+            "Id": "ThermalReport",
+            "Name": "Thermal Report"
+        }),
+    ));
+    let report = service.metric_report_by_id("ThermalReport").await?;
+    assert_eq!(report.base.id, "ThermalReport");
+
+    bmc.expect(Expect::get_not_found(format!(
+        "{}/Missing",
+        ids.metric_reports
+    )));
+    assert!(matches!(
+        service.metric_report_by_id("Missing").await,
+        Err(Error::MetricReportNotFound(id)) if id == "Missing"
+    ));
+
+    Ok(())
+}
+
 async fn get_telemetry_service(
     bmc: Arc<Bmc>,
     ids: &TelemetryIds,
@@ -261,6 +349,9 @@ async fn get_telemetry_service(
             },
             "MetricReportDefinitions": {
                 ODATA_ID: &ids.metric_report_definitions
+            },
+            "MetricReports": {
+                ODATA_ID: &ids.metric_reports
             }
         }),
     ));
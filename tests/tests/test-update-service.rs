@@ -22,12 +22,14 @@ use std::time::Duration;
 use futures_util::io::Cursor;
 #[cfg(feature = "update-service-deprecated")]
 use nv_redfish::schema::update_service::HttpPushUriOptionsUpdate;
+use nv_redfish::task_service::TaskState;
 use nv_redfish::update_service::MultipartUpdateParameters;
 use nv_redfish::update_service::UpdateService;
 #[cfg(feature = "update-service-deprecated")]
 use nv_redfish::update_service::UpdateServiceUpdate;
 use nv_redfish::Error;
 use nv_redfish::ServiceRoot;
+use nv_redfish_core::AsyncTask;
 use nv_redfish_core::DataStream;
 use nv_redfish_core::EntityTypeRef;
 use nv_redfish_core::ModificationResponse;
@@ -282,6 +284,58 @@ async fn uses_multipart_http_push_uri() -> Result<(), Box<dyn StdError>> {
     Ok(())
 }
 
+#[tokio::test]
+async fn push_update_sends_parameters_and_image_parts() -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(Bmc::default());
+
+    bmc.expect(Expect::get("/redfish/v1", service_root_json()));
+    bmc.expect(Expect::get(
+        UPDATE_SERVICE_URI,
+        update_service_json(Some(MULTIPART_URI)),
+    ));
+
+    // Only the UpdateParameters JSON part and the UpdateFile part are
+    // expected: no OEM parts are sent.
+    bmc.expect(Expect::multipart_update_with_oem_parts(
+        MULTIPART_URI,
+        json!({
+            "Targets": ["/redfish/v1/Managers/BMC"]
+        }),
+        "bmc.fwpkg",
+        Vec::<&str>::new(),
+        json!({
+            "@odata.id": "/redfish/v1/TaskService/Tasks/43",
+            "Id": "43"
+        }),
+    ));
+
+    let root = ServiceRoot::new(Arc::clone(&bmc)).await?;
+    let update_service = root
+        .update_service()
+        .await?
+        .ok_or("expected update service")?;
+    let parameters = MultipartUpdateParameters::builder()
+        .with_targets(vec!["/redfish/v1/Managers/BMC".to_string()])
+        .build();
+
+    let response = update_service
+        .push_update::<_, serde_json::Value>(
+            "bmc.fwpkg",
+            b"firmware".to_vec(),
+            &parameters,
+            Duration::from_secs(600),
+        )
+        .await?;
+
+    let ModificationResponse::Entity(body) = response else {
+        return Err(String::from("expected entity response").into());
+    };
+
+    assert_eq!(body["@odata.id"], "/redfish/v1/TaskService/Tasks/43");
+
+    Ok(())
+}
+
 #[cfg(feature = "update-service-deprecated")]
 #[tokio::test]
 async fn uses_http_push_uri_without_update_parameters() -> Result<(), Box<dyn StdError>> {
@@ -505,6 +559,88 @@ async fn requires_http_push_uri() -> Result<(), Box<dyn StdError>> {
     Ok(())
 }
 
+#[tokio::test]
+async fn simple_update_task_returns_task_to_poll() -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(Bmc::default());
+    let simple_update_target = format!("{UPDATE_SERVICE_URI}/Actions/UpdateService.SimpleUpdate");
+    let tasks_uri = "/redfish/v1/TaskService/Tasks";
+    let task_uri = format!("{tasks_uri}/42");
+
+    let mut root_json = service_root_json();
+    root_json["Tasks"] = json!({ "@odata.id": "/redfish/v1/TaskService" });
+    bmc.expect(Expect::get("/redfish/v1", root_json));
+    let mut update_service_json = update_service_json(None);
+    update_service_json["Actions"] = json!({
+        "#UpdateService.SimpleUpdate": {
+            "target": &simple_update_target
+        }
+    });
+    bmc.expect(Expect::get(UPDATE_SERVICE_URI, update_service_json));
+    bmc.expect(Expect::get(
+        "/redfish/v1/TaskService",
+        json!({
+            "@odata.id": "/redfish/v1/TaskService",
+            "Id": "TaskService",
+            "Name": "Task Service",
+            "Tasks": {
+                "@odata.id": tasks_uri
+            }
+        }),
+    ));
+
+    let root = ServiceRoot::new(Arc::clone(&bmc)).await?;
+    let update_service = root
+        .update_service()
+        .await?
+        .ok_or("expected update service")?;
+    let task_service = root.task_service().await?.ok_or("expected task service")?;
+
+    bmc.expect(Expect::action_task(
+        &simple_update_target,
+        json!({
+            "ImageURI": "https://example.com/firmware.bin"
+        }),
+        AsyncTask {
+            location: ODataId::from(task_uri.clone()).into(),
+            retry_after: None,
+        },
+    ));
+    let task = update_service
+        .simple_update_task(
+            &task_service,
+            "https://example.com/firmware.bin".to_string(),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        )
+        .await?
+        .ok_or("expected update task")?;
+    assert_eq!(task.link().odata_id().to_string(), task_uri);
+
+    bmc.expect(Expect::get(
+        &task_uri,
+        json!({
+            "@odata.id": &task_uri,
+            "@odata.type": "#Task.v1_4_3.Task",
+            "Id": "42",
+            "Name": "Task 42",
+            "TaskState": "Completed"
+        }),
+    ));
+    let task = task
+        .poll_until_complete(Duration::from_secs(1), 3, |_| async {})
+        .await?;
+    assert_eq!(task.task_state, Some(TaskState::Completed));
+    bmc.assert_all_consumed()?;
+
+    Ok(())
+}
+
 fn service_root_json() -> serde_json::Value {
     json!({
         "@odata.id": "/redfish/v1",
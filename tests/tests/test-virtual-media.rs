@@ -0,0 +1,209 @@
+// SPDX-FileCopyrightText: Copyright (c) 2026 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Integration tests of Virtual Media.
+
+use std::error::Error as StdError;
+use std::sync::Arc;
+
+use nv_redfish::virtual_media::ConnectedVia;
+use nv_redfish::virtual_media::InsertMediaOptions;
+use nv_redfish::virtual_media::TransferProtocolType;
+use nv_redfish::virtual_media::VirtualMedia;
+use nv_redfish::Error;
+use nv_redfish::ServiceRoot;
+use nv_redfish_core::ModificationResponse;
+use nv_redfish_core::ODataId;
+use nv_redfish_tests::anonymous_1_9_service_root;
+use nv_redfish_tests::json_merge;
+use nv_redfish_tests::redfish_empty_actions_payload;
+use nv_redfish_tests::Bmc;
+use nv_redfish_tests::Expect;
+use nv_redfish_tests::ODATA_ID;
+use nv_redfish_tests::ODATA_TYPE;
+
+use serde_json::json;
+use serde_json::Value;
+use tokio::test;
+
+const MANAGER_COLLECTION_DATA_TYPE: &str = "#ManagerCollection.ManagerCollection";
+const MANAGER_DATA_TYPE: &str = "#Manager.v1_16_0.Manager";
+const VIRTUAL_MEDIA_COLLECTION_DATA_TYPE: &str = "#VirtualMediaCollection.VirtualMediaCollection";
+const VIRTUAL_MEDIA_DATA_TYPE: &str = "#VirtualMedia.v1_6_0.VirtualMedia";
+
+const MANAGER_ID: &str = "/redfish/v1/Managers/1";
+const VIRTUAL_MEDIA_ID: &str = "/redfish/v1/Managers/1/VirtualMedia/CD1";
+const INSERT_TARGET: &str =
+    "/redfish/v1/Managers/1/VirtualMedia/CD1/Actions/VirtualMedia.InsertMedia";
+const EJECT_TARGET: &str =
+    "/redfish/v1/Managers/1/VirtualMedia/CD1/Actions/VirtualMedia.EjectMedia";
+
+#[test]
+async fn insert_media_sends_image_and_options() -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(Bmc::default());
+    let media = get_virtual_media(bmc.clone(), media_actions()).await?;
+
+    bmc.expect(Expect::action(
+        INSERT_TARGET,
+        json!({ "Image": "http://images.example.com/boot.iso" }),
+        json!(null),
+    ));
+    assert!(matches!(
+        media
+            .insert_media(
+                "http://images.example.com/boot.iso".into(),
+                InsertMediaOptions::default(),
+            )
+            .await?,
+        ModificationResponse::Entity(())
+    ));
+
+    bmc.expect(Expect::action(
+        INSERT_TARGET,
+        json!({
+            "Image": "https://images.example.com/boot.iso",
+            "Inserted": true,
+            "WriteProtected": true,
+            "TransferProtocolType": "HTTPS",
+            "UserName": "user",
+            "Password": "secret",
+        }),
+        json!(null),
+    ));
+    assert!(matches!(
+        media
+            .insert_media(
+                "https://images.example.com/boot.iso".into(),
+                InsertMediaOptions {
+                    inserted: Some(true),
+                    write_protected: Some(true),
+                    transfer_protocol_type: Some(TransferProtocolType::Https),
+                    username: Some("user".into()),
+                    password: Some("secret".into()),
+                    ..InsertMediaOptions::default()
+                },
+            )
+            .await?,
+        ModificationResponse::Entity(())
+    ));
+
+    Ok(())
+}
+
+#[test]
+async fn eject_media_invokes_action() -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(Bmc::default());
+    let media = get_virtual_media(bmc.clone(), media_actions()).await?;
+
+    assert_eq!(media.inserted(), Some(true));
+    assert_eq!(media.connected_via(), Some(ConnectedVia::Uri));
+
+    bmc.expect(Expect::action(EJECT_TARGET, json!({}), json!(null)));
+    assert!(matches!(
+        media.eject_media().await?,
+        ModificationResponse::Entity(())
+    ));
+
+    Ok(())
+}
+
+#[test]
+async fn media_actions_require_action_targets() -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(Bmc::default());
+    let media = get_virtual_media(bmc.clone(), redfish_empty_actions_payload()).await?;
+
+    assert!(matches!(
+        media
+            .insert_media("boot.iso".into(), InsertMediaOptions::default())
+            .await,
+        Err(Error::ActionNotAvailable)
+    ));
+    assert!(matches!(
+        media.eject_media().await,
+        Err(Error::ActionNotAvailable)
+    ));
+
+    Ok(())
+}
+
+fn media_actions() -> Value {
+    json!({
+        "Actions": {
+            "#VirtualMedia.InsertMedia": { "target": INSERT_TARGET },
+            "#VirtualMedia.EjectMedia": { "target": EJECT_TARGET },
+        }
+    })
+}
+
+async fn get_virtual_media(
+    bmc: Arc<Bmc>,
+    fields: Value,
+) -> Result<VirtualMedia<Bmc>, Box<dyn StdError>> {
+    let root_id = ODataId::service_root();
+    let managers_id = format!("{root_id}/Managers");
+    let virtual_media_collection_id = format!("{MANAGER_ID}/VirtualMedia");
+    bmc.expect(Expect::get(
+        &root_id,
+        anonymous_1_9_service_root(&root_id, json!({ "Managers": { ODATA_ID: &managers_id } })),
+    ));
+    let root = ServiceRoot::new(bmc.clone()).await?;
+
+    bmc.expect(Expect::get(
+        &managers_id,
+        json!({
+            ODATA_ID: &managers_id,
+            ODATA_TYPE: MANAGER_COLLECTION_DATA_TYPE,
+            "Id": "Managers",
+            "Name": "Manager Collection",
+            "Members": [{
+                ODATA_ID: MANAGER_ID,
+                ODATA_TYPE: MANAGER_DATA_TYPE,
+                "Id": "1",
+                "Name": "Manager",
+                "Status": { "State": "Enabled" },
+                "VirtualMedia": { ODATA_ID: &virtual_media_collection_id },
+            }]
+        }),
+    ));
+    let mut managers = root.managers().await?.unwrap().members().await?;
+    let manager = managers.pop().unwrap();
+
+    bmc.expect(Expect::get(
+        &virtual_media_collection_id,
+        json!({
+            ODATA_ID: &virtual_media_collection_id,
+            ODATA_TYPE: VIRTUAL_MEDIA_COLLECTION_DATA_TYPE,
+            "Name": "Virtual Media Services",
+            "Members": [{ ODATA_ID: VIRTUAL_MEDIA_ID }],
+        }),
+    ));
+    bmc.expect(Expect::get(
+        VIRTUAL_MEDIA_ID,
+        json_merge([
+            &json!({
+                ODATA_ID: VIRTUAL_MEDIA_ID,
+                ODATA_TYPE: VIRTUAL_MEDIA_DATA_TYPE,
+                "Id": "CD1",
+                "Name": "Virtual CD",
+                "Inserted": true,
+                "ConnectedVia": "URI",
+            }),
+            &fields,
+        ]),
+    ));
+    let mut media = manager.virtual_media().await?.unwrap();
+    assert_eq!(media.len(), 1);
+    Ok(media.remove(0))
+}
@@ -24,7 +24,13 @@ use nv_redfish::account::AccountService;
 use nv_redfish::account::AccountTypes;
 use nv_redfish::account::ManagerAccountCreate;
 use nv_redfish::account::ManagerAccountUpdate;
+use nv_redfish::account::PrivilegeType;
+use nv_redfish::account::RoleCollection;
+use nv_redfish::account::RoleCreate;
+use nv_redfish::Error;
+use nv_redfish::Resource as _;
 use nv_redfish::ServiceRoot;
+use nv_redfish_bmc_mock::Error as BmcError;
 use nv_redfish_core::AsyncTask;
 use nv_redfish_core::EntityTypeRef;
 use nv_redfish_core::ModificationResponse;
@@ -42,6 +48,8 @@ use tokio::test;
 const ACCOUNT_SERVICE_DATA_TYPE: &str = "#AccountService.v1_5_0.AccountService";
 const ACCOUNTS_DATA_TYPE: &str = "#ManagerAccountCollection.ManagerAccountCollection";
 const MANAGER_ACCOUNT_DATA_TYPE: &str = "#ManagerAccount.v1_3_0.ManagerAccount";
+const ROLES_DATA_TYPE: &str = "#RoleCollection.RoleCollection";
+const ROLE_DATA_TYPE: &str = "#Role.v1_3_1.Role";
 
 type TestResult<T> = Result<T, Box<dyn StdError>>;
 
@@ -176,6 +184,41 @@ async fn list_no_patch_accounts() -> Result<(), Box<dyn StdError>> {
     Ok(())
 }
 
+#[test]
+async fn find_by_username_stops_at_first_match() -> TestResult<()> {
+    let bmc = Arc::new(Bmc::default());
+    let root_id = ODataId::service_root();
+    let account_service = get_account_service(bmc.clone(), &root_id, "Contoso").await?;
+    let accounts_id = format!("{}/Accounts", account_service.raw().odata_id());
+    let members = json!([
+        { ODATA_ID: format!("{accounts_id}/1") },
+        { ODATA_ID: format!("{accounts_id}/2") },
+    ]);
+    let accounts = get_account_collection(bmc.clone(), &account_service, members).await?;
+
+    // Only the first member is fetched when it matches.
+    bmc.expect(Expect::get(
+        format!("{accounts_id}/1"),
+        slot_member(&accounts_id, 1, true, "admin"),
+    ));
+    let account = accounts.find_by_username("admin").await?;
+    assert_eq!(account.map(|a| a.raw().base.id.clone()), Some("1".into()));
+    bmc.assert_all_consumed()?;
+
+    bmc.expect(Expect::get(
+        format!("{accounts_id}/1"),
+        slot_member(&accounts_id, 1, true, "admin"),
+    ));
+    bmc.expect(Expect::get(
+        format!("{accounts_id}/2"),
+        slot_member(&accounts_id, 2, true, "operator"),
+    ));
+    assert!(accounts.find_by_username("nobody").await?.is_none());
+    bmc.assert_all_consumed()?;
+
+    Ok(())
+}
+
 async fn get_account_service(
     bmc: Arc<Bmc>,
     root_id: &ODataId,
@@ -210,6 +253,7 @@ async fn get_account_service(
     let service_root = ServiceRoot::new(bmc.clone()).await?;
 
     let accounts_id = format!("{account_service_id}/Accounts");
+    let roles_id = format!("{account_service_id}/Roles");
     bmc.expect(Expect::get(
         &account_service_id,
         json!({
@@ -220,6 +264,9 @@ async fn get_account_service(
             "Accounts": {
                 ODATA_ID: &accounts_id,
             },
+            "Roles": {
+                ODATA_ID: &roles_id,
+            },
         }),
     ));
     Ok(service_root.account_service().await?.unwrap())
@@ -508,6 +555,41 @@ async fn update_account_preserves_task_and_empty_responses() -> TestResult<()> {
     Ok(())
 }
 
+#[test]
+async fn account_update_helpers_send_single_field_payloads() -> TestResult<()> {
+    let (bmc, accounts_id, accounts) = account_fixture("Contoso", &[(1, true, "user")]).await?;
+    let account = accounts
+        .all_accounts_data()
+        .await?
+        .into_iter()
+        .next()
+        .ok_or("missing account")?;
+
+    let account_id = format!("{accounts_id}/1");
+
+    bmc.expect(Expect::update_empty(
+        &account_id,
+        json!({ "Password": "new-password" }),
+    ));
+    assert_empty(account.update_password("new-password".into()).await?);
+
+    bmc.expect(Expect::update_empty(
+        &account_id,
+        json!({ "Enabled": false }),
+    ));
+    assert_empty(account.update_enabled(false).await?);
+
+    bmc.expect(Expect::update_empty(
+        &account_id,
+        json!({ "RoleId": "Administrator" }),
+    ));
+    assert_empty(account.update_role_id("Administrator".into()).await?);
+
+    bmc.assert_all_consumed()?;
+
+    Ok(())
+}
+
 #[test]
 async fn delete_account_preserves_task_and_empty_responses() -> TestResult<()> {
     let (bmc, _, accounts) =
@@ -534,6 +616,28 @@ async fn delete_account_preserves_task_and_empty_responses() -> TestResult<()> {
     Ok(())
 }
 
+#[test]
+async fn delete_account_rejects_unexpected_id() -> TestResult<()> {
+    let (bmc, _, accounts) =
+        account_fixture("Contoso", &[(1, true, "first"), (2, true, "second")]).await?;
+
+    let mut account_data = accounts.all_accounts_data().await?.into_iter();
+    let first_account = account_data.next().ok_or("missing first account")?;
+    let second_account = account_data.next().ok_or("missing second account")?;
+    let first_account_id = first_account.raw().odata_id().to_string();
+    let second_account_id = second_account.raw().odata_id().to_string();
+
+    bmc.expect(Expect::delete(second_account_id));
+
+    let result = first_account.delete().await;
+    assert!(matches!(
+        result,
+        Err(Error::Bmc(BmcError::UnexpectedDelete(id, _))) if id.to_string() == first_account_id
+    ));
+
+    Ok(())
+}
+
 // Create account (Dell slot-defined): error when no disabled slot id >= min_slot is available.
 #[test]
 async fn create_account_dell_slot_defined_no_slot_available() -> TestResult<()> {
@@ -575,3 +679,154 @@ async fn list_dell_accounts_hide_disabled() -> TestResult<()> {
 
     Ok(())
 }
+
+fn expect_accounts(bmc: &Bmc, accounts_id: &str, slots: &[(u32, bool, &str)]) {
+    bmc.expect(Expect::expand(
+        accounts_id,
+        json!({
+            ODATA_ID: accounts_id,
+            ODATA_TYPE: &ACCOUNTS_DATA_TYPE,
+            "Name": "User Accounts",
+            "Members": slots
+                .iter()
+                .map(|&(id, enabled, user_name)| slot_member(accounts_id, id, enabled, user_name))
+                .collect::<Vec<_>>(),
+        }),
+    ));
+}
+
+// Change password: only `Password` is sent to the account with matching `UserName`.
+#[test]
+async fn change_password_updates_matching_account() -> TestResult<()> {
+    let bmc = Arc::new(Bmc::default());
+    let root_id = ODataId::service_root();
+    let account_service = get_account_service(bmc.clone(), &root_id, "Contoso").await?;
+    let accounts_id = format!("{}/Accounts", account_service.raw().odata_id());
+    expect_accounts(&bmc, &accounts_id, &[(1, true, "root"), (2, true, "user")]);
+    let update_req = ManagerAccountUpdate::builder()
+        .with_password("new-password".into())
+        .build();
+    bmc.expect(Expect::update_empty(
+        format!("{accounts_id}/2"),
+        serde_json::to_value(&update_req)?,
+    ));
+
+    account_service
+        .change_password("user", "new-password")
+        .await?;
+    bmc.assert_all_consumed()?;
+
+    Ok(())
+}
+
+// Change password: error when no account has matching `UserName`.
+#[test]
+async fn change_password_reports_missing_account() -> TestResult<()> {
+    let bmc = Arc::new(Bmc::default());
+    let root_id = ODataId::service_root();
+    let account_service = get_account_service(bmc.clone(), &root_id, "Contoso").await?;
+    let accounts_id = format!("{}/Accounts", account_service.raw().odata_id());
+    expect_accounts(&bmc, &accounts_id, &[(1, true, "root")]);
+
+    let result = account_service
+        .change_password("user", "new-password")
+        .await;
+    assert!(matches!(result, Err(Error::AccountNotFound(name)) if name == "user"));
+    bmc.assert_all_consumed()?;
+
+    Ok(())
+}
+
+fn role_member(roles_id: &str, id: &str, predefined: bool, privileges: &[&str]) -> JsonValue {
+    json!({
+        ODATA_ID: format!("{roles_id}/{id}"),
+        ODATA_TYPE: ROLE_DATA_TYPE,
+        "Id": id,
+        "Name": "User Role",
+        "RoleId": id,
+        "IsPredefined": predefined,
+        "AssignedPrivileges": privileges,
+    })
+}
+
+async fn role_fixture(members: JsonValue) -> TestResult<(Arc<Bmc>, String, RoleCollection<Bmc>)> {
+    let bmc = Arc::new(Bmc::default());
+    let root_id = ODataId::service_root();
+    let account_service = get_account_service(bmc.clone(), &root_id, "Contoso").await?;
+    let roles_id = format!("{}/Roles", account_service.raw().odata_id());
+    bmc.expect(Expect::expand(
+        &roles_id,
+        json!({
+            ODATA_ID: &roles_id,
+            ODATA_TYPE: ROLES_DATA_TYPE,
+            "Name": "Roles Collection",
+            "Members": members,
+        }),
+    ));
+    let roles = account_service.roles().await?.unwrap();
+    Ok((bmc, roles_id, roles))
+}
+
+#[test]
+async fn list_roles() -> TestResult<()> {
+    let roles_id = format!("{}/AccountService/Roles", ODataId::service_root());
+    let (_bmc, _, roles) = role_fixture(json!([
+        role_member(
+            &roles_id,
+            "Administrator",
+            true,
+            &["Login", "ConfigureManager"]
+        ),
+        role_member(&roles_id, "ReadOnly", true, &["Login"]),
+    ]))
+    .await?;
+
+    let roles = roles.members().await?;
+    let ids = roles
+        .iter()
+        .map(|role| role.id().to_string())
+        .collect::<Vec<_>>();
+    assert_eq!(ids, ["Administrator", "ReadOnly"]);
+    Ok(())
+}
+
+#[test]
+async fn create_and_update_custom_role() -> TestResult<()> {
+    let (bmc, roles_id, roles) = role_fixture(json!([])).await?;
+    let role_id = format!("{roles_id}/FleetOperator");
+
+    let create = RoleCreate::builder("FleetOperator".into())
+        .with_assigned_privileges(vec![
+            PrivilegeType::Login,
+            PrivilegeType::ConfigureComponents,
+        ])
+        .build();
+    bmc.expect(Expect::create(
+        &roles_id,
+        json!({
+            "RoleId": "FleetOperator",
+            "AssignedPrivileges": ["Login", "ConfigureComponents"],
+        }),
+        role_member(
+            &roles_id,
+            "FleetOperator",
+            false,
+            &["Login", "ConfigureComponents"],
+        ),
+    ));
+    let role = into_entity(roles.create(&create).await?);
+    assert_eq!(role.id().to_string(), "FleetOperator");
+    assert_eq!(role.odata_id().to_string(), role_id);
+
+    bmc.expect(Expect::update(
+        &role_id,
+        json!({ "AssignedPrivileges": ["Login"] }),
+        role_member(&roles_id, "FleetOperator", false, &["Login"]),
+    ));
+    let updated = into_entity(role.update(vec![PrivilegeType::Login]).await?);
+    assert_eq!(updated.id().to_string(), "FleetOperator");
+
+    bmc.expect(Expect::delete(&role_id));
+    assert_empty(role.delete().await?);
+    Ok(())
+}
@@ -13,8 +13,12 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use nv_redfish_bmc_mock::Error as BmcError;
+use nv_redfish_core::Bmc as _;
 use nv_redfish_core::Creatable;
+use nv_redfish_core::Deletable;
 use nv_redfish_core::EntityTypeRef;
+use nv_redfish_core::FilterQuery;
 use nv_redfish_core::ModificationResponse;
 use nv_redfish_core::NavProperty;
 use nv_redfish_core::ODataId;
@@ -32,6 +36,7 @@ use nv_redfish_tests::base::redfish::service_root::RootSetOnlyComplexType;
 use nv_redfish_tests::base::redfish::service_root::ServiceRootUpdate;
 use nv_redfish_tests::base::redfish::service_root::TestActionsServiceOemActions;
 use nv_redfish_tests::base::redfish::service_root::TestActionsServiceTestSerializationActionAction;
+use nv_redfish_tests::base::redfish::service_root::TestCollectionMember;
 use nv_redfish_tests::base::redfish::service_root::TestCollectionMemberCreate;
 use nv_redfish_tests::base::redfish::test_vendor::TestActionsServiceTestActionAction as VendorTestAction;
 use nv_redfish_tests::json_merge;
@@ -553,6 +558,289 @@ async fn create_collection_member_test() -> Result<(), Error> {
     Ok(())
 }
 
+// Check that update expectation can match a subset of the request.
+#[test]
+async fn update_matching_predicate_test() -> Result<(), Error> {
+    let bmc = Bmc::default();
+    let data_type = "ServiceRoot.v1_0_0.ServiceRoot";
+    let root_id = ODataId::service_root();
+    let root_json = json!({
+        ODATA_ID: &root_id,
+        ODATA_TYPE: &data_type,
+    });
+    bmc.expect(expect_root());
+    let service_root = get_service_root(&bmc).await.map_err(Error::Bmc)?;
+
+    let update = ServiceRootUpdate {
+        updatable: Some("Value".into()),
+        read_only_complex: None,
+        rigid_array_values: None,
+        updatable_guid: Some(
+            "a1a2a3a4-b1b2-c1c2-d1d2-d3d4d5d6d7d8"
+                .parse()
+                .expect("uuid must be parsed"),
+        ),
+        write_only: None,
+    };
+
+    // Only `Updatable` is checked, `UpdatableGuid` may have any value.
+    bmc.expect(Expect::update_matching(
+        root_id.clone(),
+        |body| body["Updatable"] == "Value",
+        &root_json,
+    ));
+    let response = service_root
+        .update(&bmc, &update)
+        .await
+        .map_err(Error::Bmc)?;
+    assert!(matches!(response, ModificationResponse::Entity(_)));
+
+    bmc.expect(Expect::update_matching(
+        root_id.clone(),
+        |body| body["Updatable"] == "Other",
+        &root_json,
+    ));
+    let response = service_root.update(&bmc, &update).await;
+    assert!(matches!(response, Err(BmcError::UnexpectedUpdate(..))));
+    bmc.assert_all_consumed().map_err(Error::Bmc)?;
+    Ok(())
+}
+
+// Check that deletable entity deletes itself through Bmc::delete.
+#[test]
+async fn delete_collection_member_test() -> Result<(), Error> {
+    let bmc = Bmc::default();
+    let root_id = ODataId::service_root();
+    let collection_name = "TestCollection";
+    let collection_id = format!("{root_id}/{collection_name}");
+    let collection_data_type = format!("ServiceRoot.v1_0_0.{collection_name}");
+    bmc.expect(expect_root_srv(collection_name, &collection_id));
+    let service_root = get_service_root(&bmc).await.map_err(Error::Bmc)?;
+
+    let collection_member_id = format!("{collection_id}/1");
+    bmc.expect(Expect::get(
+        &collection_id,
+        json!({
+            ODATA_ID: &collection_id,
+            ODATA_TYPE: &collection_data_type,
+            "Members": [{
+                ODATA_ID: &collection_member_id,
+                ODATA_TYPE: "ServiceRoot.v1_0_0.TestCollectionMember",
+                "RequiredOnCreate": "required value",
+                "OptionalWritable": "writable value",
+                "ReadOnly": "read only value",
+                "ReadOnlyComplex": { "Required": "nested required value" },
+            }],
+        }),
+    ));
+    let collection = service_root
+        .test_collection
+        .as_ref()
+        .ok_or(Error::ExpectedProperty("test_collection"))?
+        .get(&bmc)
+        .await
+        .map_err(Error::Bmc)?;
+    let member = collection
+        .members
+        .first()
+        .ok_or(Error::ExpectedProperty("members"))?
+        .get(&bmc)
+        .await
+        .map_err(Error::Bmc)?;
+
+    bmc.expect(Expect::delete(&collection_member_id));
+    let response = member.delete(&bmc).await.map_err(Error::Bmc)?;
+    assert!(matches!(response, ModificationResponse::Empty));
+
+    // Delete of another resource is not accepted.
+    bmc.expect(Expect::delete(&collection_id));
+    let response = member.delete(&bmc).await;
+    assert!(matches!(
+        response,
+        Err(BmcError::UnexpectedDelete(id, _)) if id.to_string() == collection_member_id
+    ));
+    bmc.assert_all_consumed().map_err(Error::Bmc)?;
+    Ok(())
+}
+
+// Check that scripted expectations are consumed in order.
+#[test]
+async fn sequential_gets_test() -> Result<(), Error> {
+    let bmc = Bmc::default();
+    let root_id = ODataId::service_root();
+    let collection_name = "TestCollection";
+    let collection_id = format!("{root_id}/{collection_name}");
+    let collection_data_type = format!("ServiceRoot.v1_0_0.{collection_name}");
+    let member_id = format!("{collection_id}/1");
+    let member = json!({
+        ODATA_ID: &member_id,
+        ODATA_TYPE: "ServiceRoot.v1_0_0.TestCollectionMember",
+        "RequiredOnCreate": "required value",
+        "OptionalWritable": "writable value",
+        "ReadOnly": "read only value",
+        "ReadOnlyComplex": { "Required": "nested required value" },
+    });
+    bmc.expect_sequence([
+        expect_root_srv(collection_name, &collection_id),
+        Expect::get(
+            &collection_id,
+            json!({
+                ODATA_ID: &collection_id,
+                ODATA_TYPE: &collection_data_type,
+                "Members": [{ ODATA_ID: &member_id }],
+            }),
+        ),
+        Expect::get(&member_id, member),
+    ]);
+    assert_eq!(bmc.expectations_remaining(), 3);
+
+    let service_root = get_service_root(&bmc).await.map_err(Error::Bmc)?;
+    assert_eq!(bmc.expectations_remaining(), 2);
+
+    let collection = service_root
+        .test_collection
+        .as_ref()
+        .ok_or(Error::ExpectedProperty("test_collection"))?
+        .get(&bmc)
+        .await
+        .map_err(Error::Bmc)?;
+    assert_eq!(bmc.expectations_remaining(), 1);
+
+    let member = collection
+        .members
+        .first()
+        .ok_or(Error::ExpectedProperty("members"))?
+        .get(&bmc)
+        .await
+        .map_err(Error::Bmc)?;
+    assert_eq!(member.odata_id().to_string(), member_id);
+    assert_eq!(bmc.expectations_remaining(), 0);
+    bmc.assert_all_consumed().map_err(Error::Bmc)?;
+    Ok(())
+}
+
+// Check that get_many returns results in the order of requested ids.
+#[test]
+async fn get_many_test() -> Result<(), Error> {
+    let bmc = Bmc::default();
+    let collection_id = format!("{}/TestCollection", ODataId::service_root());
+    let ids: Vec<ODataId> = ["3", "1", "2"]
+        .iter()
+        .map(|n| format!("{collection_id}/{n}").into())
+        .collect();
+    bmc.expect_sequence(ids.iter().map(|id| {
+        Expect::get(
+            id,
+            json!({
+                ODATA_ID: id,
+                ODATA_TYPE: "ServiceRoot.v1_0_0.TestCollectionMember",
+                "RequiredOnCreate": "required value",
+                "OptionalWritable": "writable value",
+                "ReadOnly": id.to_string(),
+                "ReadOnlyComplex": { "Required": "nested required value" },
+            }),
+        )
+    }));
+
+    let members = bmc.get_many::<TestCollectionMember>(&ids).await;
+    assert_eq!(bmc.max_in_flight(), ids.len());
+    let member_ids = members
+        .into_iter()
+        .map(|member| member.map(|m| m.odata_id().clone()))
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(Error::Bmc)?;
+    assert_eq!(member_ids, ids);
+    bmc.assert_all_consumed().map_err(Error::Bmc)?;
+    Ok(())
+}
+
+// Check that try_get maps absent entity to None.
+#[test]
+async fn nav_property_try_get_test() -> Result<(), Error> {
+    let bmc = Bmc::default();
+    let member_id = format!("{}/TestCollection/1", ODataId::service_root());
+    let nav = NavProperty::<TestCollectionMember>::new_reference(member_id.clone().into());
+
+    bmc.expect(Expect::get(
+        &member_id,
+        json!({
+            ODATA_ID: &member_id,
+            ODATA_TYPE: "ServiceRoot.v1_0_0.TestCollectionMember",
+            "RequiredOnCreate": "required value",
+            "OptionalWritable": "writable value",
+            "ReadOnly": "read only value",
+            "ReadOnlyComplex": { "Required": "nested required value" },
+        }),
+    ));
+    let member = nav.try_get(&bmc).await.map_err(Error::Bmc)?;
+    assert_eq!(
+        member.map(|m| m.odata_id().to_string()),
+        Some(member_id.clone())
+    );
+
+    bmc.expect(Expect::get_not_found(&member_id));
+    assert!(nav.try_get(&bmc).await.map_err(Error::Bmc)?.is_none());
+
+    let other_id = format!("{}/TestCollection/2", ODataId::service_root());
+    bmc.expect(Expect::get_not_found(&other_id));
+    assert!(matches!(
+        nav.try_get(&bmc).await,
+        Err(BmcError::UnexpectedGet(..))
+    ));
+    bmc.assert_all_consumed().map_err(Error::Bmc)?;
+    Ok(())
+}
+
+// Check that filter query is passed to Bmc::filter as is.
+#[test]
+async fn filter_collection_test() -> Result<(), Error> {
+    let bmc = Bmc::default();
+    let root_id = ODataId::service_root();
+    let collection_name = "TestCollection";
+    let collection_id = format!("{root_id}/{collection_name}");
+    let collection_data_type = format!("ServiceRoot.v1_0_0.{collection_name}");
+    bmc.expect(expect_root_srv(collection_name, &collection_id));
+    let service_root = get_service_root(&bmc).await.map_err(Error::Bmc)?;
+    let collection_nav = service_root
+        .test_collection
+        .as_ref()
+        .ok_or(Error::ExpectedProperty("test_collection"))?;
+
+    let query = FilterQuery::eq(&"Status/Health", "OK");
+    bmc.expect(Expect::filter(
+        &collection_id,
+        &query,
+        json!({
+            ODATA_ID: &collection_id,
+            ODATA_TYPE: &collection_data_type,
+            "Members": [],
+        }),
+    ));
+    let collection = collection_nav
+        .filter(&bmc, query.clone())
+        .await
+        .map_err(Error::Bmc)?;
+    assert_eq!(collection.odata_id().to_string(), collection_id);
+    assert!(collection.members.is_empty());
+
+    // Different query is not accepted.
+    bmc.expect(Expect::filter(
+        &collection_id,
+        &query,
+        json!({ ODATA_ID: &collection_id, ODATA_TYPE: &collection_data_type }),
+    ));
+    let response = collection_nav
+        .filter(&bmc, FilterQuery::eq(&"Status/Health", "Critical"))
+        .await;
+    assert!(matches!(
+        response,
+        Err(BmcError::UnexpectedFilter(_, query, _))
+            if query == "$filter=Status/Health eq 'Critical'"
+    ));
+    bmc.assert_all_consumed().map_err(Error::Bmc)?;
+    Ok(())
+}
+
 #[test]
 async fn create_struct_required_on_create_and_writable_fields_test() -> Result<(), Error> {
     let create = TestCollectionMemberCreate::builder(